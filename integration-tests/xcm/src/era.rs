@@ -0,0 +1,270 @@
+//! Our parachain: the identity and content registries behind an XCM configuration that mirrors
+//! `runtime/src/configs/xcm_config.rs`. Cumulus' XCMP queue and the collator fee split are
+//! replaced by the simulator's message queue and a fixed-rate trader.
+
+use polkadot_sdk::{
+    staging_xcm as xcm, staging_xcm_builder as xcm_builder, staging_xcm_executor as xcm_executor, *,
+};
+
+use frame_support::{
+    construct_runtime, derive_impl, parameter_types,
+    traits::{ConstU128, ConstU32, ConstU64, Contains, Everything, Nothing},
+    weights::Weight,
+};
+use frame_system::EnsureRoot;
+use pallet_xcm::{EnsureXcm, XcmPassthrough};
+use polkadot_parachain_primitives::primitives::Sibling;
+use shared::types::BaseRight;
+use sp_runtime::{
+    testing::TestXt, traits::IdentityLookup, AccountId32, BoundedVec, MultiSignature, MultiSigner,
+};
+use xcm::latest::prelude::*;
+use xcm_builder::{
+    AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowTopLevelPaidExecutionFrom,
+    EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds, FrameTransactionalProcessor,
+    FungibleAdapter, IsConcrete, NativeAsset, ParentIsPreset, SiblingParachainConvertsVia,
+    SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
+    WithComputedOrigin,
+};
+use xcm_executor::XcmExecutor;
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type Did = BoundedVec<u8, ConstU32<100>>;
+pub type Device = BoundedVec<u8, ConstU32<100>>;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+    type Block = frame_system::mocking::MockBlock<Runtime>;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Runtime {
+    type Balance = Balance;
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type RuntimeHoldReason = RuntimeHoldReason;
+}
+
+impl pallet_timestamp::Config for Runtime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const RelayLocation: Location = Location::parent();
+    pub const RelayNetwork: Option<NetworkId> = None;
+    pub UniversalLocation: InteriorLocation =
+        Parachain(xcm_simulator::mock_message_queue::ParachainId::<Runtime>::get().into()).into();
+    pub UnitWeightCost: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+    pub const MaxInstructions: u32 = 100;
+    pub const MaxAssetsIntoHolding: u32 = 64;
+    /// One relay token unit buys a million units of reference time.
+    pub RelayTokenPerSecond: (AssetId, u128, u128) = (AssetId(Parent.into()), 1_000_000, 1024 * 1024);
+}
+
+pub type LocationToAccountId = (
+    ParentIsPreset<AccountId>,
+    SiblingParachainConvertsVia<Sibling, AccountId>,
+    AccountId32Aliases<RelayNetwork, AccountId>,
+);
+
+pub type LocalAssetTransactor =
+    FungibleAdapter<Balances, IsConcrete<RelayLocation>, LocationToAccountId, AccountId, ()>;
+
+pub type XcmOriginToTransactDispatchOrigin = (
+    SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>,
+    SignedAccountId32AsNative<RelayNetwork, RuntimeOrigin>,
+    XcmPassthrough<RuntimeOrigin>,
+);
+
+/// Same as the runtime's.
+pub struct ParentOrParentsExecutivePlurality;
+impl Contains<Location> for ParentOrParentsExecutivePlurality {
+    fn contains(location: &Location) -> bool {
+        matches!(location.unpack(), (1, []) | (1, [Plurality { id: BodyId::Executive, .. }]))
+    }
+}
+
+/// Same as the runtime's.
+pub struct SiblingParachains;
+impl Contains<Location> for SiblingParachains {
+    fn contains(location: &Location) -> bool {
+        matches!(location.unpack(), (1, [Parachain(_)]))
+    }
+}
+
+pub type Barrier = (
+    TakeWeightCredit,
+    WithComputedOrigin<
+        (
+            AllowTopLevelPaidExecutionFrom<Everything>,
+            AllowExplicitUnpaidExecutionFrom<ParentOrParentsExecutivePlurality>,
+        ),
+        UniversalLocation,
+        ConstU32<8>,
+    >,
+);
+
+pub type XcmRouter = crate::ParachainXcmRouter<MsgQueue>;
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+    type RuntimeCall = RuntimeCall;
+    type XcmSender = XcmRouter;
+    type XcmEventEmitter = PolkadotXcm;
+    type AssetTransactor = LocalAssetTransactor;
+    type OriginConverter = XcmOriginToTransactDispatchOrigin;
+    type IsReserve = NativeAsset;
+    type IsTeleporter = ();
+    type UniversalLocation = UniversalLocation;
+    type Barrier = Barrier;
+    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+    type Trader = FixedRateOfFungible<RelayTokenPerSecond, ()>;
+    type ResponseHandler = PolkadotXcm;
+    type AssetTrap = PolkadotXcm;
+    type AssetClaims = PolkadotXcm;
+    type SubscriptionService = PolkadotXcm;
+    type PalletInstancesInfo = AllPalletsWithSystem;
+    type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+    type AssetLocker = ();
+    type AssetExchanger = ();
+    type FeeManager = ();
+    type MessageExporter = ();
+    type UniversalAliases = Nothing;
+    type CallDispatcher = RuntimeCall;
+    type SafeCallFilter = Everything;
+    type Aliasers = Nothing;
+    type TransactionalProcessor = FrameTransactionalProcessor;
+    type HrmpNewChannelOpenRequestHandler = ();
+    type HrmpChannelAcceptedHandler = ();
+    type HrmpChannelClosingHandler = ();
+    type XcmRecorder = PolkadotXcm;
+}
+
+pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>;
+
+impl pallet_xcm::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+    type XcmRouter = XcmRouter;
+    type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+    type XcmExecuteFilter = Nothing;
+    type XcmExecutor = XcmExecutor<XcmConfig>;
+    type XcmTeleportFilter = Everything;
+    type XcmReserveTransferFilter = Nothing;
+    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+    type UniversalLocation = UniversalLocation;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+    type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+    type Currency = Balances;
+    type CurrencyMatcher = ();
+    type TrustedLockers = ();
+    type SovereignAccountOf = LocationToAccountId;
+    type MaxLockers = ConstU32<8>;
+    type WeightInfo = pallet_xcm::TestWeightInfo;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type MaxRemoteLockConsumers = ConstU32<0>;
+    type RemoteLockConsumerIdentifier = ();
+    type AuthorizedAliasConsideration = frame_support::traits::Disabled;
+}
+
+impl xcm_simulator::mock_message_queue::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = MultiSigner;
+    type Signature = MultiSignature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type RuntimeCall = RuntimeCall;
+    type Extrinsic = TestXt<RuntimeCall, ()>;
+}
+
+impl<LocalCall> frame_system::offchain::CreateInherent<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_inherent(call: Self::RuntimeCall) -> Self::Extrinsic {
+        TestXt::new_bare(call)
+    }
+}
+
+parameter_types! {
+    pub const IpfsGateway: &'static str = "http://ipfs.test";
+    pub const MaxResponseWeight: Weight = Weight::from_parts(10_000_000_000, 256 * 1024);
+}
+
+impl pallet_identity_registry::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type MaxStringLength = ConstU32<100>;
+    type MaxKeySize = ConstU32<100>;
+    type Device = Device;
+    type Did = Did;
+    type GivenRight = BaseRight;
+    type NativeBalance = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type HoldAmount = ConstU128<100>;
+}
+
+impl pallet_content_registry::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type Did = Did;
+    type Device = Device;
+    type DidRegistry = IdentityRegistry;
+    type GivenRight = BaseRight;
+    type Content = [u8; 32];
+    type ContentDescription = BoundedVec<u8, ConstU32<100>>;
+    type ContentType = BoundedVec<u8, ConstU32<100>>;
+    type NativeBalance = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type ProofDeposit = ConstU128<100>;
+    type CommitmentDeposit = ConstU128<10>;
+    type QuotaPeriod = ConstU64<10>;
+    type DefaultQuota = ConstU32<10>;
+    type QuotaOrigin = EnsureRoot<AccountId>;
+    type XcmQueryOrigin = EnsureXcm<SiblingParachains>;
+    type XcmSender = XcmRouter;
+    type MaxResponseWeight = MaxResponseWeight;
+    type UnixTime = Timestamp;
+    type RelayBlockNumberProvider = System;
+    type MaxCaptureTimeDrift = ConstU64<60_000>;
+    type MaxIpfsCidLength = ConstU32<64>;
+    type IpfsGateway = IpfsGateway;
+    type MaxIpfsObjectSize = ConstU32<1024>;
+    type MaxPendingChecks = ConstU32<3>;
+    type CheckRetryInterval = ConstU64<10>;
+    type MaxReporters = ConstU32<5>;
+    type ReporterOrigin = EnsureRoot<AccountId>;
+    type AuthorityId = pallet_content_registry::crypto::AvailabilityAuthId;
+    type UnsignedPriority = ConstU64<100>;
+    type MaxCommitmentExpiriesPerBlock = ConstU32<2>;
+    type MaxAttestationsPerContent = ConstU32<2>;
+}
+
+construct_runtime!(
+    pub enum Runtime {
+        System: frame_system,
+        Balances: pallet_balances,
+        Timestamp: pallet_timestamp,
+        MsgQueue: xcm_simulator::mock_message_queue,
+        PolkadotXcm: pallet_xcm,
+        IdentityRegistry: pallet_identity_registry,
+        ContentRegistry: pallet_content_registry,
+    }
+);
@@ -0,0 +1,115 @@
+//! XCM round trips between our chain and a sibling parachain, simulated offline over a mock
+//! relay chain. Run with `cargo test -p xcm-integration-tests`.
+
+pub mod era;
+pub mod relay_chain;
+pub mod sibling;
+
+#[cfg(test)]
+mod tests;
+
+// `decl_test_relay_chain!` refers to `pallet_message_queue` unqualified.
+use polkadot_sdk::{
+    frame_system, pallet_balances, pallet_message_queue, sp_io, sp_runtime, xcm_simulator,
+};
+use sp_runtime::{AccountId32, BuildStorage};
+use xcm_simulator::{decl_test_network, decl_test_parachain, decl_test_relay_chain, TestExt};
+
+pub const ERA_PARA_ID: u32 = 2000;
+pub const SIBLING_PARA_ID: u32 = 2004;
+
+pub const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
+pub const INITIAL_BALANCE: u128 = 1_000_000_000;
+
+decl_test_parachain! {
+    pub struct EraPara {
+        Runtime = era::Runtime,
+        XcmpMessageHandler = era::MsgQueue,
+        DmpMessageHandler = era::MsgQueue,
+        new_ext = era_ext(),
+    }
+}
+
+decl_test_parachain! {
+    pub struct SiblingPara {
+        Runtime = sibling::Runtime,
+        XcmpMessageHandler = sibling::MsgQueue,
+        DmpMessageHandler = sibling::MsgQueue,
+        new_ext = sibling_ext(),
+    }
+}
+
+decl_test_relay_chain! {
+    pub struct Relay {
+        Runtime = relay_chain::Runtime,
+        RuntimeCall = relay_chain::RuntimeCall,
+        RuntimeEvent = relay_chain::RuntimeEvent,
+        XcmConfig = relay_chain::XcmConfig,
+        MessageQueue = relay_chain::MessageQueue,
+        System = relay_chain::System,
+        new_ext = relay_ext(),
+    }
+}
+
+decl_test_network! {
+    pub struct MockNet {
+        relay_chain = Relay,
+        parachains = vec![
+            (ERA_PARA_ID, EraPara),
+            (SIBLING_PARA_ID, SiblingPara),
+        ],
+    }
+}
+
+/// Account of a sibling parachain on another parachain.
+pub fn sibling_account(para_id: u32) -> AccountId32 {
+    use polkadot_sdk::{
+        polkadot_parachain_primitives::primitives::Sibling, sp_runtime::traits::AccountIdConversion,
+    };
+    Sibling::from(para_id).into_account_truncating()
+}
+
+/// Our chain at block 1, with ALICE and the sibling's sovereign account funded.
+pub fn era_ext() -> sp_io::TestExternalities {
+    use era::{MsgQueue, Runtime, System, Timestamp};
+
+    let mut t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: vec![
+            (ALICE, INITIAL_BALANCE),
+            (sibling_account(SIBLING_PARA_ID), INITIAL_BALANCE),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(1_700_000_000_000);
+        MsgQueue::set_para_id(ERA_PARA_ID.into());
+    });
+    ext
+}
+
+pub fn sibling_ext() -> sp_io::TestExternalities {
+    use sibling::{MsgQueue, Runtime, System};
+
+    let t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        MsgQueue::set_para_id(SIBLING_PARA_ID.into());
+    });
+    ext
+}
+
+pub fn relay_ext() -> sp_io::TestExternalities {
+    use relay_chain::{Runtime, System};
+
+    let t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,124 @@
+//! A relay chain that only routes messages. Parachains talk to each other directly over the
+//! simulated XCMP bus, so the relay chain never executes anything in these tests.
+
+use polkadot_sdk::{
+    staging_xcm as xcm, staging_xcm_builder as xcm_builder, staging_xcm_executor as xcm_executor, *,
+};
+
+use frame_support::{
+    construct_runtime, derive_impl, parameter_types,
+    traits::{ConstU128, ConstU32, Everything, Nothing, ProcessMessage, ProcessMessageError},
+    weights::{Weight, WeightMeter},
+};
+use polkadot_runtime_parachains::inclusion::{AggregateMessageOrigin, UmpQueueId};
+use sp_runtime::{traits::IdentityLookup, AccountId32};
+use xcm::latest::prelude::*;
+use xcm_builder::{FixedWeightBounds, FrameTransactionalProcessor, ProcessXcmMessage};
+use xcm_executor::XcmExecutor;
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+    type Block = frame_system::mocking::MockBlock<Runtime>;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Runtime {
+    type Balance = Balance;
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+}
+
+parameter_types! {
+    pub UniversalLocation: InteriorLocation = Here;
+    pub UnitWeightCost: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+    pub const MaxInstructions: u32 = 100;
+    pub const MaxAssetsIntoHolding: u32 = 64;
+}
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+    type RuntimeCall = RuntimeCall;
+    type XcmSender = crate::RelayChainXcmRouter;
+    type XcmEventEmitter = ();
+    type AssetTransactor = ();
+    type OriginConverter = ();
+    type IsReserve = ();
+    type IsTeleporter = ();
+    type UniversalLocation = UniversalLocation;
+    type Barrier = ();
+    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+    type Trader = ();
+    type ResponseHandler = ();
+    type AssetTrap = ();
+    type AssetClaims = ();
+    type SubscriptionService = ();
+    type PalletInstancesInfo = AllPalletsWithSystem;
+    type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+    type AssetLocker = ();
+    type AssetExchanger = ();
+    type FeeManager = ();
+    type MessageExporter = ();
+    type UniversalAliases = Nothing;
+    type CallDispatcher = RuntimeCall;
+    type SafeCallFilter = Everything;
+    type Aliasers = Nothing;
+    type TransactionalProcessor = FrameTransactionalProcessor;
+    type HrmpNewChannelOpenRequestHandler = ();
+    type HrmpChannelAcceptedHandler = ();
+    type HrmpChannelClosingHandler = ();
+    type XcmRecorder = ();
+}
+
+/// Executes upward messages as coming from the parachain that sent them.
+pub struct MessageProcessor;
+impl ProcessMessage for MessageProcessor {
+    type Origin = AggregateMessageOrigin;
+
+    fn process_message(
+        message: &[u8],
+        origin: Self::Origin,
+        meter: &mut WeightMeter,
+        id: &mut [u8; 32],
+    ) -> Result<bool, ProcessMessageError> {
+        let para = match origin {
+            AggregateMessageOrigin::Ump(UmpQueueId::Para(para)) => para,
+        };
+        ProcessXcmMessage::<Junction, XcmExecutor<XcmConfig>, RuntimeCall>::process_message(
+            message,
+            Junction::Parachain(para.into()),
+            meter,
+            id,
+        )
+    }
+}
+
+parameter_types! {
+    pub const MessageQueueServiceWeight: Weight = Weight::from_parts(1_000_000_000, 1_000_000);
+}
+
+impl pallet_message_queue::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type MessageProcessor = MessageProcessor;
+    type Size = u32;
+    type QueueChangeHandler = ();
+    type QueuePausedQuery = ();
+    type HeapSize = ConstU32<{ 64 * 1024 }>;
+    type MaxStale = ConstU32<8>;
+    type ServiceWeight = MessageQueueServiceWeight;
+    type IdleMaxServiceWeight = ();
+}
+
+construct_runtime!(
+    pub enum Runtime {
+        System: frame_system,
+        Balances: pallet_balances,
+        MessageQueue: pallet_message_queue,
+    }
+);
@@ -0,0 +1,209 @@
+//! A sibling parachain that consumes proof statuses from our chain. It lets our chain execute
+//! without paying, as the registry answers queries with an unpaid `Transact`.
+
+use polkadot_sdk::{
+    staging_xcm as xcm, staging_xcm_builder as xcm_builder, staging_xcm_executor as xcm_executor, *,
+};
+
+use frame_support::{
+    construct_runtime, derive_impl, parameter_types,
+    traits::{ConstU128, ConstU32, Equals, Everything, Nothing},
+    weights::Weight,
+};
+use frame_system::EnsureRoot;
+use pallet_xcm::{EnsureXcm, XcmPassthrough};
+use polkadot_parachain_primitives::primitives::Sibling;
+use sp_runtime::{traits::IdentityLookup, AccountId32};
+use xcm::latest::prelude::*;
+use xcm_builder::{
+    AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowTopLevelPaidExecutionFrom,
+    EnsureXcmOrigin, FixedWeightBounds, FrameTransactionalProcessor, FungibleAdapter, IsConcrete,
+    NativeAsset, ParentIsPreset, SiblingParachainConvertsVia, SignedAccountId32AsNative,
+    SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit, WithComputedOrigin,
+};
+use xcm_executor::XcmExecutor;
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+
+/// Stores the proof statuses our chain sends back.
+#[frame::pallet]
+pub mod proof_consumer {
+    use frame::prelude::*;
+    use polkadot_sdk::staging_xcm::latest::Location;
+    use shared::types::{ContentId, ProofStatus};
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// The content registry answering queries.
+        type RegistryOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Location>;
+    }
+
+    /// Answers by query id. Block numbers are the registry's.
+    #[pallet::storage]
+    pub type Answers<T> = StorageMap<_, Twox64Concat, u64, (ContentId, ProofStatus<u64>)>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(fn deposit_event)]
+    pub enum Event<T: Config> {
+        ProofStatusReceived { query_id: u64, content_id: ContentId, status: ProofStatus<u64> },
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Callback of `query_proof_status` on the registry.
+        #[pallet::call_index(0)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+        pub fn on_proof_status(
+            origin: OriginFor<T>,
+            query_id: u64,
+            content_id: ContentId,
+            status: ProofStatus<u64>,
+        ) -> DispatchResult {
+            T::RegistryOrigin::ensure_origin(origin)?;
+            Answers::<T>::insert(query_id, (content_id.clone(), status.clone()));
+            Self::deposit_event(Event::ProofStatusReceived { query_id, content_id, status });
+            Ok(())
+        }
+    }
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+    type Block = frame_system::mocking::MockBlock<Runtime>;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Runtime {
+    type Balance = Balance;
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+}
+
+parameter_types! {
+    pub const RelayLocation: Location = Location::parent();
+    pub const RelayNetwork: Option<NetworkId> = None;
+    pub UniversalLocation: InteriorLocation =
+        Parachain(xcm_simulator::mock_message_queue::ParachainId::<Runtime>::get().into()).into();
+    pub EraLocation: Location = Location::new(1, [Parachain(crate::ERA_PARA_ID)]);
+    pub UnitWeightCost: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+    pub const MaxInstructions: u32 = 100;
+    pub const MaxAssetsIntoHolding: u32 = 64;
+}
+
+pub type LocationToAccountId = (
+    ParentIsPreset<AccountId>,
+    SiblingParachainConvertsVia<Sibling, AccountId>,
+    AccountId32Aliases<RelayNetwork, AccountId>,
+);
+
+pub type XcmOriginToTransactDispatchOrigin = (
+    SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>,
+    SignedAccountId32AsNative<RelayNetwork, RuntimeOrigin>,
+    XcmPassthrough<RuntimeOrigin>,
+);
+
+pub type Barrier = (
+    TakeWeightCredit,
+    WithComputedOrigin<
+        (
+            AllowTopLevelPaidExecutionFrom<Everything>,
+            AllowExplicitUnpaidExecutionFrom<Equals<EraLocation>>,
+        ),
+        UniversalLocation,
+        ConstU32<8>,
+    >,
+);
+
+pub type XcmRouter = crate::ParachainXcmRouter<MsgQueue>;
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+    type RuntimeCall = RuntimeCall;
+    type XcmSender = XcmRouter;
+    type XcmEventEmitter = PolkadotXcm;
+    type AssetTransactor =
+        FungibleAdapter<Balances, IsConcrete<RelayLocation>, LocationToAccountId, AccountId, ()>;
+    type OriginConverter = XcmOriginToTransactDispatchOrigin;
+    type IsReserve = NativeAsset;
+    type IsTeleporter = ();
+    type UniversalLocation = UniversalLocation;
+    type Barrier = Barrier;
+    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+    type Trader = ();
+    type ResponseHandler = PolkadotXcm;
+    type AssetTrap = PolkadotXcm;
+    type AssetClaims = PolkadotXcm;
+    type SubscriptionService = PolkadotXcm;
+    type PalletInstancesInfo = AllPalletsWithSystem;
+    type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+    type AssetLocker = ();
+    type AssetExchanger = ();
+    type FeeManager = ();
+    type MessageExporter = ();
+    type UniversalAliases = Nothing;
+    type CallDispatcher = RuntimeCall;
+    type SafeCallFilter = Everything;
+    type Aliasers = Nothing;
+    type TransactionalProcessor = FrameTransactionalProcessor;
+    type HrmpNewChannelOpenRequestHandler = ();
+    type HrmpChannelAcceptedHandler = ();
+    type HrmpChannelClosingHandler = ();
+    type XcmRecorder = PolkadotXcm;
+}
+
+impl pallet_xcm::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type SendXcmOrigin =
+        EnsureXcmOrigin<RuntimeOrigin, SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>>;
+    type XcmRouter = XcmRouter;
+    type ExecuteXcmOrigin =
+        EnsureXcmOrigin<RuntimeOrigin, SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>>;
+    type XcmExecuteFilter = Nothing;
+    type XcmExecutor = XcmExecutor<XcmConfig>;
+    type XcmTeleportFilter = Nothing;
+    type XcmReserveTransferFilter = Nothing;
+    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+    type UniversalLocation = UniversalLocation;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+    type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+    type Currency = Balances;
+    type CurrencyMatcher = ();
+    type TrustedLockers = ();
+    type SovereignAccountOf = LocationToAccountId;
+    type MaxLockers = ConstU32<8>;
+    type WeightInfo = pallet_xcm::TestWeightInfo;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type MaxRemoteLockConsumers = ConstU32<0>;
+    type RemoteLockConsumerIdentifier = ();
+    type AuthorizedAliasConsideration = frame_support::traits::Disabled;
+}
+
+impl xcm_simulator::mock_message_queue::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+impl proof_consumer::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RegistryOrigin = EnsureXcm<Equals<EraLocation>>;
+}
+
+construct_runtime!(
+    pub enum Runtime {
+        System: frame_system,
+        Balances: pallet_balances,
+        MsgQueue: xcm_simulator::mock_message_queue,
+        PolkadotXcm: pallet_xcm,
+        ProofConsumer: proof_consumer,
+    }
+);
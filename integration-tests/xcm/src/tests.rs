@@ -0,0 +1,202 @@
+use crate::*;
+use codec::Encode;
+use polkadot_sdk::frame_support::{
+    assert_noop, assert_ok, traits::PalletInfoAccess, weights::Weight,
+};
+use polkadot_sdk::staging_xcm::latest::prelude::*;
+use shared::types::{
+    CameraInfo, CaptureMetadata, CaptureMetadataV1, ContentId, HashAlgorithm, ProofStatus,
+};
+use shared::traits::context::DisputeObserver;
+use sp_runtime::{traits::BadOrigin, BoundedVec};
+
+const CONTENT: [u8; 32] = [7u8; 32];
+/// Relay tokens the sibling spends on each query.
+const QUERY_FEE: u128 = 1_000_000;
+
+fn era_location() -> Location {
+    Location::new(1, [Parachain(ERA_PARA_ID)])
+}
+
+/// Registers `content` on our chain under a fresh DID of ALICE.
+fn register_content(content: [u8; 32]) -> ContentId {
+    use era::{ContentRegistry, IdentityRegistry, RuntimeOrigin, Timestamp};
+
+    EraPara::execute_with(|| {
+        let did: era::Did = BoundedVec::truncate_from(b"did:era:alice".to_vec());
+        let device: era::Device = BoundedVec::truncate_from(b"camera".to_vec());
+        let origin = RuntimeOrigin::signed(ALICE);
+        assert_ok!(IdentityRegistry::create_did(
+            origin.clone(),
+            did.clone(),
+            BoundedVec::truncate_from(vec![ALICE])
+        ));
+        for right in [shared::types::BaseRight::Impersonate, shared::types::BaseRight::Update] {
+            assert_ok!(IdentityRegistry::add_right_for_signatory(
+                origin.clone(),
+                did.clone(),
+                ALICE,
+                right,
+                pallet_identity_registry::RightDuration::Permanent
+            ));
+        }
+        assert_ok!(IdentityRegistry::register_device(origin.clone(), did.clone(), device.clone()));
+        assert_ok!(ContentRegistry::create_content(
+            origin,
+            did,
+            content,
+            HashAlgorithm::Blake2b256,
+            BoundedVec::truncate_from(b"image".to_vec()),
+            BoundedVec::truncate_from(b"press photo".to_vec()),
+            CaptureMetadata::V1(CaptureMetadataV1 {
+                mime_type: BoundedVec::truncate_from(b"image/jpeg".to_vec()),
+                captured_at: Timestamp::get(),
+                location: None,
+                camera: Some(CameraInfo {
+                    make: BoundedVec::truncate_from(b"Era".to_vec()),
+                    model: BoundedVec::truncate_from(b"Cam 1".to_vec()),
+                    sensor: None,
+                }),
+                software_version: None,
+            }),
+            device,
+            None
+        ));
+    });
+    ContentId::new(HashAlgorithm::Blake2b256, &content)
+}
+
+/// `query_proof_status` on our chain, answered to the sibling's proof consumer. The answer takes
+/// two instructions and the callback on the sibling.
+fn query_call(content_id: ContentId, query_id: u64) -> Vec<u8> {
+    let callback = [sibling::ProofConsumer::index() as u8, 0];
+    era::RuntimeCall::ContentRegistry(pallet_content_registry::Call::query_proof_status {
+        content_id,
+        query_id,
+        callback,
+        response_weight: Weight::from_parts(3_000_000_000, 3 * 64 * 1024),
+    })
+    .encode()
+}
+
+/// Sends `message` from the sibling to our chain and delivers every message it causes.
+fn send_from_sibling(message: Xcm<()>) {
+    SiblingPara::execute_with(|| {
+        assert_ok!(sibling::PolkadotXcm::send_xcm(Here, era_location(), message));
+    });
+}
+
+/// Asks our chain for the status of `content_id`, paying for execution in relay tokens held by
+/// the sibling's sovereign account.
+fn query_proof_status(content_id: ContentId, query_id: u64) {
+    let fee: Asset = (Parent, QUERY_FEE).into();
+    send_from_sibling(Xcm(vec![
+        WithdrawAsset(fee.clone().into()),
+        BuyExecution { fees: fee, weight_limit: Unlimited },
+        Transact {
+            origin_kind: OriginKind::Xcm,
+            fallback_max_weight: None,
+            call: query_call(content_id, query_id).into(),
+        },
+        RefundSurplus,
+        DepositAsset {
+            assets: AllCounted(1).into(),
+            beneficiary: Location::new(1, [Parachain(SIBLING_PARA_ID)]),
+        },
+    ]));
+}
+
+fn answer(query_id: u64) -> Option<(ContentId, ProofStatus<u64>)> {
+    SiblingPara::execute_with(|| sibling::proof_consumer::Answers::<sibling::Runtime>::get(query_id))
+}
+
+#[test]
+fn registered_content_is_reported_trusted() {
+    MockNet::reset();
+    let content_id = register_content(CONTENT);
+
+    query_proof_status(content_id.clone(), 1);
+
+    EraPara::execute_with(|| {
+        era::System::assert_has_event(
+            pallet_content_registry::Event::ProofStatusQueried {
+                querier: Location::new(1, [Parachain(SIBLING_PARA_ID)]),
+                query_id: 1,
+                content_id: content_id.clone(),
+                status: ProofStatus { exists_from: Some(1), disputed: false },
+            }
+            .into(),
+        );
+        // the unspent part of the fee went back to the sibling
+        let balance = era::Balances::free_balance(sibling_account(SIBLING_PARA_ID));
+        assert!(balance < INITIAL_BALANCE && balance > INITIAL_BALANCE - QUERY_FEE);
+    });
+    let (answered_id, status) = answer(1).expect("our chain answered");
+    assert_eq!(answered_id, content_id);
+    assert_eq!(status, ProofStatus { exists_from: Some(1), disputed: false });
+    assert!(status.is_trusted());
+}
+
+#[test]
+fn unknown_content_is_reported_missing() {
+    MockNet::reset();
+    let content_id = ContentId::new(HashAlgorithm::Blake2b256, &CONTENT);
+
+    query_proof_status(content_id.clone(), 2);
+
+    let (answered_id, status) = answer(2).expect("our chain answered");
+    assert_eq!(answered_id, content_id);
+    assert_eq!(status, ProofStatus { exists_from: None, disputed: false });
+    assert!(!status.is_trusted());
+}
+
+#[test]
+fn disputed_content_is_reported_disputed() {
+    MockNet::reset();
+    let content_id = register_content(CONTENT);
+    // what the court reports when a dispute is opened
+    EraPara::execute_with(|| {
+        <era::ContentRegistry as DisputeObserver<u64>>::on_dispute_opened(&content_id, 1)
+    });
+
+    query_proof_status(content_id.clone(), 3);
+
+    let (_, status) = answer(3).expect("our chain answered");
+    assert_eq!(status, ProofStatus { exists_from: Some(1), disputed: true });
+    assert!(!status.is_trusted());
+}
+
+#[test]
+fn unpaid_query_is_not_answered() {
+    MockNet::reset();
+    let content_id = register_content(CONTENT);
+
+    send_from_sibling(Xcm(vec![
+        UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+        Transact {
+            origin_kind: OriginKind::Xcm,
+            fallback_max_weight: None,
+            call: query_call(content_id, 4).into(),
+        },
+    ]));
+
+    assert_eq!(answer(4), None);
+}
+
+#[test]
+fn answers_only_come_from_our_chain() {
+    MockNet::reset();
+    let content_id = ContentId::new(HashAlgorithm::Blake2b256, &CONTENT);
+
+    SiblingPara::execute_with(|| {
+        assert_noop!(
+            sibling::ProofConsumer::on_proof_status(
+                sibling::RuntimeOrigin::signed(ALICE),
+                5,
+                content_id,
+                ProofStatus { exists_from: Some(1), disputed: false },
+            ),
+            BadOrigin
+        );
+    });
+}
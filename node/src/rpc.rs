@@ -8,7 +8,7 @@
 use std::sync::Arc;
 
 //use parachain_template_runtime::{opaque::Block, AccountId, Balance, Content, Did, Nonce};
-use parachain_template_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use parachain_template_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce};
 use shared::types::ContentId;
 
 use polkadot_sdk::*;
@@ -37,14 +37,15 @@ where
     C: ProvideRuntimeApi<Block>
         + HeaderBackend<Block>
         + HeaderMetadata<Block, Error = BlockChainError>
+        + sc_client_api::ProofProvider<Block>
         + Send
         + Sync
         + 'static,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: BlockBuilder<Block>,
-    C::Api: pallet_content_registry_rpc::ContentRegistryApi<Block, ContentId>,
-    C::Api: pallet_context_court_rpc::ContextCourtApi<Block, ContentId>,
+    C::Api: pallet_content_registry_rpc::ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>,
+    C::Api: pallet_context_court_rpc::ContextCourtApi<Block, ContentId, BlockNumber, Balance>,
     P: TransactionPool + Sync + Send + 'static,
 {
     use pallet_content_registry_rpc::{PalletContentRegistry, PalletContentRegistryApiServer};
@@ -2,8 +2,10 @@
 
 extern crate alloc;
 
-//use alloc::vec::Vec;
-use codec::Codec;
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+pub use shared::types::{AttestationKind, LabelKind, License};
 
 // polkadot_sdk::sp_api::decl_runtime_apis! {
 // 	/// This trait contains all the Api's that can be called into from the runtime
@@ -18,13 +20,200 @@ use codec::Codec;
 // 	}
 // }
 
+/// When a proof was registered, as recorded on the proof itself.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct RegistrationTime<BlockNumber> {
+    /// Parachain block the proof was stored in
+    pub exists_from: BlockNumber,
+    /// Relay-chain block the parachain block was built on
+    pub relay_parent_number: BlockNumber,
+    /// `pallet_timestamp` moment in milliseconds since the unix epoch
+    pub moment: u64,
+}
+
+/// A proof as stored on chain. DIDs, devices and the descriptive fields are handed out as the
+/// raw bytes of the runtime's types.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct ProofDetails<ContentId, AccountId, BlockNumber> {
+    pub content_id: ContentId,
+    pub exists_from: BlockNumber,
+    /// `pallet_timestamp` moment in milliseconds since the unix epoch
+    pub registered_at: u64,
+    pub relay_parent_number: BlockNumber,
+    pub did: Vec<u8>,
+    pub signer: AccountId,
+    pub content_type: Vec<u8>,
+    pub content_description: Vec<u8>,
+    /// SCALE-encoded `shared::types::CaptureMetadata`
+    pub content_metadata: Vec<u8>,
+    pub device: Vec<u8>,
+    pub ipfs_cid: Option<Vec<u8>>,
+}
+
+/// One page of a listing, and the cursor to ask for the next page with.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct Page<Item, Cursor> {
+    pub items: Vec<Item>,
+    /// `None` on the last page
+    pub next: Option<Cursor>,
+}
+
+/// Position in a block range listing: the block to continue in and the last id returned from it.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct BlockCursor<BlockNumber, ContentId> {
+    pub block: BlockNumber,
+    pub after: Option<ContentId>,
+}
+
+/// A third-party DID vouching for a proof.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct AttestationDetails<AccountId, BlockNumber> {
+    /// Raw bytes of the attesting DID
+    pub attester: Vec<u8>,
+    pub kind: AttestationKind,
+    pub signer: AccountId,
+    pub comment_hash: Option<[u8; 32]>,
+    pub attested_at: BlockNumber,
+}
+
+/// A license offered for sale. Prices are in the smallest unit of the native currency.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct LicenseOfferDetails {
+    pub license: License,
+    pub price: u128,
+}
+
+/// The license of a proof and the license its owner sells, if any.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct LicenseDetails {
+    pub license: License,
+    pub offer: Option<LicenseOfferDetails>,
+}
+
+/// A license bought for a proof.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct LicenseReceiptDetails<AccountId, BlockNumber> {
+    pub license: License,
+    pub price: u128,
+    pub payee: AccountId,
+    pub purchased_at: BlockNumber,
+}
+
+/// A label a court outcome attached to a proof. The content id and `disputed_at` reference the
+/// dispute it came from.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct LabelDetails<BlockNumber> {
+    pub kind: LabelKind,
+    pub disputed_at: BlockNumber,
+    pub decided_at: BlockNumber,
+}
+
+/// Whether a proof can be trusted, as far as the content registry, the context court and the
+/// identity registry know. DIDs and devices are the raw bytes of the runtime's types.
+///
+/// When several apply, `Retracted` is reported before `DeviceCompromised`, and both before what
+/// the court says about the content.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum VerificationStatus<BlockNumber> {
+    /// No proof is registered under the content id
+    NotRegistered,
+    /// Registered, and nothing speaks against it
+    Registered { did: Vec<u8>, exists_from: BlockNumber },
+    /// A dispute over the context of the content was opened and is not decided yet
+    UnderDispute { did: Vec<u8>, exists_from: BlockNumber, disputed_at: BlockNumber },
+    /// The court found the content was published in a misleading context
+    ContextConvicted { did: Vec<u8>, exists_from: BlockNumber, decided_at: BlockNumber },
+    /// The court rejected a dispute over the context of the content
+    ContextAcquitted { did: Vec<u8>, exists_from: BlockNumber, decided_at: BlockNumber },
+    /// The owning DID withdrew its backing of the proof
+    Retracted { did: Vec<u8>, exists_from: BlockNumber, retracted_at: BlockNumber },
+    /// The device the proof was registered from was revoked by its DID
+    DeviceCompromised {
+        did: Vec<u8>,
+        exists_from: BlockNumber,
+        device: Vec<u8>,
+        revoked_at: BlockNumber,
+    },
+}
+
 polkadot_sdk::sp_api::decl_runtime_apis! {
     /// This trait contains all the Api's that can be called into from the runtime
-    /// into our pallet. To read or perform certain state actions in our blockchain
-    pub trait PalletContentRegistryApi<ContentId>
+    /// into our pallet. To read or perform certain state actions in our blockchain.
+    ///
+    /// Methods added after the first release carry the API version they appeared in.
+    pub trait PalletContentRegistryApi<ContentId, BlockNumber, AccountId>
     where
         ContentId: Codec,
+        BlockNumber: Codec,
+        AccountId: Codec,
     {
+        /// Whether a proof is registered under `id`. Superseded by `verification_status`, which
+        /// also takes disputes, retractions and revoked devices into account.
         fn check_proof_of_reality(id: ContentId) -> bool;
+
+        #[api_version(2)]
+        fn registration_time(id: ContentId) -> Option<RegistrationTime<BlockNumber>>;
+
+        /// The full proof registered under `id`.
+        #[api_version(3)]
+        fn proof(id: ContentId) -> Option<ProofDetails<ContentId, AccountId, BlockNumber>>;
+
+        /// Content of a DID in registration order. The cursor is the index to start at.
+        #[api_version(3)]
+        fn did_contents(did: Vec<u8>, cursor: Option<u32>, limit: u32) -> Page<ContentId, u32>;
+
+        /// Content registered from a device. The cursor is the last id of the previous page.
+        #[api_version(3)]
+        fn device_contents(
+            device: Vec<u8>,
+            cursor: Option<ContentId>,
+            limit: u32,
+        ) -> Page<ContentId, ContentId>;
+
+        /// Content registered in blocks `from..=to`, in block order.
+        #[api_version(3)]
+        fn contents_in_block_range(
+            from: BlockNumber,
+            to: BlockNumber,
+            cursor: Option<BlockCursor<BlockNumber, ContentId>>,
+            limit: u32,
+        ) -> Page<ContentId, BlockCursor<BlockNumber, ContentId>>;
+
+        /// Number of proofs registered on the chain.
+        #[api_version(3)]
+        fn content_count() -> u64;
+
+        #[api_version(3)]
+        fn did_content_count(did: Vec<u8>) -> u32;
+
+        #[api_version(3)]
+        fn device_content_count(device: Vec<u8>) -> u32;
+
+        /// Attestations of the proof registered under `id` by other DIDs.
+        #[api_version(4)]
+        fn attestations(id: ContentId) -> Vec<AttestationDetails<AccountId, BlockNumber>>;
+
+        /// License of the proof registered under `id`, if its owner set one.
+        #[api_version(5)]
+        fn license(id: ContentId) -> Option<LicenseDetails>;
+
+        /// The license `account` bought for the proof registered under `id`.
+        #[api_version(5)]
+        fn license_receipt(
+            id: ContentId,
+            account: AccountId,
+        ) -> Option<LicenseReceiptDetails<AccountId, BlockNumber>>;
+
+        /// Raw storage key of the proof of `id`, whether or not it is registered.
+        #[api_version(6)]
+        fn proof_storage_key(id: ContentId) -> Vec<u8>;
+
+        /// Whether the proof registered under `id` can be trusted, see [`VerificationStatus`].
+        #[api_version(7)]
+        fn verification_status(id: ContentId) -> VerificationStatus<BlockNumber>;
+
+        /// Labels court outcomes attached to the proof registered under `id`.
+        #[api_version(8)]
+        fn labels(id: ContentId) -> Vec<LabelDetails<BlockNumber>>;
     }
 }
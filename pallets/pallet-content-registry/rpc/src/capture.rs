@@ -0,0 +1,124 @@
+use codec::Decode;
+use serde_json::{json, Value};
+use shared::types::{
+    CaptureMetadata, GEO_PRECISION, KNOWN_MIME_TYPES, MAX_CAMERA_FIELD_LENGTH,
+    MAX_MIME_TYPE_LENGTH, MAX_SOFTWARE_VERSION_LENGTH,
+};
+
+/// Capture metadata of a proof, in the shape described by [`capture_metadata_schema`].
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureMetadataJson {
+    pub version: u8,
+    pub mime_type: String,
+    /// Milliseconds since the unix epoch, as claimed by the device
+    pub captured_at: u64,
+    pub location: Option<LocationJson>,
+    pub camera: Option<CameraJson>,
+    pub software_version: Option<String>,
+}
+
+/// Latitude and longitude in degrees, with two decimals.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+pub struct LocationJson {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+pub struct CameraJson {
+    pub make: String,
+    pub model: String,
+    pub sensor: Option<String>,
+}
+
+impl TryFrom<CaptureMetadata> for CaptureMetadataJson {
+    type Error = ();
+
+    /// Fails for the free-form metadata of proofs registered before metadata was versioned,
+    /// which has no place in the schema.
+    fn try_from(metadata: CaptureMetadata) -> Result<Self, ()> {
+        let text = |bytes: &[u8]| String::from_utf8_lossy(bytes).into_owned();
+        let version = metadata.version();
+        match metadata {
+            CaptureMetadata::Legacy(_) => Err(()),
+            CaptureMetadata::V1(metadata) => Ok(Self {
+                version,
+                mime_type: text(&metadata.mime_type),
+                captured_at: metadata.captured_at,
+                location: metadata.location.map(|location| LocationJson {
+                    latitude: f64::from(location.latitude) / f64::from(GEO_PRECISION),
+                    longitude: f64::from(location.longitude) / f64::from(GEO_PRECISION),
+                }),
+                camera: metadata.camera.map(|camera| CameraJson {
+                    make: text(&camera.make),
+                    model: text(&camera.model),
+                    sensor: camera.sensor.map(|sensor| text(&sensor)),
+                }),
+                software_version: metadata.software_version.map(|version| text(&version)),
+            }),
+        }
+    }
+}
+
+impl CaptureMetadataJson {
+    /// Decodes the SCALE-encoded metadata handed out by the runtime API. Returns `None` for
+    /// versions this node does not know yet and for legacy free-form metadata.
+    pub fn decode(encoded: &[u8]) -> Option<Self> {
+        CaptureMetadata::decode(&mut &encoded[..]).ok()?.try_into().ok()
+    }
+}
+
+/// JSON schema (draft 2020-12) of [`CaptureMetadataJson`]. Length limits count UTF-8 bytes on
+/// chain, which is the same as characters for the ASCII values devices send in practice.
+pub fn capture_metadata_schema() -> Value {
+    let text = |max_length: u32| json!({ "type": "string", "maxLength": max_length });
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "CaptureMetadata",
+        "description": "Capture metadata registered with a content proof",
+        "type": "object",
+        "required": ["version", "mimeType", "capturedAt"],
+        "additionalProperties": false,
+        "properties": {
+            "version": { "const": 1 },
+            "mimeType": {
+                "type": "string",
+                "maxLength": MAX_MIME_TYPE_LENGTH,
+                "enum": KNOWN_MIME_TYPES,
+            },
+            "capturedAt": {
+                "description": "Capture time claimed by the device, in milliseconds since the unix epoch. Must be within the runtime's MaxCaptureTimeDrift of the registering block's timestamp.",
+                "type": "integer",
+                "minimum": 0,
+            },
+            "location": {
+                "description": "Coarse location in degrees, rounded to two decimals",
+                "type": ["object", "null"],
+                "required": ["latitude", "longitude"],
+                "additionalProperties": false,
+                "properties": {
+                    "latitude": { "type": "number", "minimum": -90, "maximum": 90 },
+                    "longitude": { "type": "number", "minimum": -180, "maximum": 180 },
+                },
+            },
+            "camera": {
+                "type": ["object", "null"],
+                "required": ["make", "model"],
+                "additionalProperties": false,
+                "properties": {
+                    "make": text(MAX_CAMERA_FIELD_LENGTH),
+                    "model": text(MAX_CAMERA_FIELD_LENGTH),
+                    "sensor": {
+                        "type": ["string", "null"],
+                        "maxLength": MAX_CAMERA_FIELD_LENGTH,
+                    },
+                },
+            },
+            "softwareVersion": {
+                "type": ["string", "null"],
+                "maxLength": MAX_SOFTWARE_VERSION_LENGTH,
+            },
+        },
+    })
+}
@@ -2,7 +2,7 @@
 
 // use polkadot_sdk::*;
 // use codec::Codec;
-// pub use content_runtime_api::PalletContentRegistryApi as ContentRegistryApi;
+// pub use content_runtime_api::{PalletContentRegistryApi as ContentRegistryApi, RegistrationTime};
 // use jsonrpsee::{
 //     core::{Error as JsonRpseeError, RpcResult},
 //     proc_macros::rpc,
@@ -80,12 +80,23 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::Codec;
-pub use content_runtime_api::PalletContentRegistryApi as ContentRegistryApi;
+mod capture;
+mod license;
+
+pub use capture::{capture_metadata_schema, CameraJson, CaptureMetadataJson, LocationJson};
+use codec::{Codec, Encode};
+pub use content_runtime_api::{
+    AttestationDetails, AttestationKind, BlockCursor, LabelDetails, LabelKind, License, LicenseDetails, LicenseOfferDetails,
+    LicenseReceiptDetails, Page, PalletContentRegistryApi as ContentRegistryApi, ProofDetails,
+    RegistrationTime, VerificationStatus,
+};
+pub use license::{ContentLicenseJson, LicenseJson, LicenseOfferJson, LicenseReceiptJson};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
 use polkadot_sdk::*;
-use sp_api::ProvideRuntimeApi;
+use sc_client_api::ProofProvider;
+use sp_api::{ApiExt, ProvideRuntimeApi, RuntimeApiInfo};
 use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
 use sp_runtime::traits::Block as BlockT;
 use std::sync::Arc;
 
@@ -95,11 +106,333 @@ pub struct Custom {
     sum: u32,
 }
 
+/// Registration time of a proof with the wall-clock moment rendered as ISO-8601.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofTimestamp<BlockNumber> {
+    pub exists_from: BlockNumber,
+    pub relay_parent_number: BlockNumber,
+    /// Milliseconds since the unix epoch
+    pub moment: u64,
+    /// e.g. `2026-10-17T10:03:00.000Z`
+    pub timestamp: String,
+}
+
+impl<BlockNumber> From<RegistrationTime<BlockNumber>> for ProofTimestamp<BlockNumber> {
+    fn from(time: RegistrationTime<BlockNumber>) -> Self {
+        Self {
+            exists_from: time.exists_from,
+            relay_parent_number: time.relay_parent_number,
+            moment: time.moment,
+            timestamp: format_iso8601(time.moment),
+        }
+    }
+}
+
+/// A proof as verifier websites consume it. Descriptive fields are decoded as UTF-8, and the
+/// capture metadata as described by `content_CaptureMetadataSchema`.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofJson<ContentId, AccountId, BlockNumber> {
+    pub content_id: ContentId,
+    pub exists_from: BlockNumber,
+    pub relay_parent_number: BlockNumber,
+    /// Milliseconds since the unix epoch
+    pub registered_at: u64,
+    /// `registered_at` as ISO-8601
+    pub timestamp: String,
+    pub did: Bytes,
+    pub signer: AccountId,
+    pub content_type: String,
+    pub content_description: String,
+    /// `null` if the metadata version is newer than this node
+    pub content_metadata: Option<CaptureMetadataJson>,
+    pub device: Bytes,
+    pub ipfs_cid: Option<String>,
+}
+
+impl<ContentId, AccountId, BlockNumber> From<ProofDetails<ContentId, AccountId, BlockNumber>>
+    for ProofJson<ContentId, AccountId, BlockNumber>
+{
+    fn from(proof: ProofDetails<ContentId, AccountId, BlockNumber>) -> Self {
+        let text = |bytes: Vec<u8>| String::from_utf8_lossy(&bytes).into_owned();
+        Self {
+            content_id: proof.content_id,
+            exists_from: proof.exists_from,
+            relay_parent_number: proof.relay_parent_number,
+            registered_at: proof.registered_at,
+            timestamp: format_iso8601(proof.registered_at),
+            did: proof.did.into(),
+            signer: proof.signer,
+            content_type: text(proof.content_type),
+            content_description: text(proof.content_description),
+            content_metadata: CaptureMetadataJson::decode(&proof.content_metadata),
+            device: proof.device.into(),
+            ipfs_cid: proof.ipfs_cid.map(text),
+        }
+    }
+}
+
+/// An attestation of a proof by another DID.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AttestationJson<AccountId, BlockNumber> {
+    pub attester: Bytes,
+    /// `editorial`, `factCheck` or `witness`
+    pub kind: String,
+    pub signer: AccountId,
+    pub comment_hash: Option<sp_core::H256>,
+    pub attested_at: BlockNumber,
+}
+
+impl<AccountId, BlockNumber> From<AttestationDetails<AccountId, BlockNumber>>
+    for AttestationJson<AccountId, BlockNumber>
+{
+    fn from(attestation: AttestationDetails<AccountId, BlockNumber>) -> Self {
+        let kind = match attestation.kind {
+            AttestationKind::Editorial => "editorial",
+            AttestationKind::FactCheck => "factCheck",
+            AttestationKind::Witness => "witness",
+        };
+        Self {
+            attester: attestation.attester.into(),
+            kind: kind.into(),
+            signer: attestation.signer,
+            comment_hash: attestation.comment_hash.map(Into::into),
+            attested_at: attestation.attested_at,
+        }
+    }
+}
+
+/// A label a court outcome attached to a proof.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelJson<BlockNumber> {
+    /// `misleadingContext`
+    pub kind: String,
+    /// Block the dispute behind the label was opened in
+    pub disputed_at: BlockNumber,
+    /// Block of the verdict that attached the label
+    pub decided_at: BlockNumber,
+}
+
+impl<BlockNumber> From<LabelDetails<BlockNumber>> for LabelJson<BlockNumber> {
+    fn from(label: LabelDetails<BlockNumber>) -> Self {
+        let kind = match label.kind {
+            LabelKind::MisleadingContext => "misleadingContext",
+        };
+        Self {
+            kind: kind.into(),
+            disputed_at: label.disputed_at,
+            decided_at: label.decided_at,
+        }
+    }
+}
+
+/// A page of content ids and the cursor to request the next page with.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+pub struct ContentPage<ContentId, Cursor> {
+    pub items: Vec<ContentId>,
+    /// `null` on the last page
+    pub next: Option<Cursor>,
+}
+
+impl<ContentId, Cursor> From<Page<ContentId, Cursor>> for ContentPage<ContentId, Cursor> {
+    fn from(page: Page<ContentId, Cursor>) -> Self {
+        Self {
+            items: page.items,
+            next: page.next,
+        }
+    }
+}
+
+/// Cursor of `content_ContentsInBlockRange`: the block to continue in and the last id returned from it.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+pub struct BlockRangeCursor<BlockNumber, ContentId> {
+    pub block: BlockNumber,
+    pub after: Option<ContentId>,
+}
+
+/// A proof together with what is needed to check it without trusting this node, see the
+/// `content-proof-verifier` crate.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadProofJson<BlockHash> {
+    /// Block the proof was read at
+    pub at: BlockHash,
+    /// SCALE-encoded header of `at`, whose state root the proof leads to
+    pub header: Bytes,
+    /// Storage key of the proof
+    pub key: Bytes,
+    /// Trie nodes proving the value under `key`, or its absence
+    pub proof: Vec<Bytes>,
+}
+
+/// Whether a proof can be trusted, tagged by `status`: `notRegistered`, `registered`,
+/// `underDispute`, `contextConvicted`, `contextAcquitted`, `retracted` or `deviceCompromised`.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "status", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum VerificationStatusJson<BlockNumber> {
+    NotRegistered,
+    Registered { did: Bytes, exists_from: BlockNumber },
+    UnderDispute { did: Bytes, exists_from: BlockNumber, disputed_at: BlockNumber },
+    ContextConvicted { did: Bytes, exists_from: BlockNumber, decided_at: BlockNumber },
+    ContextAcquitted { did: Bytes, exists_from: BlockNumber, decided_at: BlockNumber },
+    Retracted { did: Bytes, exists_from: BlockNumber, retracted_at: BlockNumber },
+    DeviceCompromised { did: Bytes, exists_from: BlockNumber, device: Bytes, revoked_at: BlockNumber },
+}
+
+impl<BlockNumber> From<VerificationStatus<BlockNumber>> for VerificationStatusJson<BlockNumber> {
+    fn from(status: VerificationStatus<BlockNumber>) -> Self {
+        match status {
+            VerificationStatus::NotRegistered => Self::NotRegistered,
+            VerificationStatus::Registered { did, exists_from } => {
+                Self::Registered { did: did.into(), exists_from }
+            },
+            VerificationStatus::UnderDispute { did, exists_from, disputed_at } => {
+                Self::UnderDispute { did: did.into(), exists_from, disputed_at }
+            },
+            VerificationStatus::ContextConvicted { did, exists_from, decided_at } => {
+                Self::ContextConvicted { did: did.into(), exists_from, decided_at }
+            },
+            VerificationStatus::ContextAcquitted { did, exists_from, decided_at } => {
+                Self::ContextAcquitted { did: did.into(), exists_from, decided_at }
+            },
+            VerificationStatus::Retracted { did, exists_from, retracted_at } => {
+                Self::Retracted { did: did.into(), exists_from, retracted_at }
+            },
+            VerificationStatus::DeviceCompromised { did, exists_from, device, revoked_at } => {
+                Self::DeviceCompromised {
+                    did: did.into(),
+                    exists_from,
+                    device: device.into(),
+                    revoked_at,
+                }
+            },
+        }
+    }
+}
+
 #[rpc(client, server)]
-pub trait PalletContentRegistryApi<BlockHash, ContentId: Codec> {
-    /// get the number of accounts that have approved a particular call hash
+pub trait PalletContentRegistryApi<BlockHash, ContentId: Codec, BlockNumber, AccountId> {
+    /// whether a proof is registered; prefer `content_VerificationStatus`, which also reports
+    /// disputes, retractions and revoked devices
     #[method(name = "content_CheckProofOfReality")]
     fn check_proof_of_reality(&self, id: ContentId, at: Option<BlockHash>) -> RpcResult<bool>;
+
+    /// when a proof was registered, by parachain block, relay-chain block and wall-clock time
+    #[method(name = "content_RegistrationTime")]
+    fn registration_time(
+        &self,
+        id: ContentId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ProofTimestamp<BlockNumber>>>;
+
+    /// the full proof registered under `id`
+    #[method(name = "content_Proof")]
+    fn proof(
+        &self,
+        id: ContentId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ProofJson<ContentId, AccountId, BlockNumber>>>;
+
+    /// content of a DID in registration order; `cursor` is the `next` of the previous page
+    #[method(name = "content_DidContents")]
+    fn did_contents(
+        &self,
+        did: Bytes,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<ContentPage<ContentId, u32>>;
+
+    /// content registered from a device; `cursor` is the `next` of the previous page
+    #[method(name = "content_DeviceContents")]
+    fn device_contents(
+        &self,
+        device: Bytes,
+        cursor: Option<ContentId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<ContentPage<ContentId, ContentId>>;
+
+    /// content registered in blocks `from..=to`; `cursor` is the `next` of the previous page
+    #[method(name = "content_ContentsInBlockRange")]
+    fn contents_in_block_range(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+        cursor: Option<BlockRangeCursor<BlockNumber, ContentId>>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<ContentPage<ContentId, BlockRangeCursor<BlockNumber, ContentId>>>;
+
+    /// number of proofs registered on the chain
+    #[method(name = "content_Count")]
+    fn content_count(&self, at: Option<BlockHash>) -> RpcResult<u64>;
+
+    /// number of proofs registered for a DID
+    #[method(name = "content_DidContentCount")]
+    fn did_content_count(&self, did: Bytes, at: Option<BlockHash>) -> RpcResult<u32>;
+
+    /// number of proofs registered from a device
+    #[method(name = "content_DeviceContentCount")]
+    fn device_content_count(&self, device: Bytes, at: Option<BlockHash>) -> RpcResult<u32>;
+
+    /// attestations of a proof by DIDs other than the registering one
+    #[method(name = "content_Attestations")]
+    fn attestations(
+        &self,
+        id: ContentId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<AttestationJson<AccountId, BlockNumber>>>;
+
+    /// license of a proof and the license its owner sells, if the owner set one
+    #[method(name = "content_License")]
+    fn license(&self, id: ContentId, at: Option<BlockHash>) -> RpcResult<Option<ContentLicenseJson>>;
+
+    /// whether `account` bought a license for a proof
+    #[method(name = "content_HoldsLicense")]
+    fn holds_license(
+        &self,
+        account: AccountId,
+        id: ContentId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<bool>;
+
+    /// the license `account` bought for a proof
+    #[method(name = "content_LicenseReceipt")]
+    fn license_receipt(
+        &self,
+        account: AccountId,
+        id: ContentId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<LicenseReceiptJson<AccountId, BlockNumber>>>;
+
+    /// the storage entry of a proof with its read proof and the block header, for verifying it
+    /// offline
+    #[method(name = "content_ReadProof")]
+    fn read_proof(&self, id: ContentId, at: Option<BlockHash>) -> RpcResult<ReadProofJson<BlockHash>>;
+
+    /// whether a proof can be trusted, given court decisions, retractions and revoked devices
+    #[method(name = "content_VerificationStatus")]
+    fn verification_status(
+        &self,
+        id: ContentId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<VerificationStatusJson<BlockNumber>>;
+
+    /// labels court outcomes attached to a proof, e.g. `misleadingContext`
+    #[method(name = "content_Labels")]
+    fn labels(
+        &self,
+        id: ContentId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<LabelJson<BlockNumber>>>;
+
+    /// JSON schema of the capture metadata returned in `content_Proof`
+    #[method(name = "content_CaptureMetadataSchema")]
+    fn capture_metadata_schema(&self) -> RpcResult<serde_json::Value>;
 }
 
 /// A struct that implements the `TemplateApi`.
@@ -120,12 +453,55 @@ impl<C, Block> PalletContentRegistry<C, Block> {
     }
 }
 
-impl<C, Block, ContentId: Codec> PalletContentRegistryApiServer<<Block as BlockT>::Hash, ContentId>
+impl<C, Block> PalletContentRegistry<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+{
+    fn block_hash(&self, at: Option<<Block as BlockT>::Hash>) -> <Block as BlockT>::Hash {
+        // If the block hash is not supplied assume the best block.
+        at.unwrap_or_else(|| self.client.info().best_hash)
+    }
+
+    /// Fails with a readable error when the runtime at `at` predates `version` of `Api`,
+    /// instead of letting the call trap.
+    fn ensure_api_version<Api: RuntimeApiInfo + ?Sized>(
+        &self,
+        at: <Block as BlockT>::Hash,
+        version: u32,
+    ) -> RpcResult<()> {
+        let found = self
+            .client
+            .runtime_api()
+            .api_version::<Api>(at)
+            .map_err(runtime_error_into_rpc_err)?
+            .unwrap_or_default();
+        if found < version {
+            return Err(ErrorObject::owned(
+                API_VERSION_ERROR,
+                "Method not supported by the runtime at this block",
+                Some(format!("requires api version {}, found {}", version, found)),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<C, Block, ContentId: Codec, BlockNumber: Codec, AccountId: Codec>
+    PalletContentRegistryApiServer<<Block as BlockT>::Hash, ContentId, BlockNumber, AccountId>
     for PalletContentRegistry<C, Block>
 where
     Block: BlockT,
-    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: ContentRegistryApi<Block, ContentId>,
+    C: Send
+        + Sync
+        + 'static
+        + ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + ProofProvider<Block>,
+    C::Api: ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>,
+    ContentId: serde::Serialize + Clone + Send + Sync + 'static,
+    BlockNumber: serde::Serialize + Clone + Send + Sync + 'static,
+    AccountId: serde::Serialize + serde::de::DeserializeOwned + Clone + Send + Sync + 'static,
 {
     fn check_proof_of_reality(
         &self,
@@ -139,9 +515,309 @@ where
         api.check_proof_of_reality(block_hash, id)
             .map_err(runtime_error_into_rpc_err)
     }
+
+    fn registration_time(
+        &self,
+        id: ContentId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ProofTimestamp<BlockNumber>>> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 2,
+        )?;
+        let time = self
+            .client
+            .runtime_api()
+            .registration_time(block_hash, id)
+            .map_err(runtime_error_into_rpc_err)?;
+        Ok(time.map(Into::into))
+    }
+
+    fn proof(
+        &self,
+        id: ContentId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ProofJson<ContentId, AccountId, BlockNumber>>> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 3,
+        )?;
+        let proof = self
+            .client
+            .runtime_api()
+            .proof(block_hash, id)
+            .map_err(runtime_error_into_rpc_err)?;
+        Ok(proof.map(Into::into))
+    }
+
+    fn did_contents(
+        &self,
+        did: Bytes,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<ContentPage<ContentId, u32>> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 3,
+        )?;
+        let page = self
+            .client
+            .runtime_api()
+            .did_contents(block_hash, did.to_vec(), cursor, limit)
+            .map_err(runtime_error_into_rpc_err)?;
+        Ok(page.into())
+    }
+
+    fn device_contents(
+        &self,
+        device: Bytes,
+        cursor: Option<ContentId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<ContentPage<ContentId, ContentId>> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 3,
+        )?;
+        let page = self
+            .client
+            .runtime_api()
+            .device_contents(block_hash, device.to_vec(), cursor, limit)
+            .map_err(runtime_error_into_rpc_err)?;
+        Ok(page.into())
+    }
+
+    fn contents_in_block_range(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+        cursor: Option<BlockRangeCursor<BlockNumber, ContentId>>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<ContentPage<ContentId, BlockRangeCursor<BlockNumber, ContentId>>> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 3,
+        )?;
+        let cursor = cursor.map(|cursor| BlockCursor {
+            block: cursor.block,
+            after: cursor.after,
+        });
+        let page = self
+            .client
+            .runtime_api()
+            .contents_in_block_range(block_hash, from, to, cursor, limit)
+            .map_err(runtime_error_into_rpc_err)?;
+        Ok(ContentPage {
+            items: page.items,
+            next: page.next.map(|cursor| BlockRangeCursor {
+                block: cursor.block,
+                after: cursor.after,
+            }),
+        })
+    }
+
+    fn content_count(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u64> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 3,
+        )?;
+        self.client
+            .runtime_api()
+            .content_count(block_hash)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn did_content_count(&self, did: Bytes, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u32> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 3,
+        )?;
+        self.client
+            .runtime_api()
+            .did_content_count(block_hash, did.to_vec())
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn device_content_count(
+        &self,
+        device: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u32> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 3,
+        )?;
+        self.client
+            .runtime_api()
+            .device_content_count(block_hash, device.to_vec())
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn attestations(
+        &self,
+        id: ContentId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<AttestationJson<AccountId, BlockNumber>>> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 4,
+        )?;
+        let attestations = self
+            .client
+            .runtime_api()
+            .attestations(block_hash, id)
+            .map_err(runtime_error_into_rpc_err)?;
+        Ok(attestations.into_iter().map(Into::into).collect())
+    }
+
+    fn license(
+        &self,
+        id: ContentId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ContentLicenseJson>> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 5,
+        )?;
+        let license = self
+            .client
+            .runtime_api()
+            .license(block_hash, id)
+            .map_err(runtime_error_into_rpc_err)?;
+        Ok(license.map(Into::into))
+    }
+
+    fn holds_license(
+        &self,
+        account: AccountId,
+        id: ContentId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<bool> {
+        Ok(self.license_receipt(account, id, at)?.is_some())
+    }
+
+    fn license_receipt(
+        &self,
+        account: AccountId,
+        id: ContentId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<LicenseReceiptJson<AccountId, BlockNumber>>> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 5,
+        )?;
+        let receipt = self
+            .client
+            .runtime_api()
+            .license_receipt(block_hash, id, account)
+            .map_err(runtime_error_into_rpc_err)?;
+        Ok(receipt.map(Into::into))
+    }
+
+    fn read_proof(
+        &self,
+        id: ContentId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<ReadProofJson<<Block as BlockT>::Hash>> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 6,
+        )?;
+        let key = self
+            .client
+            .runtime_api()
+            .proof_storage_key(block_hash, id)
+            .map_err(runtime_error_into_rpc_err)?;
+        let header = self
+            .client
+            .header(block_hash)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or_else(|| runtime_error_into_rpc_err("unknown block"))?;
+        let proof = self
+            .client
+            .read_proof(block_hash, &mut std::iter::once(key.as_slice()))
+            .map_err(runtime_error_into_rpc_err)?;
+        Ok(ReadProofJson {
+            at: block_hash,
+            header: header.encode().into(),
+            key: key.into(),
+            proof: proof.into_iter_nodes().map(Into::into).collect(),
+        })
+    }
+
+    fn verification_status(
+        &self,
+        id: ContentId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<VerificationStatusJson<BlockNumber>> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 7,
+        )?;
+        let status = self
+            .client
+            .runtime_api()
+            .verification_status(block_hash, id)
+            .map_err(runtime_error_into_rpc_err)?;
+        Ok(status.into())
+    }
+
+    fn labels(
+        &self,
+        id: ContentId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<LabelJson<BlockNumber>>> {
+        let block_hash = self.block_hash(at);
+        self.ensure_api_version::<dyn ContentRegistryApi<Block, ContentId, BlockNumber, AccountId>>(
+            block_hash, 8,
+        )?;
+        let labels = self
+            .client
+            .runtime_api()
+            .labels(block_hash, id)
+            .map_err(runtime_error_into_rpc_err)?;
+        Ok(labels.into_iter().map(Into::into).collect())
+    }
+
+    fn capture_metadata_schema(&self) -> RpcResult<serde_json::Value> {
+        Ok(capture_metadata_schema())
+    }
+}
+
+/// Formats milliseconds since the unix epoch as an ISO-8601 UTC timestamp.
+pub fn format_iso8601(moment: u64) -> String {
+    let secs = moment / 1000;
+    let days = (secs / 86_400) as i64;
+    let secs_of_day = secs % 86_400;
+
+    // civil-from-days, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60,
+        moment % 1000
+    )
 }
 
 const RUNTIME_ERROR: i32 = 1;
+const API_VERSION_ERROR: i32 = 2;
 
 /// Converts a runtime trap into an RPC error.
 fn runtime_error_into_rpc_err<'a>(err: impl std::fmt::Debug) -> ErrorObject<'a> {
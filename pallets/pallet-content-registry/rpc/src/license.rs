@@ -0,0 +1,75 @@
+use content_runtime_api::{License, LicenseDetails, LicenseOfferDetails, LicenseReceiptDetails};
+use polkadot_sdk::sp_core::H256;
+
+/// License terms, e.g. `{"type": "spdx", "id": "CC-BY-4.0"}`.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum LicenseJson {
+    AllRightsReserved,
+    Spdx { id: String },
+    /// Blake2-256 hash of a license text kept off chain
+    Custom { hash: H256 },
+}
+
+impl From<License> for LicenseJson {
+    fn from(license: License) -> Self {
+        match license {
+            License::AllRightsReserved => Self::AllRightsReserved,
+            License::Spdx(id) => Self::Spdx { id: String::from_utf8_lossy(&id).into_owned() },
+            License::Custom(hash) => Self::Custom { hash: hash.into() },
+        }
+    }
+}
+
+/// The license a proof is published under and the license its owner sells.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentLicenseJson {
+    pub license: LicenseJson,
+    pub offer: Option<LicenseOfferJson>,
+}
+
+impl From<LicenseDetails> for ContentLicenseJson {
+    fn from(details: LicenseDetails) -> Self {
+        Self { license: details.license.into(), offer: details.offer.map(Into::into) }
+    }
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LicenseOfferJson {
+    pub license: LicenseJson,
+    /// Decimal string in the smallest unit of the native currency, as balances do not fit in a
+    /// JSON number
+    pub price: String,
+}
+
+impl From<LicenseOfferDetails> for LicenseOfferJson {
+    fn from(offer: LicenseOfferDetails) -> Self {
+        Self { license: offer.license.into(), price: offer.price.to_string() }
+    }
+}
+
+/// A license bought for a proof.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LicenseReceiptJson<AccountId, BlockNumber> {
+    pub license: LicenseJson,
+    /// Decimal string, see [`LicenseOfferJson::price`]
+    pub price: String,
+    pub payee: AccountId,
+    pub purchased_at: BlockNumber,
+}
+
+impl<AccountId, BlockNumber> From<LicenseReceiptDetails<AccountId, BlockNumber>>
+    for LicenseReceiptJson<AccountId, BlockNumber>
+{
+    fn from(receipt: LicenseReceiptDetails<AccountId, BlockNumber>) -> Self {
+        Self {
+            license: receipt.license.into(),
+            price: receipt.price.to_string(),
+            payee: receipt.payee,
+            purchased_at: receipt.purchased_at,
+        }
+    }
+}
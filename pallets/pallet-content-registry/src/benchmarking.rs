@@ -1,7 +1,44 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-content-registry
 
 use super::*;
-use frame::{deps::frame_benchmarking::v2::*, prelude::*};
+use crate::migrations::v1;
+use frame::deps::{
+    frame_benchmarking::v2::*,
+    frame_support::{migrations::SteppedMigration, weights::WeightMeter},
+    sp_io::hashing::blake2_256,
+    sp_runtime::traits::TrailingZeroInput,
+};
+use frame::prelude::*;
+use frame::traits::UnixTime;
+use polkadot_sdk::sp_std::vec;
+use shared::types::{
+    CameraInfo, CaptureMetadata, CaptureMetadataV1, ContentId, GeoLocation, HashAlgorithm,
+    MAX_CAMERA_FIELD_LENGTH, MAX_LEGACY_METADATA_LENGTH, MAX_SOFTWARE_VERSION_LENGTH,
+};
+
+fn content_id(seed: u32) -> ContentId {
+    ContentId::new(HashAlgorithm::Blake2b256, &blake2_256(&seed.to_le_bytes()))
+}
+
+fn zeroed<V: Decode>() -> V {
+    V::decode(&mut TrailingZeroInput::zeroes()).expect("zeroes decode into an empty value")
+}
+
+/// Metadata with every optional field filled in to its maximum length.
+fn capture_metadata<T: Config>() -> CaptureMetadata {
+    let field = |len: u32| BoundedVec::truncate_from(vec![b'a'; len as usize]);
+    CaptureMetadata::V1(CaptureMetadataV1 {
+        mime_type: BoundedVec::truncate_from(b"image/x-adobe-dng".to_vec()),
+        captured_at: T::UnixTime::now().as_millis() as u64,
+        location: Some(GeoLocation { latitude: 0, longitude: 0 }),
+        camera: Some(CameraInfo {
+            make: field(MAX_CAMERA_FIELD_LENGTH),
+            model: field(MAX_CAMERA_FIELD_LENGTH),
+            sensor: Some(field(MAX_CAMERA_FIELD_LENGTH)),
+        }),
+        software_version: Some(field(MAX_SOFTWARE_VERSION_LENGTH)),
+    })
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -10,31 +47,69 @@ mod benchmarks {
     use crate::pallet::Pallet as Template;
     use frame_system::RawOrigin;
 
+    /// `n` proofs are already registered for the DID. The weight must not depend on it.
     #[benchmark]
-    fn do_something() {
+    fn create_content(n: Linear<0, 10_000>) {
         let caller: T::AccountId = whitelisted_caller();
-        #[extrinsic_call]
-        do_something(RawOrigin::Signed(caller), 100);
+        let (did, device) = T::BenchmarkHelper::setup_did(&caller);
+        for index in 0..n {
+            DidContentIndex::<T>::insert(&did, index, content_id(index));
+        }
+        DidContentCount::<T>::insert(&did, n);
+        let content = T::BenchmarkHelper::content(u32::MAX);
 
-        assert_eq!(
-            Something::<T>::get().map(|v| v.block_number),
-            Some(100u32.into())
+        #[extrinsic_call]
+        create_content(
+            RawOrigin::Signed(caller),
+            did.clone(),
+            content,
+            HashAlgorithm::Blake2b256,
+            zeroed(),
+            zeroed(),
+            capture_metadata::<T>(),
+            device,
+            None,
         );
+
+        assert_eq!(DidContentCount::<T>::get(&did), n + 1);
     }
 
+    /// Moves a single entry of the old `DidContents` layout, together with its proof.
     #[benchmark]
-    fn cause_error() {
-        Something::<T>::put(CompositeStruct {
-            block_number: 100u32.into(),
-        });
-        let caller: T::AccountId = whitelisted_caller();
-        #[extrinsic_call]
-        cause_error(RawOrigin::Signed(caller));
-
-        assert_eq!(
-            Something::<T>::get().map(|v| v.block_number),
-            Some(101u32.into())
+    fn migration_v1_step() {
+        StorageVersion::new(0).put::<Pallet<T>>();
+        let did: T::Did = zeroed();
+        let content = T::BenchmarkHelper::content(0);
+        let old_id = v1::old::OldContentId::from_hash(&blake2_256(&content.encode()));
+        v1::old::Proofs::<T>::insert(
+            &old_id,
+            v1::old::Proof::<T> {
+                content_id: old_id.clone(),
+                exists_from: Zero::zero(),
+                did: did.clone(),
+                signer: whitelisted_caller(),
+                content: content.clone(),
+                content_type: zeroed(),
+                content_description: zeroed(),
+                content_metadata: BoundedVec::truncate_from(vec![
+                    b'a';
+                    MAX_LEGACY_METADATA_LENGTH as usize
+                ]),
+                device: zeroed(),
+            },
         );
+        v1::old::DidContents::<T>::insert(&did, vec![old_id]);
+        let mut meter = WeightMeter::new();
+
+        #[block]
+        {
+            v1::LazyMigrationV1::<T, ()>::step(None, &mut meter).unwrap();
+        }
+
+        let content_id = v1::content_id_of::<T>(&content);
+        assert_eq!(DidContentIndex::<T>::get(&did, 0), Some(content_id.clone()));
+        assert_eq!(DidContentCount::<T>::get(&did), 1);
+        assert!(Proofs::<T>::contains_key(&content_id));
     }
 
     impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,88 @@
+use super::*;
+use frame::prelude::*;
+use shared::traits::{content::ContentInspector, context::DisputeObserver};
+use shared::types::{ContentId, ContextVerdict, LabelKind};
+
+impl<T: Config> ContentInspector<T::Did, T::Device, BlockNumberFor<T>> for Pallet<T> {
+    fn exists(content_id: &ContentId) -> bool {
+        Proofs::<T>::contains_key(content_id)
+    }
+
+    fn owner(content_id: &ContentId) -> Option<T::Did> {
+        Proofs::<T>::get(content_id).map(|proof| proof.did)
+    }
+
+    fn device(content_id: &ContentId) -> Option<T::Device> {
+        Proofs::<T>::get(content_id).map(|proof| proof.device)
+    }
+
+    fn registered_at(content_id: &ContentId) -> Option<BlockNumberFor<T>> {
+        Proofs::<T>::get(content_id).map(|proof| proof.exists_from)
+    }
+
+    fn legacy_content_id(hash: &[u8; 32]) -> Option<ContentId> {
+        crate::migrations::v1::legacy_content_id::<T>(hash)
+    }
+}
+
+/// Keeps track of disputes so `proof_status` can answer without asking the court.
+impl<T: Config> DisputeObserver<BlockNumberFor<T>> for Pallet<T> {
+    fn on_dispute_opened(content_id: &ContentId, at: BlockNumberFor<T>) {
+        ContextDisputes::<T>::insert(content_id, ContextDispute::<T> { opened_at: at, verdict: None });
+        Self::deposit_event(Event::ContentDisputed { content_id: content_id.clone() });
+    }
+
+    /// A conviction labels the content as misleading. A later verdict on the same dispute is
+    /// an appeal outcome, and an acquittal then removes the label again.
+    fn on_verdict(content_id: &ContentId, verdict: ContextVerdict, at: BlockNumberFor<T>) {
+        let disputed_at = ContextDisputes::<T>::mutate(content_id, |dispute| {
+            // disputes opened before the court reported to this pallet are only seen once decided
+            let dispute = dispute.get_or_insert(ContextDispute::<T> { opened_at: at, verdict: None });
+            dispute.verdict = Some((verdict, at));
+            dispute.opened_at
+        });
+        Self::deposit_event(Event::ContextVerdictReached { content_id: content_id.clone(), verdict });
+
+        let Some(owner) = Proofs::<T>::get(content_id).map(|proof| proof.did) else {
+            return;
+        };
+        let kind = LabelKind::MisleadingContext;
+        match verdict {
+            ContextVerdict::Convicted => {
+                Labels::<T>::insert(content_id, kind, ContentLabel::<T> { disputed_at, decided_at: at });
+                Self::deposit_event(Event::LabelAttached {
+                    content_id: content_id.clone(),
+                    owner,
+                    kind,
+                    disputed_at,
+                    decided_at: at,
+                });
+            },
+            ContextVerdict::Acquitted => {
+                if Labels::<T>::take(content_id, kind).is_some() {
+                    Self::deposit_event(Event::LabelRemoved {
+                        content_id: content_id.clone(),
+                        owner,
+                        kind,
+                        decided_at: at,
+                    });
+                }
+            },
+        }
+    }
+
+    /// A voided dispute leaves the content as it was before, a verdict of an earlier round
+    /// stands.
+    fn on_dispute_voided(content_id: &ContentId, _at: BlockNumberFor<T>) {
+        let voided = ContextDisputes::<T>::mutate_exists(content_id, |dispute| {
+            let open = dispute.as_ref().is_some_and(|dispute| dispute.verdict.is_none());
+            if open {
+                *dispute = None;
+            }
+            open
+        });
+        if voided {
+            Self::deposit_event(Event::ContentDisputeVoided { content_id: content_id.clone() });
+        }
+    }
+}
@@ -8,21 +8,90 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod impl_content;
+pub mod migrations;
+pub mod queries;
 pub mod weights;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+use frame::deps::sp_core::crypto::KeyTypeId;
+
+/// Key type of the accounts the offchain worker signs availability reports with.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"ipfs");
+
+/// Offchain local storage key a node operator can set (e.g. through `offchain_localStorageSet`)
+/// to point the availability checks at another IPFS gateway than `Config::IpfsGateway`.
+pub const IPFS_GATEWAY_KEY: &[u8] = b"content-registry::ipfs-gateway";
+
+/// Crypto used by the offchain worker to sign availability reports.
+pub mod crypto {
+    use super::KEY_TYPE;
+    use frame::deps::sp_core::sr25519::{Public as Sr25519Public, Signature as Sr25519Signature};
+    use frame::deps::sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        MultiSignature, MultiSigner,
+    };
+    app_crypto!(sr25519, KEY_TYPE);
+
+    pub struct AvailabilityAuthId;
+
+    impl frame::deps::frame_system::offchain::AppCrypto<MultiSigner, MultiSignature>
+        for AvailabilityAuthId
+    {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = Sr25519Signature;
+        type GenericPublic = Sr25519Public;
+    }
+}
+
+/// Prepares the state the benchmarks of this pallet run against.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, Did, Device, Content> {
+    /// Creates a DID `who` may register content for, and a device owned by that DID.
+    fn setup_did(who: &AccountId) -> (Did, Device);
+    /// A valid 32-byte content digest, distinct for every `seed`.
+    fn content(seed: u32) -> Content;
+}
+
 #[frame::pallet]
 pub mod pallet {
-    use frame::prelude::{OptionQuery, ValueQuery, *};
+    use crate::weights::WeightInfo;
+    use frame::deps::sp_io;
+    use frame::deps::sp_runtime::offchain::{
+        http,
+        storage::{StorageRetrievalError, StorageValueRef},
+        Duration,
+    };
+    use frame::prelude::{
+        fungible::{Mutate, MutateHold},
+        OptionQuery, ValueQuery, *,
+    };
+    use frame::traits::UnixTime;
+    use polkadot_sdk::sp_std::vec::Vec;
+    use polkadot_sdk::staging_xcm::latest::{
+        send_xcm, Instruction, Location, OriginKind, SendXcm, WeightLimit, Xcm,
+    };
     use shared::traits::identity::DidManager;
-    use shared::types::{BaseRight, ContentId};
+    use shared::types::{
+        is_known_mime_type, AttestationKind, BaseRight, CaptureMetadata, ContentId, ContextVerdict,
+        HashAlgorithm, LabelKind, License, ProofStatus,
+    };
+
+    /// Balance of the currency licenses are paid in.
+    pub type BalanceOf<T> = <<T as Config>::NativeBalance as fungible::Inspect<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
 
+    /// How long the offchain worker waits for the gateway before giving up on an object.
+    const FETCH_TIMEOUT_MS: u64 = 10_000;
+    /// Number of pending checks the offchain worker handles per block.
+    const CHECKS_PER_BLOCK: usize = 5;
 
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: CreateInherent<Call<Self>> + SigningTypes + frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// A type representing the weights required by the dispatchables of this pallet.
@@ -40,9 +109,9 @@ pub mod pallet {
             + Default
             + From<BaseRight>
             + Into<BaseRight>;
-        type MaxContentInVec: Get<u32>;
 
         type DidRegistry: DidManager<Self::AccountId, Self::Did, Self::Device, Self::GivenRight>;
+        /// Client-computed digest of the media, in one of the supported `HashAlgorithm`s
         type Content: Parameter
             + Member
             + MaxEncodedLen
@@ -50,35 +119,103 @@ pub mod pallet {
             + Eq
             + Default
             + core::hash::Hash
+            + AsRef<[u8]>
             + Encode
             + Decode
             + TypeInfo;
-            
-        //type ContentId: Parameter + Member + MaxEncodedLen + Clone + Eq ;
+
         type ContentType: Parameter + Member + MaxEncodedLen + Clone + Eq;
         type ContentDescription: Parameter + Member + MaxEncodedLen + Clone + Eq;
-        type ContentMetadata: Parameter + Member + MaxEncodedLen + Clone + Eq;
 
+        /// Currency licenses are paid in and proof deposits are held in
+        type NativeBalance: fungible::Inspect<Self::AccountId>
+            + fungible::Mutate<Self::AccountId>
+            + fungible::hold::Inspect<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + fungible::hold::Mutate<Self::AccountId>;
+        /// Overarching hold reason
+        type RuntimeHoldReason: From<HoldReason>;
+        /// Deposit held from the signer for every proof stored
+        #[pallet::constant]
+        type ProofDeposit: Get<BalanceOf<Self>>;
+        /// Deposit held from the signer for every commitment until it is revealed, expires or is
+        /// withdrawn
+        #[pallet::constant]
+        type CommitmentDeposit: Get<BalanceOf<Self>>;
+        /// Length, in blocks, of the window registration quotas apply to
+        #[pallet::constant]
+        type QuotaPeriod: Get<BlockNumberFor<Self>>;
+        /// Proofs a DID may register per `QuotaPeriod` unless governance set another quota
+        #[pallet::constant]
+        type DefaultQuota: Get<u32>;
+        /// Origin that sets the quota of a DID, e.g. to raise it for verified organizations
+        type QuotaOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin of proof status queries from other chains, resolving to the querying location
+        type XcmQueryOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Location>;
+        /// Sends the answers to proof status queries
+        type XcmSender: SendXcm;
+        /// Largest weight a querying chain may ask its answer to be executed with
+        #[pallet::constant]
+        type MaxResponseWeight: Get<Weight>;
+        /// Wall-clock time source, usually `pallet_timestamp`
+        type UnixTime: UnixTime;
+        /// Provides the relay-chain parent block number the current block is built on
+        type RelayBlockNumberProvider: BlockNumberProvider;
+        /// How far, in milliseconds, the capture time claimed in the metadata may be from chain time
+        #[pallet::constant]
+        type MaxCaptureTimeDrift: Get<u64>;
+
+        /// Maximum length of the IPFS CID stored on a proof
+        #[pallet::constant]
+        type MaxIpfsCidLength: Get<u32>;
+        /// Base URL of the IPFS HTTP gateway, e.g. `http://127.0.0.1:8080`. Overridable per node
+        /// through the offchain local storage key [`crate::IPFS_GATEWAY_KEY`]
+        type IpfsGateway: Get<&'static str>;
+        /// Largest IPFS object, in bytes, the offchain worker downloads to re-hash
+        #[pallet::constant]
+        type MaxIpfsObjectSize: Get<u32>;
+        /// Maximum number of proofs waiting for an availability check
+        #[pallet::constant]
+        type MaxPendingChecks: Get<u32>;
+        /// Blocks the offchain worker waits before retrying a check it already submitted
+        #[pallet::constant]
+        type CheckRetryInterval: Get<BlockNumberFor<Self>>;
+        /// Maximum number of accounts allowed to report on availability
+        #[pallet::constant]
+        type MaxReporters: Get<u32>;
+        /// Origin that manages the set of availability reporters
+        type ReporterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Key the offchain worker signs availability reports with
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+        /// Priority of the unsigned availability reports in the transaction pool
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+        /// Maximum number of commitments scheduled to expire in the same block
+        #[pallet::constant]
+        type MaxCommitmentExpiriesPerBlock: Get<u32>;
+        /// Maximum number of DIDs that can attest the same proof
+        #[pallet::constant]
+        type MaxAttestationsPerContent: Get<u32>;
+
+        /// Sets up the identities the benchmarks register content for
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: crate::BenchmarkHelper<
+            Self::AccountId,
+            Self::Did,
+            Self::Device,
+            Self::Content,
+        >;
     }
 
+    pub type RelayBlockNumberOf<T> =
+        <<T as Config>::RelayBlockNumberProvider as BlockNumberProvider>::BlockNumber;
+
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
-    // #[derive(Encode, Decode, Clone, Eq, PartialEq, Default, TypeInfo, MaxEncodedLen, Debug, DecodeWithMemTracking)]
-    // pub struct ContentId {
-    //     prefix: [u8; 4],
-    //     hash: [u8; 32],
-    // }
-
-    // impl ContentId {
-    //     pub fn new(prefix: &[u8], hash: &[u8]) -> Self {
-    //         let mut content_id = ContentId::default();
-    //         content_id.prefix.copy_from_slice(prefix);
-    //         content_id.hash.copy_from_slice(hash);
-    //         content_id
-    //     }
-    // }
-
     #[pallet::composite_enum]
     #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo, DecodeWithMemTracking)]
     pub enum HoldReason {
@@ -86,6 +223,12 @@ pub mod pallet {
         JurorAccountCreation,
         #[codec(index = 1)]
         CallCreation,
+        /// Deposit for the storage a proof takes up
+        #[codec(index = 2)]
+        ProofDeposit,
+        /// Deposit for the storage a commitment takes up until it is revealed
+        #[codec(index = 3)]
+        CommitmentDeposit,
     }
 
     #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, MaxEncodedLen)]
@@ -93,29 +236,196 @@ pub mod pallet {
     pub struct Proof<T: Config> {
         pub content_id: ContentId,
         pub exists_from: BlockNumberFor<T>,
+        /// `pallet_timestamp` moment of the registering block, in milliseconds since the unix epoch
+        pub registered_at: u64,
+        /// Relay-chain block the registering block was built on
+        pub relay_parent_number: RelayBlockNumberOf<T>,
         pub did: T::Did,
         pub signer: T::AccountId,
         pub content: T::Content,
         pub content_type: T::ContentType,
         pub content_description: T::ContentDescription,
-        pub content_metadata: T::ContentMetadata,
+        pub content_metadata: CaptureMetadata,
+        pub device: T::Device,
+        /// CID under which the media was uploaded to IPFS, if any
+        pub ipfs_cid: Option<BoundedVec<u8, T::MaxIpfsCidLength>>,
+    }
+
+    /// Salted hash of a DID, device and content id, see [`Pallet::commitment_hash`].
+    pub type CommitmentHash = [u8; 32];
+
+    /// Content committed to but not revealed yet. Holds everything a `Proof` records about when
+    /// and by whom it was registered, so the proof made on reveal dates from the commit.
+    #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Commitment<T: Config> {
+        pub did: T::Did,
         pub device: T::Device,
+        pub signer: T::AccountId,
+        pub committed_at: BlockNumberFor<T>,
+        /// `pallet_timestamp` moment of the commit, in milliseconds since the unix epoch
+        pub registered_at: u64,
+        pub relay_parent_number: RelayBlockNumberOf<T>,
+        /// Block the commitment is dropped in unless revealed before
+        pub expires_at: Option<BlockNumberFor<T>>,
+        /// Held from `signer` until the commitment is revealed, expires or is withdrawn
+        pub deposit: BalanceOf<T>,
+    }
+
+    /// A third-party DID vouching for a proof.
+    #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Attestation<T: Config> {
+        pub kind: AttestationKind,
+        /// Account that signed on behalf of the attesting DID
+        pub signer: T::AccountId,
+        /// Hash of a comment kept off chain, e.g. a fact-check article
+        pub comment_hash: Option<[u8; 32]>,
+        pub attested_at: BlockNumberFor<T>,
+    }
+
+    /// A license the owner of a proof sells, and its price.
+    #[derive(
+        Debug,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        TypeInfo,
+        PartialEq,
+        Eq,
+        Clone,
+        MaxEncodedLen,
+    )]
+    pub struct LicenseOffer<Balance> {
+        pub license: License,
+        pub price: Balance,
+    }
+
+    /// The license a proof is published under, and the license its owner sells, if any.
+    #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ContentLicense<T: Config> {
+        pub license: License,
+        pub offer: Option<LicenseOffer<BalanceOf<T>>>,
+    }
+
+    /// Record of a license bought for a proof.
+    #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct LicenseReceipt<T: Config> {
+        pub license: License,
+        pub price: BalanceOf<T>,
+        /// Payout account of the owning DID at the time of purchase
+        pub payee: T::AccountId,
+        pub purchased_at: BlockNumberFor<T>,
+    }
+
+    /// Proofs a DID registered in the current quota window.
+    #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct QuotaUsage<T: Config> {
+        /// Block the window started in. It ends `QuotaPeriod` blocks later, and the next
+        /// registration after that starts a new one
+        pub window_start: BlockNumberFor<T>,
+        pub used: u32,
+    }
+
+    /// A dispute over the context of a proof, as reported by the context court.
+    #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ContextDispute<T: Config> {
+        pub opened_at: BlockNumberFor<T>,
+        /// `None` while the dispute is open
+        pub verdict: Option<(ContextVerdict, BlockNumberFor<T>)>,
+    }
+
+    impl<T: Config> ContextDispute<T> {
+        /// Whether the dispute is open or ended in a conviction.
+        pub fn is_disputed(&self) -> bool {
+            !matches!(self.verdict, Some((ContextVerdict::Acquitted, _)))
+        }
+    }
+
+    /// A label attached to a proof by a court outcome. The court keeps one dispute per content
+    /// id, so the content id and `disputed_at` reference the dispute it came from.
+    #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ContentLabel<T: Config> {
+        /// Block the dispute was opened in
+        pub disputed_at: BlockNumberFor<T>,
+        /// Block of the verdict that attached the label
+        pub decided_at: BlockNumberFor<T>,
+    }
+
+    /// When a proof counts as registered.
+    struct Registration<T: Config> {
+        exists_from: BlockNumberFor<T>,
+        registered_at: u64,
+        relay_parent_number: RelayBlockNumberOf<T>,
+    }
+
+    /// Outcome of the offchain worker fetching and re-hashing the IPFS object of a proof.
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub enum ContentStatus {
+        /// The object was fetched and hashes to the registered content id
+        Available,
+        /// The object was fetched but hashes to a different digest
+        HashMismatch,
+        /// The gateway did not serve the object before the deadline
+        Unavailable,
+        /// The object is larger than `MaxIpfsObjectSize` and was not hashed
+        TooLarge,
+    }
+
+    #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct AvailabilityCheck<T: Config> {
+        pub status: ContentStatus,
+        pub checked_at: BlockNumberFor<T>,
+        pub reporter: T::AccountId,
+    }
+
+    /// Payload the offchain worker signs when reporting on a pending check.
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo,
+    )]
+    pub struct AvailabilityReport<Public, BlockNumber> {
+        pub content_id: ContentId,
+        pub status: ContentStatus,
+        pub block_number: BlockNumber,
+        pub public: Public,
+    }
+
+    impl<T: SigningTypes> SignedPayload<T> for AvailabilityReport<T::Public, BlockNumberFor<T>> {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
     }
 
     // hash of the content is the content_id, so we can check if it exists
     // did -> cid -> bool
-    // did -> Vec<ContentId>
+    // did -> index -> ContentId
     // cid -> Content
 
+    /// Content of a DID in registration order, keyed by its position in the list.
     #[pallet::storage]
-    #[pallet::getter(fn get_did_contents)]
-    pub type DidContents<T: Config> = StorageMap<
-        _,
-        Blake2_128Concat,
-        T::Did,
-        BoundedVec<ContentId, T::MaxContentInVec>,
-        ValueQuery,
-    >;
+    pub type DidContentIndex<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::Did, Twox64Concat, u32, ContentId, OptionQuery>;
+
+    /// Number of entries in `DidContentIndex` for a DID, which is also the next free index.
+    #[pallet::storage]
+    pub type DidContentCount<T: Config> = StorageMap<_, Blake2_128Concat, T::Did, u32, ValueQuery>;
 
     /// double map for easy lookup if DID content exists.
     #[pallet::storage]
@@ -127,6 +437,152 @@ pub mod pallet {
     #[pallet::getter(fn get_content)]
     pub type Proofs<T: Config> = StorageMap<_, Blake2_128Concat, ContentId, Proof<T>, OptionQuery>;
 
+    /// Content registered from a device, for lookups by device.
+    #[pallet::storage]
+    pub type DeviceContents<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::Device, Blake2_128Concat, ContentId, (), OptionQuery>;
+
+    #[pallet::storage]
+    pub type DeviceContentCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Device, u32, ValueQuery>;
+
+    /// Content registered in a block, for block range lookups.
+    #[pallet::storage]
+    pub type BlockContents<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        ContentId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Total number of proofs ever registered.
+    #[pallet::storage]
+    #[pallet::getter(fn content_count)]
+    pub type ContentCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Proofs with an IPFS CID that still wait for the offchain worker to check them.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_availability_checks)]
+    pub type PendingAvailabilityChecks<T: Config> =
+        StorageValue<_, BoundedVec<ContentId, T::MaxPendingChecks>, ValueQuery>;
+
+    /// Last reported availability of the IPFS object behind a proof.
+    #[pallet::storage]
+    #[pallet::getter(fn content_status)]
+    pub type ContentStatuses<T: Config> =
+        StorageMap<_, Blake2_128Concat, ContentId, AvailabilityCheck<T>, OptionQuery>;
+
+    /// Accounts whose offchain workers may report on availability.
+    #[pallet::storage]
+    #[pallet::getter(fn availability_reporters)]
+    pub type AvailabilityReporters<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxReporters>, ValueQuery>;
+
+    /// Attestations of a proof by DIDs other than the registering one, one per DID.
+    #[pallet::storage]
+    #[pallet::getter(fn attestation)]
+    pub type Attestations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ContentId,
+        Blake2_128Concat,
+        T::Did,
+        Attestation<T>,
+        OptionQuery,
+    >;
+
+    /// Number of attestations of a proof, kept within `MaxAttestationsPerContent`.
+    #[pallet::storage]
+    #[pallet::getter(fn attestation_count)]
+    pub type AttestationCount<T: Config> = StorageMap<_, Blake2_128Concat, ContentId, u32, ValueQuery>;
+
+    /// Commitments waiting to be revealed.
+    #[pallet::storage]
+    #[pallet::getter(fn commitment)]
+    pub type Commitments<T: Config> =
+        StorageMap<_, Blake2_128Concat, CommitmentHash, Commitment<T>, OptionQuery>;
+
+    /// Commitments to drop at the start of a block.
+    #[pallet::storage]
+    pub type CommitmentExpiries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<CommitmentHash, T::MaxCommitmentExpiriesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// License of a proof, set by its owning DID.
+    #[pallet::storage]
+    #[pallet::getter(fn license)]
+    pub type Licenses<T: Config> =
+        StorageMap<_, Blake2_128Concat, ContentId, ContentLicense<T>, OptionQuery>;
+
+    /// Licenses bought for a proof, by buying account.
+    #[pallet::storage]
+    #[pallet::getter(fn license_receipt)]
+    pub type LicenseReceipts<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ContentId,
+        Blake2_128Concat,
+        T::AccountId,
+        LicenseReceipt<T>,
+        OptionQuery,
+    >;
+
+    /// Account license sales of a DID's content are paid to.
+    #[pallet::storage]
+    #[pallet::getter(fn payout_account)]
+    pub type PayoutAccounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Did, T::AccountId, OptionQuery>;
+
+    /// Deposit held for a proof, and the account it is held from.
+    #[pallet::storage]
+    #[pallet::getter(fn proof_deposit)]
+    pub type ProofDeposits<T: Config> =
+        StorageMap<_, Blake2_128Concat, ContentId, (T::AccountId, BalanceOf<T>), OptionQuery>;
+
+    /// Quota of a DID set by `QuotaOrigin`, in place of `DefaultQuota`.
+    #[pallet::storage]
+    pub type DidQuotas<T: Config> = StorageMap<_, Blake2_128Concat, T::Did, u32, OptionQuery>;
+
+    /// Registrations of a DID in its current quota window.
+    #[pallet::storage]
+    #[pallet::getter(fn quota_usage)]
+    pub type QuotaUsages<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Did, QuotaUsage<T>, OptionQuery>;
+
+    /// Disputes over the context of proofs, reported by the context court.
+    #[pallet::storage]
+    #[pallet::getter(fn context_dispute)]
+    pub type ContextDisputes<T: Config> =
+        StorageMap<_, Blake2_128Concat, ContentId, ContextDispute<T>, OptionQuery>;
+
+    /// Labels court outcomes attached to a proof. Only a later outcome of the same dispute, i.e.
+    /// an appeal, removes them.
+    #[pallet::storage]
+    #[pallet::getter(fn label)]
+    pub type Labels<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ContentId,
+        Blake2_128Concat,
+        LabelKind,
+        ContentLabel<T>,
+        OptionQuery,
+    >;
+
+    /// Block the owning DID retracted a proof in. Retracted proofs stay registered, so the
+    /// content keeps its history, but are no longer vouched for by their owner.
+    #[pallet::storage]
+    #[pallet::getter(fn retracted_at)]
+    pub type Retractions<T: Config> =
+        StorageMap<_, Blake2_128Concat, ContentId, BlockNumberFor<T>, OptionQuery>;
+
     /// Pallets use events to inform users when important changes are made.
     /// <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/guides/your_first_pallet/index.html#event-and-error>
     #[pallet::event]
@@ -144,6 +600,102 @@ pub mod pallet {
             content: T::Content,
             did: T::Did,
         },
+        AvailabilityReported {
+            content_id: ContentId,
+            status: ContentStatus,
+            reporter: T::AccountId,
+        },
+        /// The proof was registered without an availability check, as `MaxPendingChecks` proofs
+        /// already wait for one
+        AvailabilityCheckSkipped {
+            content_id: ContentId,
+        },
+        AvailabilityReportersSet {
+            reporters: BoundedVec<T::AccountId, T::MaxReporters>,
+        },
+        ContentCommitted {
+            commitment: CommitmentHash,
+            did: T::Did,
+            who: T::AccountId,
+            expires_at: Option<BlockNumberFor<T>>,
+        },
+        CommitmentRevealed {
+            commitment: CommitmentHash,
+            content_id: ContentId,
+        },
+        CommitmentExpired {
+            commitment: CommitmentHash,
+        },
+        CommitmentWithdrawn {
+            commitment: CommitmentHash,
+        },
+        ContentAttested {
+            content_id: ContentId,
+            attester: T::Did,
+            who: T::AccountId,
+            kind: AttestationKind,
+        },
+        AttestationRevoked {
+            content_id: ContentId,
+            attester: T::Did,
+        },
+        LicenseSet {
+            content_id: ContentId,
+            license: License,
+            offer: Option<LicenseOffer<BalanceOf<T>>>,
+        },
+        LicensePurchased {
+            content_id: ContentId,
+            buyer: T::AccountId,
+            payee: T::AccountId,
+            license: License,
+            price: BalanceOf<T>,
+        },
+        PayoutAccountSet {
+            did: T::Did,
+            account: Option<T::AccountId>,
+        },
+        DidQuotaSet {
+            did: T::Did,
+            quota: Option<u32>,
+        },
+        ProofStatusQueried {
+            querier: Location,
+            query_id: u64,
+            content_id: ContentId,
+            status: ProofStatus<BlockNumberFor<T>>,
+        },
+        ContentRetracted {
+            content_id: ContentId,
+            did: T::Did,
+            who: T::AccountId,
+        },
+        ContentDisputed {
+            content_id: ContentId,
+        },
+        ContextVerdictReached {
+            content_id: ContentId,
+            verdict: ContextVerdict,
+        },
+        /// The dispute over the content ended without a verdict, it is trusted again.
+        ContentDisputeVoided {
+            content_id: ContentId,
+        },
+        /// A court outcome labelled content of `owner`.
+        LabelAttached {
+            content_id: ContentId,
+            owner: T::Did,
+            kind: LabelKind,
+            disputed_at: BlockNumberFor<T>,
+            decided_at: BlockNumberFor<T>,
+        },
+        /// An appeal outcome removed a label from content of `owner`.
+        LabelRemoved {
+            content_id: ContentId,
+            owner: T::Did,
+            kind: LabelKind,
+            decided_at: BlockNumberFor<T>,
+        },
     }
 
     #[pallet::error]
@@ -162,44 +714,699 @@ pub mod pallet {
         CouldNotPushContent,
         /// Device not owned
         DeviceNotOwned,
+        /// Content digest does not match the length of the hash algorithm
+        InvalidContentDigest,
+        /// IPFS CID contains characters outside of the multibase alphabets
+        InvalidIpfsCid,
+        /// Account is not allowed to report on availability
+        NotAReporter,
+        /// Content is not waiting for an availability check
+        NoPendingCheck,
+        /// The MIME type in the capture metadata is not one of the known MIME types
+        UnknownMimeType,
+        /// The claimed capture time is further from chain time than `MaxCaptureTimeDrift`
+        CaptureTimeOutOfRange,
+        /// Latitude or longitude of the capture location is out of range
+        InvalidLocation,
+        /// A commitment with the same hash is already pending
+        CommitmentAlreadyExists,
+        /// No pending commitment with this hash, it was revealed, expired or never made
+        UnknownCommitment,
+        /// The revealed content, salt, DID and device do not hash to the commitment
+        CommitmentMismatch,
+        /// Only the account that made a commitment may withdraw it
+        NotCommitmentSigner,
+        /// Commitments cannot expire in the block they are made in
+        InvalidCommitmentExpiry,
+        /// Too many commitments already expire in the requested block
+        TooManyCommitmentExpiries,
+        /// No proof is registered under this content id
+        ContentNotFound,
+        /// The DID that registered a proof cannot attest it
+        CannotAttestOwnContent,
+        /// The DID already attested this proof
+        AlreadyAttested,
+        /// The proof has reached `MaxAttestationsPerContent`
+        TooManyAttestations,
+        /// The DID has not attested this proof
+        AttestationNotFound,
+        /// The SPDX identifier is not one of the known license identifiers
+        UnknownLicense,
+        /// The owner of the proof does not sell a license for it
+        NoLicenseOffer,
+        /// The offered license costs more than the buyer is willing to pay
+        PriceAboveLimit,
+        /// The account already bought a license for this proof
+        AlreadyLicensed,
+        /// The DID owning the proof has no payout account to pay the license to
+        NoPayoutAccount,
+        /// The DID registered its quota of proofs for the current window
+        QuotaExceeded,
+        /// The signer cannot afford the proof deposit
+        InsufficientDeposit,
+        /// The answer to a proof status query could not be sent to the querying chain
+        ResponseNotSent,
+        /// The owner of the proof retracted it
+        ContentRetracted,
+        /// The weight asked for the answer to a proof status query exceeds `MaxResponseWeight`
+        ResponseWeightTooHigh,
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            Self::expire_commitments(n)
+        }
+
+        fn offchain_worker(block_number: BlockNumberFor<T>) {
+            if let Err(e) = Self::check_pending_availability(block_number) {
+                frame::log::warn!(target: "runtime::content-registry", "availability check failed: {}", e);
+            }
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let Call::report_availability { report, signature } = call else {
+                return InvalidTransaction::Call.into();
+            };
+            if !SignedPayload::<T>::verify::<T::AuthorityId>(report, signature.clone()) {
+                return InvalidTransaction::BadProof.into();
+            }
+            let reporter = report.public.clone().into_account();
+            if !AvailabilityReporters::<T>::get().contains(&reporter) {
+                return InvalidTransaction::BadSigner.into();
+            }
+            if !PendingAvailabilityChecks::<T>::get().contains(&report.content_id) {
+                return InvalidTransaction::Stale.into();
+            }
+            ValidTransaction::with_tag_prefix("ContentAvailability")
+                .priority(T::UnsignedPriority::get())
+                // one report settles a check, whoever sends it first
+                .and_provides(&report.content_id)
+                .longevity(5)
+                .propagate(true)
+                .build()
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(5))]
+        #[pallet::weight(T::WeightInfo::create_content())]
+        #[allow(clippy::too_many_arguments)]
         pub fn create_content(
             origin: OriginFor<T>,
             did: T::Did,
             content: T::Content,
+            algorithm: HashAlgorithm,
             content_type: T::ContentType,
             content_description: T::ContentDescription,
-            content_metadata: T::ContentMetadata,
+            content_metadata: CaptureMetadata,
             device: T::Device,
+            ipfs_cid: Option<BoundedVec<u8, T::MaxIpfsCidLength>>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let is_valid = <T as Config>::DidRegistry::is_signer_valid(
+            Self::ensure_can_register(&who, &did, &device)?;
+            Self::store_proof(
+                who,
+                did,
+                device,
+                content,
+                algorithm,
+                content_type,
+                content_description,
+                content_metadata,
+                ipfs_cid,
+                Registration {
+                    exists_from: frame_system::Pallet::<T>::block_number(),
+                    registered_at: T::UnixTime::now().as_millis().saturated_into::<u64>(),
+                    relay_parent_number: T::RelayBlockNumberProvider::current_block_number(),
+                },
+            )
+        }
+
+        /// Records the outcome of an availability check done by a reporter's offchain worker.
+        #[pallet::call_index(1)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2, 2))]
+        pub fn report_availability(
+            origin: OriginFor<T>,
+            report: AvailabilityReport<T::Public, BlockNumberFor<T>>,
+            // checked in `validate_unsigned`
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            let reporter = report.public.into_account();
+            ensure!(
+                AvailabilityReporters::<T>::get().contains(&reporter),
+                Error::<T>::NotAReporter
+            );
+            PendingAvailabilityChecks::<T>::try_mutate(|pending| -> DispatchResult {
+                let index = pending
+                    .iter()
+                    .position(|id| id == &report.content_id)
+                    .ok_or(Error::<T>::NoPendingCheck)?;
+                pending.remove(index);
+                Ok(())
+            })?;
+            ContentStatuses::<T>::insert(
+                &report.content_id,
+                AvailabilityCheck::<T> {
+                    status: report.status,
+                    checked_at: frame_system::Pallet::<T>::block_number(),
+                    reporter: reporter.clone(),
+                },
+            );
+            Self::deposit_event(Event::AvailabilityReported {
+                content_id: report.content_id,
+                status: report.status,
+                reporter,
+            });
+            Ok(())
+        }
+
+        #[pallet::call_index(2)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+        pub fn set_availability_reporters(
+            origin: OriginFor<T>,
+            reporters: BoundedVec<T::AccountId, T::MaxReporters>,
+        ) -> DispatchResult {
+            T::ReporterOrigin::ensure_origin(origin)?;
+            AvailabilityReporters::<T>::put(&reporters);
+            Self::deposit_event(Event::AvailabilityReportersSet { reporters });
+            Ok(())
+        }
+        /// Commits to content without revealing it. `commitment` is
+        /// [`Pallet::commitment_hash`] of the DID, the device, the content id and a secret salt.
+        /// If `expires_in` is given, the commitment is dropped that many blocks from now unless
+        /// it was revealed before. `CommitmentDeposit` is held from the signer until then.
+        #[pallet::call_index(3)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(6, 4))]
+        pub fn commit_content(
+            origin: OriginFor<T>,
+            did: T::Did,
+            device: T::Device,
+            commitment: CommitmentHash,
+            expires_in: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_can_register(&who, &did, &device)?;
+            ensure!(
+                !Commitments::<T>::contains_key(commitment),
+                Error::<T>::CommitmentAlreadyExists
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let expires_at = match expires_in {
+                Some(blocks) => {
+                    ensure!(!blocks.is_zero(), Error::<T>::InvalidCommitmentExpiry);
+                    let expires_at = now.saturating_add(blocks);
+                    CommitmentExpiries::<T>::try_mutate(expires_at, |expiring| {
+                        expiring
+                            .try_push(commitment)
+                            .map_err(|_| Error::<T>::TooManyCommitmentExpiries)
+                    })?;
+                    Some(expires_at)
+                },
+                None => None,
+            };
+            let deposit = T::CommitmentDeposit::get();
+            T::NativeBalance::hold(&HoldReason::CommitmentDeposit.into(), &who, deposit)
+                .map_err(|_| Error::<T>::InsufficientDeposit)?;
+            Commitments::<T>::insert(
+                commitment,
+                Commitment::<T> {
+                    did: did.clone(),
+                    device,
+                    signer: who.clone(),
+                    committed_at: now,
+                    registered_at: T::UnixTime::now().as_millis().saturated_into::<u64>(),
+                    relay_parent_number: T::RelayBlockNumberProvider::current_block_number(),
+                    expires_at,
+                    deposit,
+                },
+            );
+            Self::deposit_event(Event::ContentCommitted { commitment, did, who, expires_at });
+            Ok(())
+        }
+
+        /// Reveals the content behind a commitment and registers it as a proof that exists from
+        /// the block of the commitment. The capture time is checked against the commitment time.
+        #[pallet::call_index(4)]
+        #[pallet::weight(
+            T::WeightInfo::create_content() + T::DbWeight::get().reads_writes(4, 4)
+        )]
+        #[allow(clippy::too_many_arguments)]
+        pub fn reveal_content(
+            origin: OriginFor<T>,
+            commitment: CommitmentHash,
+            salt: [u8; 32],
+            content: T::Content,
+            algorithm: HashAlgorithm,
+            content_type: T::ContentType,
+            content_description: T::ContentDescription,
+            content_metadata: CaptureMetadata,
+            ipfs_cid: Option<BoundedVec<u8, T::MaxIpfsCidLength>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let pending = Commitments::<T>::get(commitment).ok_or(Error::<T>::UnknownCommitment)?;
+            // rights may have been revoked since the commit
+            Self::ensure_can_register(&who, &pending.did, &pending.device)?;
+
+            let content_id = ContentId::from_digest(algorithm, content.as_ref())
+                .map_err(|_| Error::<T>::InvalidContentDigest)?;
+            ensure!(
+                Self::commitment_hash(&pending.did, &pending.device, &content_id, &salt)
+                    == commitment,
+                Error::<T>::CommitmentMismatch
+            );
+
+            Self::drop_commitment(commitment, &pending);
+            Self::store_proof(
+                who,
+                pending.did,
+                pending.device,
+                content,
+                algorithm,
+                content_type,
+                content_description,
+                content_metadata,
+                ipfs_cid,
+                Registration {
+                    exists_from: pending.committed_at,
+                    registered_at: pending.registered_at,
+                    relay_parent_number: pending.relay_parent_number,
+                },
+            )?;
+            Self::deposit_event(Event::CommitmentRevealed { commitment, content_id });
+            Ok(())
+        }
+
+        /// Attests an existing proof on behalf of `attester`, a DID other than the one that
+        /// registered it. The signer needs the `Attest` right on `attester`.
+        #[pallet::call_index(5)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4, 2))]
+        pub fn attest_content(
+            origin: OriginFor<T>,
+            content_id: ContentId,
+            attester: T::Did,
+            kind: AttestationKind,
+            comment_hash: Option<[u8; 32]>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_can_attest(&who, &attester)?;
+            let proof = Proofs::<T>::get(&content_id).ok_or(Error::<T>::ContentNotFound)?;
+            ensure!(proof.did != attester, Error::<T>::CannotAttestOwnContent);
+            ensure!(!Retractions::<T>::contains_key(&content_id), Error::<T>::ContentRetracted);
+            ensure!(
+                !Attestations::<T>::contains_key(&content_id, &attester),
+                Error::<T>::AlreadyAttested
+            );
+            AttestationCount::<T>::try_mutate(&content_id, |count| -> DispatchResult {
+                ensure!(*count < T::MaxAttestationsPerContent::get(), Error::<T>::TooManyAttestations);
+                *count += 1;
+                Ok(())
+            })?;
+            Attestations::<T>::insert(
+                &content_id,
+                &attester,
+                Attestation::<T> {
+                    kind,
+                    signer: who.clone(),
+                    comment_hash,
+                    attested_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+            Self::deposit_event(Event::ContentAttested { content_id, attester, who, kind });
+            Ok(())
+        }
+
+        /// Withdraws the attestation `attester` made for a proof.
+        #[pallet::call_index(6)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(3, 2))]
+        pub fn revoke_attestation(
+            origin: OriginFor<T>,
+            content_id: ContentId,
+            attester: T::Did,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_can_attest(&who, &attester)?;
+            ensure!(
+                Attestations::<T>::take(&content_id, &attester).is_some(),
+                Error::<T>::AttestationNotFound
+            );
+            AttestationCount::<T>::mutate_exists(&content_id, |count| {
+                *count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+            });
+            Self::deposit_event(Event::AttestationRevoked { content_id, attester });
+            Ok(())
+        }
+
+        /// Sets the license a proof is published under, and optionally a license buyers can
+        /// pay for. The signer needs the `Update` right on the DID that registered the proof.
+        /// Licenses already bought keep their receipts.
+        #[pallet::call_index(7)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2, 1))]
+        pub fn set_license(
+            origin: OriginFor<T>,
+            content_id: ContentId,
+            license: License,
+            offer: Option<LicenseOffer<BalanceOf<T>>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let proof = Proofs::<T>::get(&content_id).ok_or(Error::<T>::ContentNotFound)?;
+            Self::ensure_can_update(&who, &proof.did)?;
+            ensure!(license.is_valid(), Error::<T>::UnknownLicense);
+            if let Some(offer) = &offer {
+                ensure!(offer.license.is_valid(), Error::<T>::UnknownLicense);
+            }
+            Licenses::<T>::insert(
+                &content_id,
+                ContentLicense::<T> { license: license.clone(), offer: offer.clone() },
+            );
+            Self::deposit_event(Event::LicenseSet { content_id, license, offer });
+            Ok(())
+        }
+
+        /// Buys the license offered for a proof. The price is paid to the payout account of
+        /// the owning DID, and fails if it is above `max_price`, e.g. because the owner raised
+        /// it after the buyer looked.
+        #[pallet::call_index(8)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(6, 3))]
+        pub fn purchase_license(
+            origin: OriginFor<T>,
+            content_id: ContentId,
+            max_price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let proof = Proofs::<T>::get(&content_id).ok_or(Error::<T>::ContentNotFound)?;
+            ensure!(!Retractions::<T>::contains_key(&content_id), Error::<T>::ContentRetracted);
+            let offer = Licenses::<T>::get(&content_id)
+                .and_then(|license| license.offer)
+                .ok_or(Error::<T>::NoLicenseOffer)?;
+            ensure!(offer.price <= max_price, Error::<T>::PriceAboveLimit);
+            ensure!(
+                !LicenseReceipts::<T>::contains_key(&content_id, &who),
+                Error::<T>::AlreadyLicensed
+            );
+            let payee = PayoutAccounts::<T>::get(&proof.did).ok_or(Error::<T>::NoPayoutAccount)?;
+
+            T::NativeBalance::transfer(&who, &payee, offer.price, Preservation::Preserve)?;
+            LicenseReceipts::<T>::insert(
+                &content_id,
                 &who,
-                &did,
+                LicenseReceipt::<T> {
+                    license: offer.license.clone(),
+                    price: offer.price,
+                    payee: payee.clone(),
+                    purchased_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+            Self::deposit_event(Event::LicensePurchased {
+                content_id,
+                buyer: who,
+                payee,
+                license: offer.license,
+                price: offer.price,
+            });
+            Ok(())
+        }
+
+        /// Sets or clears the account license sales of a DID's content are paid to. The signer
+        /// needs the `Update` right on the DID.
+        #[pallet::call_index(9)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+        pub fn set_payout_account(
+            origin: OriginFor<T>,
+            did: T::Did,
+            account: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_can_update(&who, &did)?;
+            PayoutAccounts::<T>::set(&did, account.clone());
+            Self::deposit_event(Event::PayoutAccountSet { did, account });
+            Ok(())
+        }
+
+        /// Sets the number of proofs `did` may register per `QuotaPeriod`, or resets it to
+        /// `DefaultQuota` with `None`.
+        #[pallet::call_index(10)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+        pub fn set_did_quota(origin: OriginFor<T>, did: T::Did, quota: Option<u32>) -> DispatchResult {
+            T::QuotaOrigin::ensure_origin(origin)?;
+            DidQuotas::<T>::set(&did, quota);
+            Self::deposit_event(Event::DidQuotaSet { did, quota });
+            Ok(())
+        }
+
+        /// Answers another chain, usually through an XCM `Transact`, whether `content_id` is
+        /// registered and undisputed. The answer is sent back as a `Transact` with an XCM origin
+        /// of this chain, calling `callback` (the pallet and call index of a call on the querying
+        /// chain taking `(query_id, content_id, status)`).
+        ///
+        /// The answer is sent without paying for execution, so the querying chain must allow
+        /// unpaid execution from this chain. Its execution there is limited to `response_weight`,
+        /// at most `MaxResponseWeight`.
+        #[pallet::call_index(11)]
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0)
+                + T::DbWeight::get().reads(3)
+                + Pallet::<T>::send_response_weight()
+        )]
+        pub fn query_proof_status(
+            origin: OriginFor<T>,
+            content_id: ContentId,
+            query_id: u64,
+            callback: [u8; 2],
+            response_weight: Weight,
+        ) -> DispatchResult {
+            let querier = T::XcmQueryOrigin::ensure_origin(origin)?;
+            ensure!(
+                response_weight.all_lte(T::MaxResponseWeight::get()),
+                Error::<T>::ResponseWeightTooHigh
+            );
+            let status = Self::proof_status(&content_id);
+
+            let mut call = callback.to_vec();
+            (query_id, &content_id, &status).encode_to(&mut call);
+            let message = Xcm(vec![
+                Instruction::UnpaidExecution {
+                    weight_limit: WeightLimit::Limited(response_weight),
+                    check_origin: None,
+                },
+                Instruction::Transact {
+                    origin_kind: OriginKind::Xcm,
+                    fallback_max_weight: Some(response_weight),
+                    call: call.into(),
+                },
+            ]);
+            send_xcm::<T::XcmSender>(querier.clone(), message)
+                .map_err(|_| Error::<T>::ResponseNotSent)?;
+
+            Self::deposit_event(Event::ProofStatusQueried { querier, query_id, content_id, status });
+            Ok(())
+        }
+
+        /// Withdraws the owner's backing of a proof, e.g. because the content was staged or
+        /// published by mistake. The signer needs the `Update` right on the DID that registered
+        /// it. The proof stays registered, but can no longer be attested or licensed. Its deposit
+        /// is released to the account it was held from.
+        #[pallet::call_index(12)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(6, 4))]
+        pub fn retract_content(origin: OriginFor<T>, content_id: ContentId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let proof = Proofs::<T>::get(&content_id).ok_or(Error::<T>::ContentNotFound)?;
+            Self::ensure_can_update(&who, &proof.did)?;
+            ensure!(!Retractions::<T>::contains_key(&content_id), Error::<T>::ContentRetracted);
+            Retractions::<T>::insert(&content_id, frame_system::Pallet::<T>::block_number());
+            // a retracted proof needs no availability check, free its place in the queue
+            PendingAvailabilityChecks::<T>::mutate(|pending| pending.retain(|id| id != &content_id));
+            if let Some((depositor, deposit)) = ProofDeposits::<T>::take(&content_id) {
+                T::NativeBalance::release(
+                    &HoldReason::ProofDeposit.into(),
+                    &depositor,
+                    deposit,
+                    Precision::BestEffort,
+                )?;
+            }
+            Self::deposit_event(Event::ContentRetracted { content_id, did: proof.did, who });
+            Ok(())
+        }
+
+        /// Drops a commitment that will not be revealed and releases its deposit. Only the
+        /// account that made the commitment may withdraw it.
+        #[pallet::call_index(13)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4, 4))]
+        pub fn withdraw_commitment(origin: OriginFor<T>, commitment: CommitmentHash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let pending = Commitments::<T>::get(commitment).ok_or(Error::<T>::UnknownCommitment)?;
+            ensure!(pending.signer == who, Error::<T>::NotCommitmentSigner);
+            Self::drop_commitment(commitment, &pending);
+            Self::deposit_event(Event::CommitmentWithdrawn { commitment });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Hash a client commits to before revealing `content_id`. The DID and device are part of
+        /// the preimage, so a commitment cannot be revealed under another identity.
+        pub fn commitment_hash(
+            did: &T::Did,
+            device: &T::Device,
+            content_id: &ContentId,
+            salt: &[u8; 32],
+        ) -> CommitmentHash {
+            (did, device, content_id, salt).using_encoded(sp_io::hashing::blake2_256)
+        }
+
+        /// Checks that `who` may register content for `did` from `device`.
+        fn ensure_can_register(who: &T::AccountId, did: &T::Did, device: &T::Device) -> DispatchResult {
+            let is_valid = <T as Config>::DidRegistry::is_signer_valid(
+                who,
+                did,
                 &T::GivenRight::from(BaseRight::Impersonate),
             )
             .map_err(|_| Error::<T>::CouldNotGetResponse)?;
             ensure!(is_valid, Error::<T>::SignerDoesNotHaveRight);
 
-            let owned_devices = <T as Config>::DidRegistry::read_did_devices(&did)
+            let owned_devices = <T as Config>::DidRegistry::read_did_devices(did)
                 .map_err(|_| Error::<T>::CouldNotGetResponse)?;
-            ensure!(owned_devices.contains(&device), Error::<T>::DeviceNotOwned);
-            let prefix = b"cid:".as_slice();
-            let hash = blake2_256(&content.encode());
-            let content_id = ContentId::new(prefix, &hash);
+            ensure!(owned_devices.contains(device), Error::<T>::DeviceNotOwned);
+            Ok(())
+        }
+
+        /// Checks that `who` may attest content on behalf of `attester`.
+        fn ensure_can_attest(who: &T::AccountId, attester: &T::Did) -> DispatchResult {
+            let is_valid = <T as Config>::DidRegistry::is_signer_valid(
+                who,
+                attester,
+                &T::GivenRight::from(BaseRight::Attest),
+            )
+            .map_err(|_| Error::<T>::CouldNotGetResponse)?;
+            ensure!(is_valid, Error::<T>::SignerDoesNotHaveRight);
+            Ok(())
+        }
+
+        /// Checks that `who` may manage the content of `did`.
+        fn ensure_can_update(who: &T::AccountId, did: &T::Did) -> DispatchResult {
+            let is_valid = <T as Config>::DidRegistry::is_signer_valid(
+                who,
+                did,
+                &T::GivenRight::from(BaseRight::Update),
+            )
+            .map_err(|_| Error::<T>::CouldNotGetResponse)?;
+            ensure!(is_valid, Error::<T>::SignerDoesNotHaveRight);
+            Ok(())
+        }
+
+        /// Raw storage key of the proof of `content_id`, for requesting a read proof of it.
+        pub fn proof_storage_key(content_id: &ContentId) -> Vec<u8> {
+            Proofs::<T>::hashed_key_for(content_id)
+        }
+
+        /// Weight of handing an answer to `XcmSender`: the XCMP queue reads the channel and its
+        /// outbound page and writes them back, and the delivery fee factor is read.
+        fn send_response_weight() -> Weight {
+            Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(3, 2)
+        }
+
+        /// Whether `content_id` is registered, and whether it is disputed.
+        pub fn proof_status(content_id: &ContentId) -> ProofStatus<BlockNumberFor<T>> {
+            ProofStatus {
+                exists_from: Proofs::<T>::get(content_id).map(|proof| proof.exists_from),
+                disputed: ContextDisputes::<T>::get(content_id)
+                    .is_some_and(|dispute| dispute.is_disputed()),
+            }
+        }
+
+        /// Proofs `did` may register per `QuotaPeriod`.
+        pub fn did_quota(did: &T::Did) -> u32 {
+            DidQuotas::<T>::get(did).unwrap_or_else(T::DefaultQuota::get)
+        }
+
+        /// Counts a registration against the quota of `did`, starting a new window if the last
+        /// one is over.
+        fn use_quota(did: &T::Did) -> DispatchResult {
+            let now = frame_system::Pallet::<T>::block_number();
+            let quota = Self::did_quota(did);
+            QuotaUsages::<T>::try_mutate(did, |usage| {
+                let usage = match usage {
+                    Some(usage) if now < usage.window_start.saturating_add(T::QuotaPeriod::get()) => {
+                        usage
+                    },
+                    _ => usage.insert(QuotaUsage { window_start: now, used: 0 }),
+                };
+                ensure!(usage.used < quota, Error::<T>::QuotaExceeded);
+                usage.used.saturating_inc();
+                Ok(())
+            })
+        }
+
+        /// Whether `who` bought a license for a proof.
+        pub fn holds_license(content_id: &ContentId, who: &T::AccountId) -> bool {
+            LicenseReceipts::<T>::contains_key(content_id, who)
+        }
+
+        /// Attestations of a proof, keyed by attesting DID.
+        pub fn attestations(content_id: &ContentId) -> Vec<(T::Did, Attestation<T>)> {
+            Attestations::<T>::iter_prefix(content_id).collect()
+        }
+
+        /// Labels court outcomes attached to a proof.
+        pub fn labels(content_id: &ContentId) -> Vec<(LabelKind, ContentLabel<T>)> {
+            Labels::<T>::iter_prefix(content_id).collect()
+        }
+
+        /// Stores a new proof with all of its indexes, as registered at `registration`.
+        #[allow(clippy::too_many_arguments)]
+        fn store_proof(
+            who: T::AccountId,
+            did: T::Did,
+            device: T::Device,
+            content: T::Content,
+            algorithm: HashAlgorithm,
+            content_type: T::ContentType,
+            content_description: T::ContentDescription,
+            content_metadata: CaptureMetadata,
+            ipfs_cid: Option<BoundedVec<u8, T::MaxIpfsCidLength>>,
+            registration: Registration<T>,
+        ) -> DispatchResult {
+            // the content is already a digest computed by the client, so the id is the same
+            // one IPFS and C2PA tools derive for the file
+            let content_id = ContentId::from_digest(algorithm, content.as_ref())
+                .map_err(|_| Error::<T>::InvalidContentDigest)?;
 
             ensure!(
                 !Proofs::<T>::contains_key(&content_id),
                 Error::<T>::ContentAlreadyExists
             );
+            Self::validate_capture_metadata(&content_metadata, registration.registered_at)?;
+            if let Some(cid) = &ipfs_cid {
+                // the CID ends up in the gateway URL, so keep it to the multibase alphabets
+                ensure!(
+                    !cid.is_empty() && cid.iter().all(u8::is_ascii_alphanumeric),
+                    Error::<T>::InvalidIpfsCid
+                );
+                // a full queue must not block registrations, the check is what gets dropped
+                let queued = PendingAvailabilityChecks::<T>::mutate(|pending| {
+                    pending.try_push(content_id.clone()).is_ok()
+                });
+                if !queued {
+                    Self::deposit_event(Event::AvailabilityCheckSkipped {
+                        content_id: content_id.clone(),
+                    });
+                }
+            }
+            let index = DidContentCount::<T>::get(&did);
+            let next_index = index.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+            Self::use_quota(&did)?;
+            let deposit = T::ProofDeposit::get();
+            T::NativeBalance::hold(&HoldReason::ProofDeposit.into(), &who, deposit)
+                .map_err(|_| Error::<T>::InsufficientDeposit)?;
+            ProofDeposits::<T>::insert(&content_id, (who.clone(), deposit));
             let ctx = Proof::<T> {
                 content_id: content_id.clone(),
                 signer: who.clone(),
@@ -209,16 +1416,19 @@ pub mod pallet {
                 content_type,
                 content_description,
                 content_metadata,
-                exists_from: frame_system::Pallet::<T>::block_number(),
+                exists_from: registration.exists_from,
+                registered_at: registration.registered_at,
+                relay_parent_number: registration.relay_parent_number,
+                ipfs_cid,
             };
             Proofs::<T>::insert(&content_id, &ctx);
             DidContentExists::<T>::insert(&did, &content_id, true);
-            DidContents::<T>::try_mutate(&did, |contents| -> DispatchResult {
-                contents
-                    .try_push(content_id.clone())
-                    .map_err(|_| Error::<T>::CouldNotPushContent)?;
-                Ok(())
-            })?;
+            DeviceContents::<T>::insert(&ctx.device, &content_id, ());
+            DeviceContentCount::<T>::mutate(&ctx.device, |count| count.saturating_inc());
+            BlockContents::<T>::insert(ctx.exists_from, &content_id, ());
+            ContentCount::<T>::mutate(|count| count.saturating_inc());
+            DidContentIndex::<T>::insert(&did, index, &content_id);
+            DidContentCount::<T>::insert(&did, next_index);
 
             Self::deposit_event(Event::ContentStored {
                 block_number: <frame_system::Pallet<T>>::block_number(),
@@ -229,5 +1439,168 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Drops the commitments that were scheduled to expire at block `n`.
+        fn expire_commitments(n: BlockNumberFor<T>) -> Weight {
+            let expiring = CommitmentExpiries::<T>::take(n);
+            for commitment in &expiring {
+                if let Some(pending) = Commitments::<T>::take(commitment) {
+                    Self::release_commitment_deposit(&pending);
+                    Self::deposit_event(Event::CommitmentExpired { commitment: *commitment });
+                }
+            }
+            T::DbWeight::get().reads_writes(1, 1).saturating_add(
+                T::DbWeight::get().reads_writes(3, 3).saturating_mul(expiring.len() as u64),
+            )
+        }
+
+        /// Removes a commitment and its expiry, and releases its deposit.
+        fn drop_commitment(commitment: CommitmentHash, pending: &Commitment<T>) {
+            Commitments::<T>::remove(commitment);
+            if let Some(expires_at) = pending.expires_at {
+                CommitmentExpiries::<T>::mutate(expires_at, |expiring| {
+                    expiring.retain(|c| c != &commitment)
+                });
+            }
+            Self::release_commitment_deposit(pending);
+        }
+
+        fn release_commitment_deposit(pending: &Commitment<T>) {
+            let _ = T::NativeBalance::release(
+                &HoldReason::CommitmentDeposit.into(),
+                &pending.signer,
+                pending.deposit,
+                Precision::BestEffort,
+            );
+        }
+
+        /// Checks the capture metadata of a new proof against the known MIME types and the chain
+        /// time `now`, in milliseconds.
+        fn validate_capture_metadata(metadata: &CaptureMetadata, now: u64) -> DispatchResult {
+            ensure!(is_known_mime_type(metadata.mime_type()), Error::<T>::UnknownMimeType);
+            ensure!(
+                metadata.captured_at().abs_diff(now) <= T::MaxCaptureTimeDrift::get(),
+                Error::<T>::CaptureTimeOutOfRange
+            );
+            if let Some(location) = metadata.location() {
+                ensure!(location.is_valid(), Error::<T>::InvalidLocation);
+            }
+            Ok(())
+        }
+
+        /// Fetches the IPFS objects of a few pending proofs and submits a signed report for each.
+        fn check_pending_availability(
+            block_number: BlockNumberFor<T>,
+        ) -> Result<(), &'static str> {
+            let signer = Signer::<T, T::AuthorityId>::any_account();
+            if !signer.can_sign() {
+                return Err("no local key to sign availability reports with");
+            }
+            let gateway = Self::ipfs_gateway();
+            let pending = PendingAvailabilityChecks::<T>::get();
+            let mut checked = 0;
+            for content_id in pending {
+                if checked == CHECKS_PER_BLOCK {
+                    break;
+                }
+                let Some(cid) = Proofs::<T>::get(&content_id).and_then(|proof| proof.ipfs_cid)
+                else {
+                    continue;
+                };
+                if !Self::acquire_check(&content_id, block_number) {
+                    continue;
+                }
+                checked += 1;
+
+                let status = Self::check_object(&gateway, &cid, &content_id);
+                let sent = signer.send_unsigned_transaction(
+                    |account| AvailabilityReport {
+                        content_id: content_id.clone(),
+                        status,
+                        block_number,
+                        public: account.public.clone(),
+                    },
+                    |report, signature| Call::report_availability { report, signature },
+                );
+                if let Some((_, Err(()))) = sent {
+                    frame::log::warn!(
+                        target: "runtime::content-registry",
+                        "could not submit availability report for {:?}",
+                        content_id
+                    );
+                }
+            }
+            Ok(())
+        }
+
+        /// Marks the check as attempted in local storage, so it is not repeated every block while
+        /// the report waits for inclusion. Returns false if it was attempted recently.
+        fn acquire_check(content_id: &ContentId, block_number: BlockNumberFor<T>) -> bool {
+            let key = (b"content-registry::checked", content_id).encode();
+            let last_attempt = StorageValueRef::persistent(&key);
+            last_attempt
+                .mutate(
+                    |last: Result<Option<BlockNumberFor<T>>, StorageRetrievalError>| match last {
+                        Ok(Some(at))
+                            if block_number < at.saturating_add(T::CheckRetryInterval::get()) =>
+                            Err(()),
+                        _ => Ok(block_number),
+                    },
+                )
+                .is_ok()
+        }
+
+        fn ipfs_gateway() -> Vec<u8> {
+            StorageValueRef::persistent(crate::IPFS_GATEWAY_KEY)
+                .get::<Vec<u8>>()
+                .ok()
+                .flatten()
+                .unwrap_or_else(|| T::IpfsGateway::get().as_bytes().to_vec())
+        }
+
+        fn check_object(gateway: &[u8], cid: &[u8], content_id: &ContentId) -> ContentStatus {
+            match Self::fetch_object(gateway, cid) {
+                Ok(Some(body)) if &content_id.algorithm().digest(&body) == content_id.hash() => {
+                    ContentStatus::Available
+                },
+                Ok(Some(_)) => ContentStatus::HashMismatch,
+                Ok(None) => ContentStatus::TooLarge,
+                Err(_) => ContentStatus::Unavailable,
+            }
+        }
+
+        /// GETs `{gateway}/ipfs/{cid}`. `Ok(None)` means the object exceeds `MaxIpfsObjectSize`.
+        fn fetch_object(gateway: &[u8], cid: &[u8]) -> Result<Option<Vec<u8>>, http::Error> {
+            let gateway = gateway.strip_suffix(b"/").unwrap_or(gateway);
+            let url = [gateway, b"/ipfs/", cid].concat();
+            let url = core::str::from_utf8(&url).map_err(|_| http::Error::Unknown)?;
+
+            let deadline =
+                sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+            let pending = http::Request::get(url)
+                .deadline(deadline)
+                .send()
+                .map_err(|_| http::Error::IoError)?;
+            let response = pending
+                .try_wait(deadline)
+                .map_err(|_| http::Error::DeadlineReached)??;
+            if response.code != 200 {
+                return Err(http::Error::Unknown);
+            }
+
+            let max_size = T::MaxIpfsObjectSize::get() as usize;
+            let mut body = Vec::new();
+            let mut chunks = response.body();
+            for byte in chunks.by_ref() {
+                if body.len() == max_size {
+                    return Ok(None);
+                }
+                body.push(byte);
+            }
+            if chunks.error().is_some() {
+                return Err(http::Error::IoError);
+            }
+            Ok(Some(body))
+        }
     }
 }
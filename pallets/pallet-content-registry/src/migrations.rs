@@ -0,0 +1,272 @@
+//! Storage migrations of the content registry pallet.
+
+/// Identifier of the content registry migrations in `pallet-migrations`.
+pub const PALLET_MIGRATIONS_ID: &[u8; 27] = b"pallet-content-registry-mbm";
+
+pub mod v1 {
+    //! Moves the content list of every DID out of the single `DidContents` vector into the
+    //! `(DID, index)` keyed `DidContentIndex`, so registering content no longer decodes and
+    //! re-encodes everything the DID registered before.
+    //!
+    //! Every listed proof is also moved to the current layout: its content id becomes the
+    //! blake2-256 multihash of the media digest it registered, the same id `create_content`
+    //! derives for that digest, which changes its `Proofs` and `DidContentExists` keys. Its
+    //! free-form metadata is kept as [`CaptureMetadata::Legacy`]. The registration time and
+    //! relay-chain block were not recorded back then and are set to zero. The device, block and
+    //! total count lookups that only new registrations fill are backfilled from the same proof.
+    //! Listed ids without a proof cannot be given a new id and are dropped from the list.
+    //!
+    //! The migration is stepped: each step moves as many entries as the weight meter allows
+    //! and resumes from the DID and index it stopped at.
+
+    use super::PALLET_MIGRATIONS_ID;
+    use crate::{
+        weights::WeightInfo, BlockContents, Config, ContentCount, DeviceContentCount,
+        DeviceContents, DidContentCount, DidContentExists, DidContentIndex, Pallet, Proofs,
+    };
+    use frame::deps::frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        storage::unhashed,
+        storage_alias,
+        weights::WeightMeter,
+    };
+    use frame::deps::sp_io::hashing::blake2_256;
+    use frame::prelude::*;
+    use polkadot_sdk::sp_std::vec::Vec;
+    use shared::types::{CaptureMetadata, ContentId, HashAlgorithm, MAX_LEGACY_METADATA_LENGTH};
+
+    /// The storage layout before version 1.
+    pub mod old {
+        use super::*;
+
+        /// Content id before it became a multihash digest: a `cid:` tag and the blake2-256
+        /// hash of the encoded content.
+        #[derive(Encode, Decode, Clone, MaxEncodedLen)]
+        pub(crate) struct OldContentId {
+            prefix: [u8; 4],
+            hash: [u8; 32],
+        }
+
+        impl OldContentId {
+            pub(crate) fn from_hash(hash: &[u8; 32]) -> Self {
+                OldContentId { prefix: *b"cid:", hash: *hash }
+            }
+        }
+
+        /// A proof as registered before content ids, timestamps and capture metadata changed.
+        #[derive(Encode, Decode, MaxEncodedLen)]
+        pub(crate) struct Proof<T: Config> {
+            pub(crate) content_id: OldContentId,
+            pub(crate) exists_from: BlockNumberFor<T>,
+            pub(crate) did: T::Did,
+            pub(crate) signer: T::AccountId,
+            pub(crate) content: T::Content,
+            pub(crate) content_type: T::ContentType,
+            pub(crate) content_description: T::ContentDescription,
+            pub(crate) content_metadata: BoundedVec<u8, ConstU32<MAX_LEGACY_METADATA_LENGTH>>,
+            pub(crate) device: T::Device,
+        }
+
+        impl<T: Config> Proof<T> {
+            pub(crate) fn upgrade(self) -> crate::Proof<T> {
+                crate::Proof {
+                    content_id: content_id_of::<T>(&self.content),
+                    exists_from: self.exists_from,
+                    registered_at: 0,
+                    relay_parent_number: Zero::zero(),
+                    did: self.did,
+                    signer: self.signer,
+                    content: self.content,
+                    content_type: self.content_type,
+                    content_description: self.content_description,
+                    content_metadata: CaptureMetadata::Legacy(self.content_metadata),
+                    device: self.device,
+                    ipfs_cid: None,
+                }
+            }
+        }
+
+        #[storage_alias]
+        pub type Proofs<T: Config> =
+            StorageMap<Pallet<T>, Blake2_128Concat, OldContentId, Proof<T>, OptionQuery>;
+
+        #[storage_alias]
+        pub type DidContentExists<T: Config> = StorageDoubleMap<
+            Pallet<T>,
+            Twox64Concat,
+            <T as Config>::Did,
+            Blake2_128Concat,
+            OldContentId,
+            bool,
+            ValueQuery,
+        >;
+
+        #[storage_alias]
+        pub type DidContents<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as Config>::Did,
+            Vec<OldContentId>,
+            OptionQuery,
+        >;
+    }
+
+    /// Progress of the migration: the DID being moved and the position in its old list to
+    /// resume from.
+    pub type Cursor<T> = (<T as Config>::Did, u32);
+
+    /// Migrates `DidContents` to `DidContentIndex` and `DidContentCount`.
+    pub struct LazyMigrationV1<T, W>(PhantomData<(T, W)>);
+
+    impl<T: Config, W: WeightInfo> SteppedMigration for LazyMigrationV1<T, W> {
+        type Cursor = Cursor<T>;
+        type Identifier = MigrationId<27>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
+        }
+
+        fn step(
+            cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+                return Ok(None);
+            }
+
+            let required = W::migration_v1_step();
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            let mut cursor = cursor;
+            loop {
+                let (did, mut index) = match cursor.take() {
+                    Some(cursor) => cursor,
+                    None => match old::DidContents::<T>::iter_keys().next() {
+                        Some(did) => (did, 0),
+                        None => {
+                            StorageVersion::new(1).put::<Pallet<T>>();
+                            return Ok(None);
+                        },
+                    },
+                };
+
+                // loading the old list is paid for once per step, by its encoded size
+                let load = T::DbWeight::get().reads_writes(2, 2).saturating_add(Weight::from_parts(
+                    0,
+                    old::DidContents::<T>::decode_len(&did)
+                        .unwrap_or_default()
+                        .saturating_mul(old::OldContentId::max_encoded_len()) as u64,
+                ));
+                if meter.try_consume(load).is_err() {
+                    return Ok(Some((did, index)));
+                }
+                // the DID was just listed, so a missing list could not be decoded; dropping it
+                // would lose every proof the DID owns
+                let contents = old::DidContents::<T>::get(&did).ok_or_else(|| {
+                    frame::log::error!(target: "runtime::content-registry", "cannot decode DidContents");
+                    SteppedMigrationError::Failed
+                })?;
+
+                // the new index only counts the ids that still have a proof
+                let mut count = DidContentCount::<T>::get(&did);
+                for old_id in contents.into_iter().skip(index as usize) {
+                    if meter.try_consume(required).is_err() {
+                        DidContentCount::<T>::insert(&did, count);
+                        return Ok(Some((did, index)));
+                    }
+                    old::DidContentExists::<T>::remove(&did, &old_id);
+                    index.saturating_inc();
+                    let Some(proof) = take_old_proof::<T>(&old_id)? else {
+                        continue;
+                    };
+                    let proof = proof.upgrade();
+                    let content_id = proof.content_id.clone();
+                    DeviceContents::<T>::insert(&proof.device, &content_id, ());
+                    DeviceContentCount::<T>::mutate(&proof.device, |count| count.saturating_inc());
+                    BlockContents::<T>::insert(proof.exists_from, &content_id, ());
+                    ContentCount::<T>::mutate(|count| count.saturating_inc());
+                    Proofs::<T>::insert(&content_id, proof);
+                    DidContentExists::<T>::insert(&did, &content_id, true);
+                    DidContentIndex::<T>::insert(&did, count, content_id);
+                    count.saturating_inc();
+                }
+                DidContentCount::<T>::insert(&did, count);
+                old::DidContents::<T>::remove(&did);
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame::deps::sp_runtime::TryRuntimeError> {
+            let total: u64 = old::DidContents::<T>::iter_values()
+                .flatten()
+                .filter(|old_id| old::Proofs::<T>::contains_key(old_id))
+                .count() as u64;
+            Ok(total.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame::deps::sp_runtime::TryRuntimeError> {
+            let total = u64::decode(&mut &state[..])
+                .map_err(|_| "content registry v1: cannot decode pre-upgrade state")?;
+            ensure!(
+                old::DidContents::<T>::iter_keys().next().is_none(),
+                "content registry v1: DidContents was not emptied"
+            );
+            let migrated: u64 = DidContentCount::<T>::iter_values().map(u64::from).sum();
+            ensure!(migrated == total, "content registry v1: content count mismatch");
+            ensure!(
+                ContentCount::<T>::get() == Proofs::<T>::iter_keys().count() as u64,
+                "content registry v1: ContentCount does not match the proofs"
+            );
+            for (did, _, content_id) in DidContentIndex::<T>::iter() {
+                ensure!(
+                    DidContentExists::<T>::get(&did, &content_id),
+                    "content registry v1: index entry without DidContentExists"
+                );
+                ensure!(
+                    Proofs::<T>::contains_key(&content_id),
+                    "content registry v1: index entry without proof"
+                );
+            }
+            Ok(())
+        }
+    }
+
+    /// Id the proof of `content` is stored under from version 1 on: the multihash of the media
+    /// digest it registered, as `create_content` derives it. Content that is no blake2-256
+    /// digest could not be registered any more and keeps the hash of its encoding.
+    pub fn content_id_of<T: Config>(content: &T::Content) -> ContentId {
+        ContentId::from_digest(HashAlgorithm::Blake2b256, content.as_ref()).unwrap_or_else(|_| {
+            ContentId::new(HashAlgorithm::Blake2b256, &blake2_256(&content.encode()))
+        })
+    }
+
+    /// Id the proof the old layout stores under the blake2-256 `hash` of its content moves to,
+    /// as long as this migration has not moved it yet. Lets other pallets re-key what they
+    /// stored under the old id before this migration runs.
+    pub fn legacy_content_id<T: Config>(hash: &[u8; 32]) -> Option<ContentId> {
+        let key = old::Proofs::<T>::hashed_key_for(old::OldContentId::from_hash(hash));
+        let raw = unhashed::get_raw(&key)?;
+        let proof = old::Proof::<T>::decode(&mut &raw[..]).ok()?;
+        Some(content_id_of::<T>(&proof.content))
+    }
+
+    /// Removes the proof stored under `old_id` in the old layout. Fails the migration if it is
+    /// there but cannot be decoded, since skipping it would leave it unreachable.
+    fn take_old_proof<T: Config>(
+        old_id: &old::OldContentId,
+    ) -> Result<Option<old::Proof<T>>, SteppedMigrationError> {
+        let key = old::Proofs::<T>::hashed_key_for(old_id);
+        let Some(raw) = unhashed::get_raw(&key) else {
+            return Ok(None);
+        };
+        let proof = old::Proof::<T>::decode(&mut &raw[..]).map_err(|_| {
+            frame::log::error!(target: "runtime::content-registry", "cannot decode proof");
+            SteppedMigrationError::Failed
+        })?;
+        unhashed::kill(&key);
+        Ok(Some(proof))
+    }
+}
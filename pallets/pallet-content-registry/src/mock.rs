@@ -6,7 +6,11 @@ use frame::{
 };
 use pallet_identity_registry;
 use shared::types::BaseRight;
-use polkadot_sdk::{pallet_balances, sp_io};
+use polkadot_sdk::{pallet_balances, pallet_timestamp, sp_io};
+use polkadot_sdk::staging_xcm::latest::{
+    Assets, Junction, Location, SendError, SendResult, SendXcm, Xcm, XcmHash,
+};
+use frame::deps::sp_runtime::testing::{TestSignature, TestXt, UintAuthorityId};
 
 type Balance = u128;
 // Configure a mock runtime to test the pallet.
@@ -35,6 +39,8 @@ mod test_runtime {
     pub type IdentityRegistry = pallet_identity_registry;
     #[runtime::pallet_index(3)]
     pub type Balances = pallet_balances;
+    #[runtime::pallet_index(4)]
+    pub type Timestamp = pallet_timestamp;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -62,6 +68,103 @@ impl pallet_balances::Config for Test {
 	type FreezeIdentifier = ();
 	type MaxFreezes = ConstU32<10>;
 }
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub static RelayBlockNumber: u32 = 0;
+    pub static SentXcm: Vec<(Location, Xcm<()>)> = vec![];
+    pub const IpfsGateway: &'static str = "http://ipfs.test";
+    pub const MaxResponseWeight: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+}
+
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type RuntimeCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateInherent<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_inherent(call: Self::RuntimeCall) -> Self::Extrinsic {
+        Extrinsic::new_bare(call)
+    }
+}
+
+/// Signs with the keys registered through `UintAuthorityId::set_all_keys`.
+pub struct TestAuthorityId;
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthorityId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+}
+
+/// Stands in for the relay-chain data provider of `cumulus_pallet_parachain_system`.
+pub struct MockRelayBlockNumberProvider;
+impl BlockNumberProvider for MockRelayBlockNumberProvider {
+    type BlockNumber = u32;
+
+    fn current_block_number() -> Self::BlockNumber {
+        RelayBlockNumber::get()
+    }
+}
+
+/// Stands in for `pallet_xcm::EnsureXcm`: a signed origin `n` is the sibling parachain `n`.
+pub struct EnsureSiblingQuerier;
+impl EnsureOrigin<RuntimeOrigin> for EnsureSiblingQuerier {
+    type Success = Location;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Location, RuntimeOrigin> {
+        match o.clone().into() {
+            Ok(frame_system::RawOrigin::Signed(who)) => {
+                Ok(Location::new(1, [Junction::Parachain(who as u32)]))
+            },
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::signed(2000))
+    }
+}
+
+/// Records sent messages in `SentXcm` instead of routing them.
+pub struct TestXcmSender;
+impl SendXcm for TestXcmSender {
+    type Ticket = (Location, Xcm<()>);
+
+    fn validate(
+        destination: &mut Option<Location>,
+        message: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        let destination = destination.take().ok_or(SendError::MissingArgument)?;
+        let message = message.take().ok_or(SendError::MissingArgument)?;
+        Ok(((destination, message), Assets::new()))
+    }
+
+    fn deliver((destination, message): Self::Ticket) -> Result<XcmHash, SendError> {
+        let hash = message.using_encoded(sp_io::hashing::blake2_256);
+        SentXcm::mutate(|sent| sent.push((destination, message)));
+        Ok(hash)
+    }
+}
+
 impl pallet_identity_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -85,8 +188,69 @@ impl crate::Config for Test {
     type Content = [u8; 32];
     type ContentDescription = BoundedVec<u8, ConstU32<100>>;
     type ContentType = BoundedVec<u8, ConstU32<100>>;
-    type ContentMetadata = BoundedVec<u8, ConstU32<100>>;
-    type MaxContentInVec = ConstU32<10000>;
+    type NativeBalance = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type ProofDeposit = ConstU128<100>;
+    type CommitmentDeposit = ConstU128<10>;
+    type QuotaPeriod = ConstU64<10>;
+    type DefaultQuota = ConstU32<10>;
+    type QuotaOrigin = EnsureRoot<u64>;
+    type XcmQueryOrigin = EnsureSiblingQuerier;
+    type XcmSender = TestXcmSender;
+    type MaxResponseWeight = MaxResponseWeight;
+    type UnixTime = Timestamp;
+    type RelayBlockNumberProvider = MockRelayBlockNumberProvider;
+    type MaxCaptureTimeDrift = ConstU64<60_000>;
+    type MaxIpfsCidLength = ConstU32<64>;
+    type IpfsGateway = IpfsGateway;
+    type MaxIpfsObjectSize = ConstU32<1024>;
+    type MaxPendingChecks = ConstU32<3>;
+    type CheckRetryInterval = ConstU64<10>;
+    type MaxReporters = ConstU32<5>;
+    type ReporterOrigin = EnsureRoot<u64>;
+    type AuthorityId = TestAuthorityId;
+    type UnsignedPriority = ConstU64<100>;
+    type MaxCommitmentExpiriesPerBlock = ConstU32<2>;
+    type MaxAttestationsPerContent = ConstU32<2>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u64, BoundedVec<u8, ConstU32<1024>>, BoundedVec<u8, ConstU32<1024>>, [u8; 32]>
+    for MockBenchmarkHelper
+{
+    fn setup_did(who: &u64) -> (BoundedVec<u8, ConstU32<1024>>, BoundedVec<u8, ConstU32<1024>>) {
+        let did: BoundedVec<u8, ConstU32<1024>> = BoundedVec::truncate_from(b"did:era:bench".to_vec());
+        let device: BoundedVec<u8, ConstU32<1024>> = BoundedVec::truncate_from(b"bench-device".to_vec());
+        Balances::force_set_balance(RuntimeOrigin::root(), *who, 10000000).unwrap();
+        IdentityRegistry::create_did(
+            RuntimeOrigin::signed(*who),
+            did.clone(),
+            BoundedVec::truncate_from(vec![*who]),
+        )
+        .unwrap();
+        for right in [BaseRight::Impersonate, BaseRight::Update] {
+            IdentityRegistry::add_right_for_signatory(
+                RuntimeOrigin::signed(*who),
+                did.clone(),
+                *who,
+                right,
+                pallet_identity_registry::RightDuration::Permanent,
+            )
+            .unwrap();
+        }
+        IdentityRegistry::register_device(RuntimeOrigin::signed(*who), did.clone(), device.clone())
+            .unwrap();
+        (did, device)
+    }
+
+    fn content(seed: u32) -> [u8; 32] {
+        sp_io::hashing::blake2_256(&seed.to_le_bytes())
+    }
 }
 
 // Build genesis storage according to the mock runtime.
@@ -0,0 +1,118 @@
+use super::*;
+use frame::prelude::*;
+use polkadot_sdk::sp_std::vec::Vec;
+use shared::types::ContentId;
+
+/// Largest page any of the query helpers returns.
+pub const MAX_PAGE_SIZE: u32 = 100;
+/// Number of blocks a block range query looks at before handing back a cursor.
+pub const MAX_BLOCKS_PER_PAGE: u32 = 10_000;
+
+/// Where a block range query resumes: the block to continue in, and the last content id
+/// returned from it, if any.
+pub type BlockCursor<T> = (BlockNumberFor<T>, Option<ContentId>);
+
+impl<T: Config> Pallet<T> {
+    /// Content of a DID in registration order, starting at index `start`.
+    /// Returns the page and the index of the next page, if there is one.
+    pub fn did_contents_page(did: &T::Did, start: u32, limit: u32) -> (Vec<ContentId>, Option<u32>) {
+        let count = DidContentCount::<T>::get(did);
+        let end = start.saturating_add(page_size(limit) as u32).min(count);
+        let items: Vec<ContentId> = (start..end)
+            .filter_map(|index| DidContentIndex::<T>::get(did, index))
+            .collect();
+        let next = (end < count).then_some(end);
+        (items, next)
+    }
+
+    /// Content registered from `device`, continuing after the content id `after`.
+    /// Returns the page and the cursor of the next page, if there is one.
+    pub fn device_contents_page(
+        device: &T::Device,
+        after: Option<ContentId>,
+        limit: u32,
+    ) -> (Vec<ContentId>, Option<ContentId>) {
+        let iter = match after {
+            Some(after) => DeviceContents::<T>::iter_key_prefix_from(
+                device,
+                DeviceContents::<T>::hashed_key_for(device, after),
+            ),
+            None => DeviceContents::<T>::iter_key_prefix(device),
+        };
+        Self::page(iter, limit)
+    }
+
+    /// Content registered in blocks `from..=to`, block by block, continuing at `cursor`.
+    /// Returns the page and the cursor of the next page, if there is one.
+    pub fn block_range_page(
+        from: BlockNumberFor<T>,
+        to: BlockNumberFor<T>,
+        cursor: Option<BlockCursor<T>>,
+        limit: u32,
+    ) -> (Vec<ContentId>, Option<BlockCursor<T>>) {
+        let limit = page_size(limit);
+        let (mut block, mut after) = match cursor {
+            Some((block, after)) if block >= from => (block, after),
+            _ => (from, None),
+        };
+        let mut items = Vec::new();
+        let mut scanned = 0;
+        while block <= to {
+            if scanned == MAX_BLOCKS_PER_PAGE {
+                return (items, Some((block, None)));
+            }
+            let iter = match after.take() {
+                Some(after) => BlockContents::<T>::iter_key_prefix_from(
+                    block,
+                    BlockContents::<T>::hashed_key_for(block, after),
+                ),
+                None => BlockContents::<T>::iter_key_prefix(block),
+            };
+            for content_id in iter {
+                if items.len() == limit {
+                    return (items, Some((block, after)));
+                }
+                after = Some(content_id.clone());
+                items.push(content_id);
+            }
+            if block == to {
+                break;
+            }
+            after = None;
+            block = block.saturating_add(One::one());
+            scanned += 1;
+        }
+        (items, None)
+    }
+
+    /// Number of proofs registered from `device`.
+    pub fn device_content_count(device: &T::Device) -> u32 {
+        DeviceContentCount::<T>::get(device)
+    }
+
+    /// Number of proofs registered for `did`.
+    pub fn did_content_count(did: &T::Did) -> u32 {
+        DidContentCount::<T>::get(did)
+    }
+
+    fn page(
+        iter: impl Iterator<Item = ContentId>,
+        limit: u32,
+    ) -> (Vec<ContentId>, Option<ContentId>) {
+        let limit = page_size(limit);
+        // one extra item tells whether there is a next page
+        let mut items: Vec<ContentId> = iter.take(limit + 1).collect();
+        let next = if items.len() > limit {
+            items.truncate(limit);
+            items.last().cloned()
+        } else {
+            None
+        };
+        (items, next)
+    }
+}
+
+/// Page sizes are kept within `1..=MAX_PAGE_SIZE`, so a cursor always moves forward.
+fn page_size(limit: u32) -> usize {
+    limit.clamp(1, MAX_PAGE_SIZE) as usize
+}
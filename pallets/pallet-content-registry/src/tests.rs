@@ -1,7 +1,33 @@
 #![allow(unused)]
-use crate::{mock::*, Error, Event};
+use crate::{
+    migrations::v1, mock::*, weights::WeightInfo, AvailabilityReport, BlockContents, ContentStatus,
+    AttestationCount, CommitmentExpiries, Commitments, ContentLabel, ContentLicense, DidContentCount, DidContentIndex,
+    Error, Event, HoldReason, LicenseOffer, LicenseReceipt, PendingAvailabilityChecks, QuotaUsage,
+};
+use frame::deps::frame_support::{
+    migrations::{SteppedMigration, SteppedMigrationError},
+    storage::unhashed,
+    weights::WeightMeter,
+};
+use frame::deps::sp_core::offchain::{
+    self as offchain,
+    testing::{PendingRequest, PoolState, TestOffchainExt, TestTransactionPoolExt},
+    OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use frame::deps::sp_runtime::{
+    offchain::storage::StorageValueRef,
+    testing::{TestSignature, UintAuthorityId},
+};
+use frame::deps::sp_io::hashing::blake2_256;
 use frame::testing_prelude::*;
-use shared::types::{BaseRight, ContentId};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use shared::types::{
+    AttestationKind, BaseRight, CameraInfo, CaptureMetadata, CaptureMetadataV1, CidError, ContentId, ContextVerdict, GeoLocation,
+    HashAlgorithm, LabelKind, License, ProofStatus,
+};
+use shared::traits::{content::ContentInspector, context::DisputeObserver};
+use polkadot_sdk::staging_xcm::latest::{Instruction, Junction, Location, OriginKind, WeightLimit, Xcm};
 
 pub const ALICE: u64 = 1;
 pub const BOB: u64 = 2000;
@@ -62,7 +88,7 @@ fn should_create_content() {
         let content = CONTENT1;
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"profile picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         setup_did_with_rights(who, did.clone(), vec![BOB]);
         register_device_for_did(who, did.clone(), device.clone());
@@ -71,10 +97,12 @@ fn should_create_content() {
             RuntimeOrigin::signed(who),
             did.clone(),
             content,
+            HashAlgorithm::Blake2b256,
             content_type.clone(),
             content_description.clone(),
             content_metadata.clone(),
-            device.clone()
+            device.clone(),
+            None
         ));
         
         // Verify content was stored
@@ -95,7 +123,7 @@ fn should_create_content() {
         assert!(Template::does_did_have_content(&did, &content_id));
         
         // Verify content is in DID's content list
-        let did_contents = Template::get_did_contents(&did);
+        let (did_contents, _) = Template::did_contents_page(&did, 0, 100);
         assert_eq!(did_contents.len(), 1);
         assert!(did_contents.contains(&content_id));
     });
@@ -110,7 +138,7 @@ fn should_create_content_and_emit_event() {
         let content = CONTENT1;
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"profile picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         setup_did_with_rights(who, did.clone(), vec![BOB]);
         register_device_for_did(who, did.clone(), device.clone());
@@ -119,10 +147,12 @@ fn should_create_content_and_emit_event() {
             RuntimeOrigin::signed(who),
             did.clone(),
             content,
+            HashAlgorithm::Blake2b256,
             content_type.clone(),
             content_description.clone(),
             content_metadata.clone(),
-            device.clone()
+            device.clone(),
+            None
         ));
         
         let content_id = generate_content_id(&content);
@@ -150,7 +180,7 @@ fn should_create_multiple_contents_for_same_did() {
         let content2 = CONTENT2;
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         setup_did_with_rights(who, did.clone(), vec![BOB]);
         register_device_for_did(who, did.clone(), device.clone());
@@ -160,10 +190,12 @@ fn should_create_multiple_contents_for_same_did() {
             RuntimeOrigin::signed(who),
             did.clone(),
             content1,
+            HashAlgorithm::Blake2b256,
             content_type.clone(),
             content_description.clone(),
             content_metadata.clone(),
-            device.clone()
+            device.clone(),
+            None
         ));
         
         // Create second content
@@ -171,10 +203,12 @@ fn should_create_multiple_contents_for_same_did() {
             RuntimeOrigin::signed(who),
             did.clone(),
             content2,
+            HashAlgorithm::Blake2b256,
             content_type.clone(),
             content_description.clone(),
             content_metadata.clone(),
-            device.clone()
+            device.clone(),
+            None
         ));
         
         // Verify both contents exist
@@ -184,7 +218,7 @@ fn should_create_multiple_contents_for_same_did() {
         assert!(Template::does_did_have_content(&did, &content_id1));
         assert!(Template::does_did_have_content(&did, &content_id2));
         
-        let did_contents = Template::get_did_contents(&did);
+        let (did_contents, _) = Template::did_contents_page(&did, 0, 100);
         assert_eq!(did_contents.len(), 2);
         assert!(did_contents.contains(&content_id1));
         assert!(did_contents.contains(&content_id2));
@@ -202,7 +236,7 @@ fn should_create_content_with_different_devices() {
         let content2 = CONTENT2;
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         setup_did_with_rights(who, did.clone(), vec![BOB]);
         register_device_for_did(who, did.clone(), device1.clone());
@@ -213,10 +247,12 @@ fn should_create_content_with_different_devices() {
             RuntimeOrigin::signed(who),
             did.clone(),
             content1,
+            HashAlgorithm::Blake2b256,
             content_type.clone(),
             content_description.clone(),
             content_metadata.clone(),
-            device1.clone()
+            device1.clone(),
+            None
         ));
         
         // Create content with device2
@@ -224,10 +260,12 @@ fn should_create_content_with_different_devices() {
             RuntimeOrigin::signed(who),
             did.clone(),
             content2,
+            HashAlgorithm::Blake2b256,
             content_type.clone(),
             content_description.clone(),
             content_metadata.clone(),
-            device2.clone()
+            device2.clone(),
+            None
         ));
         
         // Verify both contents reference correct devices
@@ -253,7 +291,7 @@ fn should_fail_to_create_content_without_impersonate_right() {
         let content = CONTENT1;
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"profile picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         // Create DID but don't grant Impersonate right
         let signatories = BoundedVec::try_from(vec![BOB]).unwrap();
@@ -271,10 +309,12 @@ fn should_fail_to_create_content_without_impersonate_right() {
                 RuntimeOrigin::signed(who),
                 did.clone(),
                 content,
+                HashAlgorithm::Blake2b256,
                 content_type,
                 content_description,
                 content_metadata,
-                device
+                device,
+                None
             ),
             Error::<Test>::SignerDoesNotHaveRight
         );
@@ -290,7 +330,7 @@ fn should_fail_to_create_content_with_unowned_device() {
         let content = CONTENT1;
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"profile picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         setup_did_with_rights(who, did.clone(), vec![BOB]);
         // Don't register the device
@@ -301,10 +341,12 @@ fn should_fail_to_create_content_with_unowned_device() {
                 RuntimeOrigin::signed(who),
                 did.clone(),
                 content,
+                HashAlgorithm::Blake2b256,
                 content_type,
                 content_description,
                 content_metadata,
-                device
+                device,
+                None
             ),
             Error::<Test>::DeviceNotOwned
         );
@@ -320,7 +362,7 @@ fn should_fail_to_create_duplicate_content() {
         let content = CONTENT1;
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"profile picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         setup_did_with_rights(who, did.clone(), vec![BOB]);
         register_device_for_did(who, did.clone(), device.clone());
@@ -330,10 +372,12 @@ fn should_fail_to_create_duplicate_content() {
             RuntimeOrigin::signed(who),
             did.clone(),
             content,
+            HashAlgorithm::Blake2b256,
             content_type.clone(),
             content_description.clone(),
             content_metadata.clone(),
-            device.clone()
+            device.clone(),
+            None
         ));
         
         // Try to create same content again (same content hash = same content_id)
@@ -342,10 +386,12 @@ fn should_fail_to_create_duplicate_content() {
                 RuntimeOrigin::signed(who),
                 did.clone(),
                 content,
+                HashAlgorithm::Blake2b256,
                 content_type,
                 content_description,
                 content_metadata,
-                device
+                device,
+                None
             ),
             Error::<Test>::ContentAlreadyExists
         );
@@ -364,7 +410,7 @@ fn should_fail_to_create_content_with_different_did_but_same_content() {
         let content = CONTENT1; // Same content
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"profile picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         // Setup first DID
         setup_did_with_rights(who1, did1.clone(), vec![]);
@@ -379,10 +425,12 @@ fn should_fail_to_create_content_with_different_did_but_same_content() {
             RuntimeOrigin::signed(who1),
             did1.clone(),
             content,
+            HashAlgorithm::Blake2b256,
             content_type.clone(),
             content_description.clone(),
             content_metadata.clone(),
-            device1.clone()
+            device1.clone(),
+            None
         ));
         
         // Try to create same content with second DID (should fail - content_id is global)
@@ -391,10 +439,12 @@ fn should_fail_to_create_content_with_different_did_but_same_content() {
                 RuntimeOrigin::signed(who2),
                 did2.clone(),
                 content,
+                HashAlgorithm::Blake2b256,
                 content_type,
                 content_description,
                 content_metadata,
-                device2
+                device2,
+                None
             ),
             Error::<Test>::ContentAlreadyExists
         );
@@ -411,7 +461,7 @@ fn should_fail_to_create_content_with_unauthorized_signer() {
         let content = CONTENT1;
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"profile picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         setup_did_with_rights(who, did.clone(), vec![BOB]);
         register_device_for_did(who, did.clone(), device.clone());
@@ -422,10 +472,12 @@ fn should_fail_to_create_content_with_unauthorized_signer() {
                 RuntimeOrigin::signed(unauthorized),
                 did.clone(),
                 content,
+                HashAlgorithm::Blake2b256,
                 content_type,
                 content_description,
                 content_metadata,
-                device
+                device,
+                None
             ),
             Error::<Test>::SignerDoesNotHaveRight
         );
@@ -443,7 +495,7 @@ fn should_retrieve_content_by_id() {
         let content = CONTENT1;
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"profile picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         setup_did_with_rights(who, did.clone(), vec![BOB]);
         register_device_for_did(who, did.clone(), device.clone());
@@ -452,10 +504,12 @@ fn should_retrieve_content_by_id() {
             RuntimeOrigin::signed(who),
             did.clone(),
             content,
+            HashAlgorithm::Blake2b256,
             content_type.clone(),
             content_description.clone(),
             content_metadata.clone(),
-            device.clone()
+            device.clone(),
+            None
         ));
         
         let content_id = generate_content_id(&content);
@@ -494,7 +548,7 @@ fn should_list_all_contents_for_did() {
         let content2 = CONTENT2;
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         setup_did_with_rights(who, did.clone(), vec![BOB]);
         register_device_for_did(who, did.clone(), device.clone());
@@ -504,23 +558,27 @@ fn should_list_all_contents_for_did() {
             RuntimeOrigin::signed(who),
             did.clone(),
             content1,
+            HashAlgorithm::Blake2b256,
             content_type.clone(),
             content_description.clone(),
             content_metadata.clone(),
-            device.clone()
+            device.clone(),
+            None
         ));
         
         assert_ok!(Template::create_content(
             RuntimeOrigin::signed(who),
             did.clone(),
             content2,
+            HashAlgorithm::Blake2b256,
             content_type.clone(),
             content_description.clone(),
             content_metadata.clone(),
-            device.clone()
+            device.clone(),
+            None
         ));
         
-        let did_contents = Template::get_did_contents(&did);
+        let (did_contents, _) = Template::did_contents_page(&did, 0, 100);
         assert_eq!(did_contents.len(), 2);
         
         let content_id1 = generate_content_id(&content1);
@@ -540,7 +598,7 @@ fn should_check_content_existence_for_did() {
         let content = CONTENT1;
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"profile picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         setup_did_with_rights(who, did.clone(), vec![BOB]);
         register_device_for_did(who, did.clone(), device.clone());
@@ -554,10 +612,12 @@ fn should_check_content_existence_for_did() {
             RuntimeOrigin::signed(who),
             did.clone(),
             content,
+            HashAlgorithm::Blake2b256,
             content_type,
             content_description,
             content_metadata,
-            device
+            device,
+            None
         ));
         
         // After creation
@@ -576,7 +636,7 @@ fn should_handle_content_creation_with_temporary_right() {
         let content = CONTENT1;
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"profile picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         // Setup DID
         let signatories = BoundedVec::try_from(vec![BOB]).unwrap();
@@ -602,10 +662,12 @@ fn should_handle_content_creation_with_temporary_right() {
             RuntimeOrigin::signed(who),
             did.clone(),
             content,
+            HashAlgorithm::Blake2b256,
             content_type,
             content_description,
             content_metadata,
-            device
+            device,
+            None
         ));
         
         // Content should persist across blocks
@@ -625,7 +687,7 @@ fn should_maintain_content_across_block_numbers() {
         let content = CONTENT1;
         let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
         let content_description = BoundedVec::try_from(b"profile picture".to_vec()).unwrap();
-        let content_metadata = BoundedVec::try_from(b"{}".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
         
         setup_did_with_rights(who, did.clone(), vec![BOB]);
         register_device_for_did(who, did.clone(), device.clone());
@@ -636,10 +698,12 @@ fn should_maintain_content_across_block_numbers() {
             RuntimeOrigin::signed(who),
             did.clone(),
             content,
+            HashAlgorithm::Blake2b256,
             content_type.clone(),
             content_description.clone(),
             content_metadata.clone(),
-            device.clone()
+            device.clone(),
+            None
         ));
         
         let content_id = generate_content_id(&content);
@@ -654,11 +718,2386 @@ fn should_maintain_content_across_block_numbers() {
     });
 }
 
+#[test]
+fn should_record_timestamp_and_relay_parent_on_proof() {
+    new_test_ext().execute_with(|| {
+        let who = ALICE;
+        let did = BoundedVec::try_from(DID.to_vec()).unwrap();
+        let device = BoundedVec::try_from(DEVICE1.to_vec()).unwrap();
+        let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
+        let content_description = BoundedVec::try_from(b"profile picture".to_vec()).unwrap();
+
+        setup_did_with_rights(who, did.clone(), vec![BOB]);
+        register_device_for_did(who, did.clone(), device.clone());
+
+        // 2026-10-17T10:03:00Z
+        let moment = 1_792_231_380_000u64;
+        System::set_block_number(7);
+        Timestamp::set_timestamp(moment);
+        RelayBlockNumber::set(4242);
+        let content_metadata = capture_metadata();
+
+        assert_ok!(Template::create_content(
+            RuntimeOrigin::signed(who),
+            did.clone(),
+            CONTENT1,
+            HashAlgorithm::Blake2b256,
+            content_type,
+            content_description,
+            content_metadata,
+            device,
+            None
+        ));
+
+        let proof = Template::get_content(generate_content_id(&CONTENT1)).unwrap();
+        assert_eq!(proof.exists_from, 7);
+        assert_eq!(proof.registered_at, moment);
+        assert_eq!(proof.relay_parent_number, 4242);
+    });
+}
+
+// ============ ContentId Tests ============
+
+#[test]
+fn should_create_content_with_sha2_digest() {
+    new_test_ext().execute_with(|| {
+        let who = ALICE;
+        let did = BoundedVec::try_from(DID.to_vec()).unwrap();
+        let device = BoundedVec::try_from(DEVICE1.to_vec()).unwrap();
+        let content = CONTENT1;
+        let content_type = BoundedVec::try_from(b"image".to_vec()).unwrap();
+        let content_description = BoundedVec::try_from(b"profile picture".to_vec()).unwrap();
+        let content_metadata = capture_metadata();
+
+        setup_did_with_rights(who, did.clone(), vec![BOB]);
+        register_device_for_did(who, did.clone(), device.clone());
+
+        assert_ok!(Template::create_content(
+            RuntimeOrigin::signed(who),
+            did.clone(),
+            content,
+            HashAlgorithm::Sha2_256,
+            content_type,
+            content_description,
+            content_metadata,
+            device,
+            None
+        ));
+
+        // the same digest under another algorithm is a different id
+        let sha2_id = ContentId::new(HashAlgorithm::Sha2_256, &content);
+        assert!(Template::get_content(&sha2_id).is_some());
+        assert!(Template::get_content(generate_content_id(&content)).is_none());
+        assert_eq!(Template::get_content(&sha2_id).unwrap().content_id.algorithm(), HashAlgorithm::Sha2_256);
+    });
+}
+
+#[test]
+fn should_match_ipfs_cid_for_sha2_digest() {
+    // `ipfs add --cid-version 1 --raw-leaves` of "hello world" (no trailing newline)
+    let digest: [u8; 32] = frame::hashing::sha2_256(b"hello world");
+    let content_id = ContentId::new(HashAlgorithm::Sha2_256, &digest);
+    let cid = "bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e";
+
+    assert_eq!(content_id.to_cid_string(), cid);
+    assert_eq!(ContentId::from_cid_str(cid), Ok(content_id));
+}
+
+#[test]
+fn should_round_trip_cid_for_every_algorithm() {
+    for algorithm in [HashAlgorithm::Blake2b256, HashAlgorithm::Sha2_256, HashAlgorithm::Blake3] {
+        let content_id = ContentId::new(algorithm, &CONTENT2);
+        let cid = content_id.to_cid_string();
+        assert!(cid.starts_with('b'));
+        assert_eq!(ContentId::from_cid_str(&cid), Ok(content_id.clone()));
+
+        // base16 multibase decodes to the same id
+        let hex: String = content_id.to_cid_bytes().iter().map(|b| format!("{:02x}", b)).collect();
+        let hex_cid = format!("f{}", hex);
+        assert_eq!(ContentId::from_cid_str(&hex_cid), Ok(content_id));
+    }
+}
+
+#[test]
+fn should_reject_malformed_cid() {
+    let content_id = ContentId::new(HashAlgorithm::Blake3, &CONTENT1);
+    let cid = content_id.to_cid_string();
+    let bytes = content_id.to_cid_bytes();
+
+    assert_eq!(ContentId::from_cid_str(""), Err(CidError::UnsupportedMultibase));
+    assert_eq!(ContentId::from_cid_str(&cid[1..]), Err(CidError::UnsupportedMultibase));
+    assert_eq!(ContentId::from_cid_str(&cid.to_uppercase()), Err(CidError::UnsupportedMultibase));
+    assert_eq!(ContentId::from_cid_str(&format!("{}1", &cid[..cid.len() - 1])), Err(CidError::InvalidEncoding));
+    assert_eq!(ContentId::from_cid_bytes(&bytes[..bytes.len() - 1]), Err(CidError::InvalidDigestLength));
+    assert_eq!(ContentId::from_cid_str("fzz"), Err(CidError::InvalidEncoding));
+
+    // CIDv0 and dag-pb are not content ids
+    let mut v0 = bytes.clone();
+    v0[0] = 0x00;
+    assert_eq!(ContentId::from_cid_bytes(&v0), Err(CidError::UnsupportedVersion));
+    let mut dag_pb = bytes.clone();
+    dag_pb[1] = 0x70;
+    assert_eq!(ContentId::from_cid_bytes(&dag_pb), Err(CidError::UnsupportedCodec));
+
+    // sha2-512 is a valid multihash but not a supported algorithm
+    let mut sha512 = bytes.clone();
+    sha512[2] = 0x13;
+    assert_eq!(ContentId::from_cid_bytes(&sha512), Err(CidError::UnsupportedHash));
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(ContentId::from_cid_bytes(&trailing), Err(CidError::TrailingBytes));
+    assert_eq!(ContentId::from_cid_bytes(&[0x81]), Err(CidError::InvalidVarint));
+}
+
+// ============ IPFS Availability Tests ============
+
+const HELLO_WORLD_CID: &str = "bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e";
+const REPORTER: u64 = 77;
+
+#[test]
+fn should_store_ipfs_cid_and_queue_check() {
+    new_test_ext().execute_with(|| {
+        let content_id = register_with_ipfs_cid(&CONTENT1, HashAlgorithm::Blake2b256, HELLO_WORLD_CID);
+
+        let proof = Template::get_content(&content_id).unwrap();
+        assert_eq!(proof.ipfs_cid.unwrap().to_vec(), HELLO_WORLD_CID.as_bytes().to_vec());
+        assert_eq!(Template::pending_availability_checks().to_vec(), vec![content_id.clone()]);
+        assert!(Template::content_status(&content_id).is_none());
+    });
+}
+
+#[test]
+fn should_not_queue_check_without_ipfs_cid() {
+    new_test_ext().execute_with(|| {
+        let did = BoundedVec::try_from(DID.to_vec()).unwrap();
+        let device = BoundedVec::try_from(DEVICE1.to_vec()).unwrap();
+        setup_did_with_rights(ALICE, did.clone(), vec![BOB]);
+        register_device_for_did(ALICE, did.clone(), device.clone());
+
+        assert_ok!(Template::create_content(
+            RuntimeOrigin::signed(ALICE),
+            did,
+            CONTENT1,
+            HashAlgorithm::Blake2b256,
+            BoundedVec::try_from(b"image".to_vec()).unwrap(),
+            BoundedVec::try_from(b"profile picture".to_vec()).unwrap(),
+            capture_metadata(),
+            device,
+            None
+        ));
+
+        assert!(Template::pending_availability_checks().is_empty());
+    });
+}
+
+#[test]
+fn should_fail_to_create_content_with_invalid_ipfs_cid() {
+    new_test_ext().execute_with(|| {
+        let did = BoundedVec::try_from(DID.to_vec()).unwrap();
+        let device = BoundedVec::try_from(DEVICE1.to_vec()).unwrap();
+        setup_did_with_rights(ALICE, did.clone(), vec![BOB]);
+        register_device_for_did(ALICE, did.clone(), device.clone());
+
+        for cid in [&b"../admin"[..], &b""[..], &b"bafy?x=1"[..]] {
+            assert_noop!(
+                Template::create_content(
+                    RuntimeOrigin::signed(ALICE),
+                    did.clone(),
+                    CONTENT1,
+                    HashAlgorithm::Blake2b256,
+                    BoundedVec::try_from(b"image".to_vec()).unwrap(),
+                    BoundedVec::try_from(b"profile picture".to_vec()).unwrap(),
+                    capture_metadata(),
+                    device.clone(),
+                    Some(BoundedVec::try_from(cid.to_vec()).unwrap())
+                ),
+                Error::<Test>::InvalidIpfsCid
+            );
+        }
+    });
+}
+
+#[test]
+fn should_skip_check_when_pending_checks_are_full() {
+    new_test_ext().execute_with(|| {
+        // the mock allows three pending checks
+        for n in 1..=3u8 {
+            register_with_ipfs_cid(&[n; 32], HashAlgorithm::Blake2b256, HELLO_WORLD_CID);
+        }
+
+        let content_id = register_with_ipfs_cid(&[4; 32], HashAlgorithm::Blake2b256, HELLO_WORLD_CID);
+
+        assert!(Template::get_content(&content_id).is_some());
+        assert!(!Template::pending_availability_checks().contains(&content_id));
+        System::assert_has_event(Event::AvailabilityCheckSkipped { content_id }.into());
+    });
+}
+
+#[test]
+fn should_drop_pending_check_of_retracted_content() {
+    new_test_ext().execute_with(|| {
+        let content_id = register_with_ipfs_cid(&CONTENT1, HashAlgorithm::Blake2b256, HELLO_WORLD_CID);
+        assert!(Template::pending_availability_checks().contains(&content_id));
+
+        assert_ok!(Template::retract_content(RuntimeOrigin::signed(ALICE), content_id.clone()));
+
+        assert!(!Template::pending_availability_checks().contains(&content_id));
+    });
+}
+
+#[test]
+fn should_only_allow_reporter_origin_to_set_reporters() {
+    new_test_ext().execute_with(|| {
+        let reporters = BoundedVec::try_from(vec![REPORTER]).unwrap();
+        assert_noop!(
+            Template::set_availability_reporters(RuntimeOrigin::signed(ALICE), reporters.clone()),
+            BadOrigin
+        );
+
+        assert_ok!(Template::set_availability_reporters(RuntimeOrigin::root(), reporters.clone()));
+        assert_eq!(Template::availability_reporters(), reporters);
+        System::assert_last_event(Event::AvailabilityReportersSet { reporters }.into());
+    });
+}
+
+#[test]
+fn offchain_worker_should_report_available_content() {
+    let (mut ext, offchain, pool) = offchain_test_ext();
+    ext.execute_with(|| {
+        let digest = frame::hashing::sha2_256(b"hello world");
+        let content_id = register_with_ipfs_cid(&digest, HashAlgorithm::Sha2_256, HELLO_WORLD_CID);
+        expect_ipfs_get(&offchain, &format!("http://ipfs.test/ipfs/{}", HELLO_WORLD_CID), b"hello world");
+
+        Template::offchain_worker(1);
+
+        let (report, signature) = submitted_report(&pool);
+        assert_eq!(report.content_id, content_id);
+        assert_eq!(report.status, ContentStatus::Available);
+        assert_eq!(report.public, UintAuthorityId(REPORTER));
+
+        assert_ok!(Template::report_availability(RuntimeOrigin::none(), report, signature));
+        let check = Template::content_status(&content_id).unwrap();
+        assert_eq!(check.status, ContentStatus::Available);
+        assert_eq!(check.checked_at, 1);
+        assert_eq!(check.reporter, REPORTER);
+        assert!(Template::pending_availability_checks().is_empty());
+        System::assert_last_event(
+            Event::AvailabilityReported { content_id, status: ContentStatus::Available, reporter: REPORTER }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn offchain_worker_should_report_hash_mismatch() {
+    let (mut ext, offchain, pool) = offchain_test_ext();
+    ext.execute_with(|| {
+        let digest = frame::hashing::sha2_256(b"hello world");
+        let content_id = register_with_ipfs_cid(&digest, HashAlgorithm::Sha2_256, HELLO_WORLD_CID);
+        expect_ipfs_get(&offchain, &format!("http://ipfs.test/ipfs/{}", HELLO_WORLD_CID), b"hello w0rld");
+
+        Template::offchain_worker(1);
+
+        let (report, signature) = submitted_report(&pool);
+        assert_eq!(report.status, ContentStatus::HashMismatch);
+        assert_ok!(Template::report_availability(RuntimeOrigin::none(), report, signature));
+        assert_eq!(Template::content_status(&content_id).unwrap().status, ContentStatus::HashMismatch);
+    });
+}
+
+#[test]
+fn offchain_worker_should_rehash_with_the_content_algorithm() {
+    let (mut ext, offchain, pool) = offchain_test_ext();
+    ext.execute_with(|| {
+        let body = b"frame 0001";
+        let digest: [u8; 32] = blake3::hash(body).into();
+        register_with_ipfs_cid(&digest, HashAlgorithm::Blake3, HELLO_WORLD_CID);
+        expect_ipfs_get(&offchain, &format!("http://ipfs.test/ipfs/{}", HELLO_WORLD_CID), body);
+
+        Template::offchain_worker(1);
+
+        assert_eq!(submitted_report(&pool).0.status, ContentStatus::Available);
+    });
+}
+
+#[test]
+fn offchain_worker_should_report_objects_over_the_size_limit() {
+    let (mut ext, offchain, pool) = offchain_test_ext();
+    ext.execute_with(|| {
+        // one byte more than `MaxIpfsObjectSize`
+        let body = vec![7u8; 1025];
+        let digest = frame::hashing::sha2_256(&body);
+        register_with_ipfs_cid(&digest, HashAlgorithm::Sha2_256, HELLO_WORLD_CID);
+        expect_ipfs_get(&offchain, &format!("http://ipfs.test/ipfs/{}", HELLO_WORLD_CID), &body);
+
+        Template::offchain_worker(1);
+
+        assert_eq!(submitted_report(&pool).0.status, ContentStatus::TooLarge);
+    });
+}
+
+#[test]
+fn offchain_worker_should_report_unreachable_gateway() {
+    let (offchain, _) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(OffchainWorkerExt::new(UnreachableGateway));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    ext.execute_with(|| {
+        UintAuthorityId::set_all_keys(vec![REPORTER]);
+        let content_id = register_with_ipfs_cid(&CONTENT1, HashAlgorithm::Blake2b256, HELLO_WORLD_CID);
+
+        Template::offchain_worker(1);
+
+        let (report, signature) = submitted_report(&pool_state);
+        assert_eq!(report.status, ContentStatus::Unavailable);
+        assert_ok!(Template::set_availability_reporters(
+            RuntimeOrigin::root(),
+            BoundedVec::try_from(vec![REPORTER]).unwrap()
+        ));
+        assert_ok!(Template::report_availability(RuntimeOrigin::none(), report, signature));
+        assert_eq!(Template::content_status(&content_id).unwrap().status, ContentStatus::Unavailable);
+    });
+}
+
+#[test]
+fn offchain_worker_should_use_gateway_from_local_storage() {
+    let (mut ext, offchain, pool) = offchain_test_ext();
+    ext.execute_with(|| {
+        StorageValueRef::persistent(crate::IPFS_GATEWAY_KEY).set(&b"http://127.0.0.1:8080/".to_vec());
+        let digest = frame::hashing::sha2_256(b"hello world");
+        register_with_ipfs_cid(&digest, HashAlgorithm::Sha2_256, HELLO_WORLD_CID);
+        expect_ipfs_get(&offchain, &format!("http://127.0.0.1:8080/ipfs/{}", HELLO_WORLD_CID), b"hello world");
+
+        Template::offchain_worker(1);
+
+        assert_eq!(submitted_report(&pool).0.status, ContentStatus::Available);
+    });
+}
+
+#[test]
+fn offchain_worker_should_not_refetch_within_retry_interval() {
+    let (mut ext, offchain, pool) = offchain_test_ext();
+    ext.execute_with(|| {
+        let digest = frame::hashing::sha2_256(b"hello world");
+        register_with_ipfs_cid(&digest, HashAlgorithm::Sha2_256, HELLO_WORLD_CID);
+        expect_ipfs_get(&offchain, &format!("http://ipfs.test/ipfs/{}", HELLO_WORLD_CID), b"hello world");
+
+        Template::offchain_worker(1);
+        // the report is not included yet, but no new request is expected before the retry interval
+        Template::offchain_worker(5);
+        assert_eq!(pool.read().transactions.len(), 1);
+
+        expect_ipfs_get(&offchain, &format!("http://ipfs.test/ipfs/{}", HELLO_WORLD_CID), b"hello world");
+        Template::offchain_worker(11);
+        assert_eq!(pool.read().transactions.len(), 2);
+    });
+}
+
+#[test]
+fn offchain_worker_should_not_submit_without_local_key() {
+    let (mut ext, _, pool) = offchain_test_ext();
+    ext.execute_with(|| {
+        UintAuthorityId::set_all_keys(Vec::<u64>::new());
+        register_with_ipfs_cid(&CONTENT1, HashAlgorithm::Blake2b256, HELLO_WORLD_CID);
+
+        Template::offchain_worker(1);
+
+        assert!(pool.read().transactions.is_empty());
+    });
+}
+
+#[test]
+fn should_validate_availability_reports() {
+    new_test_ext().execute_with(|| {
+        let content_id = register_with_ipfs_cid(&CONTENT1, HashAlgorithm::Blake2b256, HELLO_WORLD_CID);
+        let report = AvailabilityReport {
+            content_id: content_id.clone(),
+            status: ContentStatus::Available,
+            block_number: 1,
+            public: UintAuthorityId(REPORTER),
+        };
+        let signature = TestSignature(REPORTER, report.encode());
+        let call = crate::Call::report_availability { report: report.clone(), signature: signature.clone() };
+
+        // not a reporter yet
+        assert_eq!(
+            <Template as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call),
+            InvalidTransaction::BadSigner.into()
+        );
+        assert_noop!(
+            Template::report_availability(RuntimeOrigin::none(), report.clone(), signature.clone()),
+            Error::<Test>::NotAReporter
+        );
+
+        assert_ok!(Template::set_availability_reporters(
+            RuntimeOrigin::root(),
+            BoundedVec::try_from(vec![REPORTER]).unwrap()
+        ));
+        assert!(<Template as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call).is_ok());
+
+        // signature over another payload
+        let forged = crate::Call::report_availability {
+            report: AvailabilityReport { status: ContentStatus::HashMismatch, ..report.clone() },
+            signature: signature.clone(),
+        };
+        assert_eq!(
+            <Template as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &forged),
+            InvalidTransaction::BadProof.into()
+        );
+
+        // once settled, the check is no longer pending
+        assert_ok!(Template::report_availability(RuntimeOrigin::none(), report.clone(), signature.clone()));
+        assert_eq!(
+            <Template as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call),
+            InvalidTransaction::Stale.into()
+        );
+        assert_noop!(
+            Template::report_availability(RuntimeOrigin::none(), report, signature),
+            Error::<Test>::NoPendingCheck
+        );
+    });
+}
+
+// ============ Query Tests ============
+
+#[test]
+fn should_page_did_contents_by_index() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let ids: Vec<ContentId> = (1..=5u8).map(|n| register_content(&did, &device, [n; 32])).collect();
+
+        assert_eq!(Template::did_contents_page(&did, 0, 2), (ids[0..2].to_vec(), Some(2)));
+        assert_eq!(Template::did_contents_page(&did, 2, 2), (ids[2..4].to_vec(), Some(4)));
+        assert_eq!(Template::did_contents_page(&did, 4, 2), (ids[4..].to_vec(), None));
+        assert_eq!(Template::did_contents_page(&did, 9, 2), (vec![], None));
+        // a zero limit still moves the cursor forward
+        assert_eq!(Template::did_contents_page(&did, 0, 0), (ids[0..1].to_vec(), Some(1)));
+        assert_eq!(Template::did_content_count(&did), 5);
+    });
+}
+
+#[test]
+fn should_page_device_contents_with_cursor() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let other_device: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DEVICE2.to_vec()).unwrap();
+        assert_ok!(IdentityRegistry::register_device(RuntimeOrigin::signed(ALICE), did.clone(), other_device.clone()));
+
+        let mut ids: Vec<ContentId> = (1..=5u8).map(|n| register_content(&did, &device, [n; 32])).collect();
+        let other = register_content(&did, &other_device, [9; 32]);
+
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (page, next) = Template::device_contents_page(&device, cursor, 2);
+            assert!(page.len() <= 2);
+            seen.extend(page);
+            if next.is_none() {
+                break;
+            }
+            cursor = next;
+        }
+        seen.sort_by_key(|id| *id.hash());
+        ids.sort_by_key(|id| *id.hash());
+        assert_eq!(seen, ids);
+
+        assert_eq!(Template::device_contents_page(&other_device, None, 10), (vec![other], None));
+        assert_eq!(Template::device_content_count(&device), 5);
+        assert_eq!(Template::device_content_count(&other_device), 1);
+    });
+}
+
+#[test]
+fn should_page_contents_in_block_range() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let first = register_content(&did, &device, [1; 32]);
+        let second = register_content(&did, &device, [2; 32]);
+        System::set_block_number(2);
+        let third = register_content(&did, &device, [3; 32]);
+        System::set_block_number(4);
+        let fourth = register_content(&did, &device, [4; 32]);
+
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (page, next) = Template::block_range_page(1, 4, cursor, 1);
+            seen.extend(page);
+            if next.is_none() {
+                break;
+            }
+            cursor = next;
+        }
+        assert_eq!(seen.len(), 4);
+        assert!(seen[..2].contains(&first) && seen[..2].contains(&second));
+        assert_eq!(seen[2..], [third.clone(), fourth.clone()]);
+
+        assert_eq!(Template::block_range_page(2, 3, None, 10), (vec![third], None));
+        assert_eq!(Template::block_range_page(5, 9, None, 10), (vec![], None));
+        // a cursor before the range starts over at `from`
+        assert_eq!(Template::block_range_page(4, 4, Some((1, None)), 10), (vec![fourth], None));
+        assert_eq!(Template::content_count(), 4);
+    });
+}
+
+// ============ Migration Tests ============
+
+/// Content id the registry gave `content` before content ids became multihash digests: a
+/// `cid:` tag and the blake2-256 hash of the encoded content.
+fn old_content_id(content: &[u8; 32]) -> v1::old::OldContentId {
+    v1::old::OldContentId::from_hash(&blake2_256(&content.encode()))
+}
+
+/// Writes a `DidContents` list of `contents` as the registry stored it before content ids
+/// became multihash digests.
+fn put_old_did_contents(did: &BoundedVec<u8, ConstU32<1024>>, contents: &[[u8; 32]]) {
+    let ids: Vec<v1::old::OldContentId> = contents.iter().map(old_content_id).collect();
+    unhashed::put_raw(&v1::old::DidContents::<Test>::hashed_key_for(did), &ids.encode());
+}
+
+/// Writes a proof of `content` and its `DidContentExists` entry as the registry stored them
+/// before content ids, registration times and capture metadata changed. Returns the proof key.
+fn put_old_proof(
+    did: &BoundedVec<u8, ConstU32<1024>>,
+    device: &BoundedVec<u8, ConstU32<1024>>,
+    content: [u8; 32],
+) -> Vec<u8> {
+    let old_id = old_content_id(&content);
+    let proof = (
+        old_id.clone(),
+        3u64,
+        did.clone(),
+        ALICE,
+        content,
+        b"image".to_vec(),
+        b"profile picture".to_vec(),
+        b"legacy metadata".to_vec(),
+        device.clone(),
+    );
+    let key = v1::old::Proofs::<Test>::hashed_key_for(&old_id);
+    unhashed::put_raw(&key, &proof.encode());
+    unhashed::put_raw(&v1::old::DidContentExists::<Test>::hashed_key_for(did, &old_id), &true.encode());
+    key
+}
+
+#[test]
+fn should_register_past_the_old_vector_limit() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        // the old layout held at most 10_000 ids per DID
+        DidContentCount::<Test>::insert(&did, 10_000);
+
+        let content_id = register_content(&did, &device, CONTENT1);
+
+        assert_eq!(DidContentIndex::<Test>::get(&did, 10_000), Some(content_id.clone()));
+        assert_eq!(Template::did_content_count(&did), 10_001);
+        assert!(Template::does_did_have_content(&did, &content_id));
+    });
+}
+
+#[test]
+fn should_migrate_did_contents_in_steps() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Template>();
+        let did1: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DID.to_vec()).unwrap();
+        let did2: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DID2.to_vec()).unwrap();
+        let device: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DEVICE1.to_vec()).unwrap();
+        let contents1: Vec<[u8; 32]> = (1..=5u8).map(|n| [n; 32]).collect();
+        let contents2: Vec<[u8; 32]> = (6..=7u8).map(|n| [n; 32]).collect();
+        put_old_did_contents(&did1, &contents1);
+        put_old_did_contents(&did2, &contents2);
+        for (did, contents) in [(&did1, &contents1), (&did2, &contents2)] {
+            for content in contents {
+                put_old_proof(did, &device, *content);
+            }
+        }
+        let ids1: Vec<ContentId> = contents1.iter().map(generate_content_id).collect();
+        let ids2: Vec<ContentId> = contents2.iter().map(generate_content_id).collect();
+
+        // enough for loading a DID and moving two entries
+        let step = <() as WeightInfo>::migration_v1_step();
+        let load = <Test as frame_system::Config>::DbWeight::get().reads_writes(2, 2);
+        let limit = Weight::from_parts(load.ref_time() + 2 * step.ref_time(), u64::MAX);
+
+        let mut cursor = None;
+        let mut steps = 0;
+        loop {
+            let mut meter = WeightMeter::with_limit(limit);
+            cursor = v1::LazyMigrationV1::<Test, ()>::step(cursor, &mut meter).unwrap();
+            steps += 1;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert!(steps > 3);
+
+        for (did, ids) in [(&did1, &ids1), (&did2, &ids2)] {
+            assert_eq!(Template::did_content_count(did), ids.len() as u32);
+            assert_eq!(Template::did_contents_page(did, 0, 100), (ids.clone(), None));
+            assert!(!v1::old::DidContents::<Test>::contains_key(did));
+        }
+        assert_eq!(Template::on_chain_storage_version(), 1);
+    });
+}
+
+#[test]
+fn should_migrate_old_proofs_to_the_current_layout() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Template>();
+        let did: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DID.to_vec()).unwrap();
+        let device: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DEVICE1.to_vec()).unwrap();
+        put_old_did_contents(&did, &[CONTENT1]);
+        let old_key = put_old_proof(&did, &device, CONTENT1);
+
+        let mut meter = WeightMeter::new();
+        assert!(matches!(v1::LazyMigrationV1::<Test, ()>::step(None, &mut meter), Ok(None)));
+
+        let content_id = generate_content_id(&CONTENT1);
+        let proof = Template::get_content(&content_id).unwrap();
+        assert_eq!(proof.content_id, content_id);
+        assert_eq!(proof.exists_from, 3);
+        assert_eq!(proof.registered_at, 0);
+        assert_eq!((proof.did, proof.signer, proof.device), (did.clone(), ALICE, device));
+        assert_eq!(
+            proof.content_metadata,
+            CaptureMetadata::Legacy(BoundedVec::try_from(b"legacy metadata".to_vec()).unwrap())
+        );
+        assert_eq!(proof.ipfs_cid, None);
+        assert!(Template::does_did_have_content(&did, &content_id));
+        assert_eq!(unhashed::get_raw(&old_key), None);
+        assert_eq!(DidContentIndex::<Test>::get(&did, 0), Some(content_id));
+    });
+}
+
+#[test]
+fn should_backfill_lookups_of_migrated_proofs() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Template>();
+        let did: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DID.to_vec()).unwrap();
+        let device: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DEVICE1.to_vec()).unwrap();
+        put_old_did_contents(&did, &[CONTENT1, CONTENT2]);
+        put_old_proof(&did, &device, CONTENT1);
+        put_old_proof(&did, &device, CONTENT2);
+
+        let mut meter = WeightMeter::new();
+        assert!(matches!(v1::LazyMigrationV1::<Test, ()>::step(None, &mut meter), Ok(None)));
+
+        let ids = vec![generate_content_id(&CONTENT1), generate_content_id(&CONTENT2)];
+        assert_eq!(Template::content_count(), 2);
+        assert_eq!(Template::device_content_count(&device), 2);
+        let (mut by_device, _) = Template::device_contents_page(&device, None, 10);
+        by_device.sort_by_key(|id| id.encode());
+        let mut expected = ids.clone();
+        expected.sort_by_key(|id| id.encode());
+        assert_eq!(by_device, expected);
+        for id in &ids {
+            assert!(BlockContents::<Test>::contains_key(3, id));
+        }
+    });
+}
+
+#[test]
+fn should_not_register_migrated_content_again() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        StorageVersion::new(0).put::<Template>();
+        put_old_did_contents(&did, &[CONTENT1]);
+        put_old_proof(&did, &device, CONTENT1);
+
+        let mut meter = WeightMeter::new();
+        assert!(matches!(v1::LazyMigrationV1::<Test, ()>::step(None, &mut meter), Ok(None)));
+
+        // the migrated proof has the id registering the same media derives
+        assert!(Template::get_content(generate_content_id(&CONTENT1)).is_some());
+        assert_noop!(
+            create_with_metadata(&did, &device, capture_metadata()),
+            Error::<Test>::ContentAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn should_drop_listed_ids_without_proof() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Template>();
+        let did: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DID.to_vec()).unwrap();
+        let device: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DEVICE1.to_vec()).unwrap();
+        put_old_did_contents(&did, &[CONTENT1, CONTENT2]);
+        put_old_proof(&did, &device, CONTENT2);
+
+        let mut meter = WeightMeter::new();
+        assert!(matches!(v1::LazyMigrationV1::<Test, ()>::step(None, &mut meter), Ok(None)));
+
+        assert_eq!(
+            Template::did_contents_page(&did, 0, 100),
+            (vec![generate_content_id(&CONTENT2)], None)
+        );
+    });
+}
+
+#[test]
+fn should_fail_migration_on_undecodable_proof() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Template>();
+        let did: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DID.to_vec()).unwrap();
+        let device: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DEVICE1.to_vec()).unwrap();
+        put_old_did_contents(&did, &[CONTENT1]);
+        let old_key = put_old_proof(&did, &device, CONTENT1);
+        let mut raw = unhashed::get_raw(&old_key).unwrap();
+        raw.truncate(40);
+        unhashed::put_raw(&old_key, &raw);
+
+        let mut meter = WeightMeter::new();
+        assert!(matches!(
+            v1::LazyMigrationV1::<Test, ()>::step(None, &mut meter),
+            Err(SteppedMigrationError::Failed)
+        ));
+        assert_eq!(unhashed::get_raw(&old_key), Some(raw));
+    });
+}
+
+#[test]
+fn should_not_migrate_twice() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<Template>();
+        let did: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DID.to_vec()).unwrap();
+        put_old_did_contents(&did, &[CONTENT1]);
+
+        let mut meter = WeightMeter::new();
+        assert!(matches!(v1::LazyMigrationV1::<Test, ()>::step(None, &mut meter), Ok(None)));
+        assert_eq!(DidContentCount::<Test>::get(&did), 0);
+        assert!(v1::old::DidContents::<Test>::contains_key(&did));
+    });
+}
+
+#[test]
+fn should_fail_migration_on_undecodable_did_contents() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Template>();
+        let did: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DID.to_vec()).unwrap();
+        let key = v1::old::DidContents::<Test>::hashed_key_for(&did);
+        // one id and a truncated second one
+        let mut raw = vec![1u8; 2 + 36 + 10];
+        raw[0] = 2 << 2;
+        unhashed::put_raw(&key, &raw);
+
+        let mut meter = WeightMeter::new();
+        assert!(matches!(
+            v1::LazyMigrationV1::<Test, ()>::step(None, &mut meter),
+            Err(SteppedMigrationError::Failed)
+        ));
+        assert_eq!(DidContentCount::<Test>::get(&did), 0);
+        assert_eq!(unhashed::get_raw(&key), Some(raw));
+    });
+}
+
+#[test]
+fn should_fail_migration_step_without_weight() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Template>();
+        let mut meter = WeightMeter::with_limit(Weight::zero());
+        let required = <() as WeightInfo>::migration_v1_step();
+        assert!(matches!(
+            v1::LazyMigrationV1::<Test, ()>::step(None, &mut meter),
+            Err(SteppedMigrationError::InsufficientWeight { required: r }) if r == required
+        ));
+    });
+}
+
+// ============ Capture Metadata Tests ============
+
+#[test]
+fn should_store_capture_metadata() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        Timestamp::set_timestamp(1_792_231_380_000);
+        let metadata = capture_metadata();
+
+        assert_ok!(create_with_metadata(&did, &device, metadata.clone()));
+
+        let proof = Template::get_content(generate_content_id(&CONTENT1)).unwrap();
+        assert_eq!(proof.content_metadata, metadata);
+        assert_eq!(proof.content_metadata.version(), 1);
+        // the version is the first byte of the encoding
+        assert_eq!(metadata.encode()[0], 1);
+    });
+}
+
+#[test]
+fn should_reject_unknown_mime_type() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        for mime_type in [&b"text/html"[..], b"IMAGE/JPEG", b"image/", b""] {
+            let metadata = capture_metadata_with(|m| {
+                m.mime_type = BoundedVec::try_from(mime_type.to_vec()).unwrap()
+            });
+            assert_noop!(
+                create_with_metadata(&did, &device, metadata),
+                Error::<Test>::UnknownMimeType
+            );
+        }
+    });
+}
+
+#[test]
+fn should_accept_capture_time_within_drift() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let now = 1_792_231_380_000;
+        Timestamp::set_timestamp(now);
+        let drift = <<Test as crate::Config>::MaxCaptureTimeDrift as Get<u64>>::get();
+
+        let earlier = capture_metadata_with(|m| m.captured_at = now - drift);
+        assert_ok!(create_with_metadata(&did, &device, earlier));
+        let later = capture_metadata_with(|m| m.captured_at = now + drift);
+        assert_ok!(register_with_metadata(&did, &device, CONTENT2, later));
+    });
+}
+
+#[test]
+fn should_reject_capture_time_outside_drift() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let now = 1_792_231_380_000;
+        Timestamp::set_timestamp(now);
+        let drift = <<Test as crate::Config>::MaxCaptureTimeDrift as Get<u64>>::get();
+
+        for captured_at in [now - drift - 1, now + drift + 1, 0, u64::MAX] {
+            let metadata = capture_metadata_with(|m| m.captured_at = captured_at);
+            assert_noop!(
+                create_with_metadata(&did, &device, metadata),
+                Error::<Test>::CaptureTimeOutOfRange
+            );
+        }
+    });
+}
+
+#[test]
+fn should_validate_location() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        for (latitude, longitude) in [(9_001, 0), (-9_001, 0), (0, 18_001), (0, -18_001)] {
+            let metadata = capture_metadata_with(|m| {
+                m.location = Some(GeoLocation { latitude, longitude })
+            });
+            assert_noop!(
+                create_with_metadata(&did, &device, metadata),
+                Error::<Test>::InvalidLocation
+            );
+        }
+
+        let edge = capture_metadata_with(|m| {
+            m.location = Some(GeoLocation { latitude: -9_000, longitude: 18_000 })
+        });
+        assert_ok!(create_with_metadata(&did, &device, edge));
+        let unlocated = capture_metadata_with(|m| {
+            m.location = None;
+            m.camera = None;
+            m.software_version = None;
+        });
+        assert_ok!(register_with_metadata(&did, &device, CONTENT2, unlocated));
+    });
+}
+
+// ============ Commit-Reveal Tests ============
+
+const SALT: [u8; 32] = [7; 32];
+
+#[test]
+fn should_reveal_commitment_as_proof_from_commit_block() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let commit_moment = 1_792_231_380_000;
+        Timestamp::set_timestamp(commit_moment);
+        RelayBlockNumber::set(100);
+        let metadata = capture_metadata();
+        let commitment = commit(&did, &device, &CONTENT1, None);
+        System::assert_last_event(
+            Event::ContentCommitted { commitment, did: did.clone(), who: ALICE, expires_at: None }
+                .into(),
+        );
+        // nothing about the content is on chain yet
+        assert!(!Template::does_did_have_content(&did, &generate_content_id(&CONTENT1)));
+
+        // publication, well after the capture time drift
+        System::set_block_number(50);
+        Timestamp::set_timestamp(commit_moment + 10 * 60_000);
+        RelayBlockNumber::set(200);
+        assert_ok!(reveal(commitment, SALT, CONTENT1, metadata));
+
+        let content_id = generate_content_id(&CONTENT1);
+        let proof = Template::get_content(&content_id).unwrap();
+        assert_eq!(proof.exists_from, 1);
+        assert_eq!(proof.registered_at, commit_moment);
+        assert_eq!(proof.relay_parent_number, 100);
+        assert_eq!(proof.did, did);
+        assert_eq!(proof.device, device);
+        assert!(Template::does_did_have_content(&did, &content_id));
+        assert_eq!(Template::block_range_page(1, 1, None, 10), (vec![content_id.clone()], None));
+        assert_eq!(Template::commitment(commitment), None);
+        System::assert_last_event(Event::CommitmentRevealed { commitment, content_id }.into());
+    });
+}
+
+#[test]
+fn should_reject_reveal_not_matching_commitment() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let commitment = commit(&did, &device, &CONTENT1, None);
+
+        assert_noop!(
+            reveal(commitment, [8; 32], CONTENT1, capture_metadata()),
+            Error::<Test>::CommitmentMismatch
+        );
+        assert_noop!(
+            reveal(commitment, SALT, CONTENT2, capture_metadata()),
+            Error::<Test>::CommitmentMismatch
+        );
+        assert_noop!(
+            reveal([0; 32], SALT, CONTENT1, capture_metadata()),
+            Error::<Test>::UnknownCommitment
+        );
+    });
+}
+
+#[test]
+fn should_tie_commitment_to_did_and_device() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let other_device: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DEVICE2.to_vec()).unwrap();
+        assert_ok!(IdentityRegistry::register_device(
+            RuntimeOrigin::signed(ALICE),
+            did.clone(),
+            other_device.clone()
+        ));
+        let content_id = generate_content_id(&CONTENT1);
+        let for_other_device = Template::commitment_hash(&did, &other_device, &content_id, &SALT);
+
+        // committed for one device, but the hash was computed for another
+        assert_ok!(Template::commit_content(
+            RuntimeOrigin::signed(ALICE),
+            did.clone(),
+            device.clone(),
+            for_other_device,
+            None
+        ));
+        assert_noop!(
+            reveal(for_other_device, SALT, CONTENT1, capture_metadata()),
+            Error::<Test>::CommitmentMismatch
+        );
+    });
+}
+
+#[test]
+fn should_check_rights_on_commit_and_reveal() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let commitment = Template::commitment_hash(&did, &device, &generate_content_id(&CONTENT1), &SALT);
+        assert_noop!(
+            Template::commit_content(RuntimeOrigin::signed(BOB), did.clone(), device.clone(), commitment, None),
+            Error::<Test>::SignerDoesNotHaveRight
+        );
+
+        assert_ok!(Template::commit_content(
+            RuntimeOrigin::signed(ALICE),
+            did.clone(),
+            device.clone(),
+            commitment,
+            None
+        ));
+        assert_noop!(
+            Template::commit_content(RuntimeOrigin::signed(ALICE), did.clone(), device, commitment, None),
+            Error::<Test>::CommitmentAlreadyExists
+        );
+        assert_noop!(
+            Template::reveal_content(
+                RuntimeOrigin::signed(BOB),
+                commitment,
+                SALT,
+                CONTENT1,
+                HashAlgorithm::Blake2b256,
+                BoundedVec::try_from(b"image".to_vec()).unwrap(),
+                BoundedVec::try_from(b"leak".to_vec()).unwrap(),
+                capture_metadata(),
+                None
+            ),
+            Error::<Test>::SignerDoesNotHaveRight
+        );
+    });
+}
+
+#[test]
+fn should_expire_unrevealed_commitment() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let commitment = commit(&did, &device, &CONTENT1, Some(5));
+        assert_eq!(Template::commitment(commitment).unwrap().expires_at, Some(6));
+        assert_eq!(CommitmentExpiries::<Test>::get(6).to_vec(), vec![commitment]);
+
+        Template::on_initialize(5);
+        assert!(Template::commitment(commitment).is_some());
+
+        System::set_block_number(6);
+        Template::on_initialize(6);
+        assert_eq!(Template::commitment(commitment), None);
+        assert!(CommitmentExpiries::<Test>::get(6).is_empty());
+        System::assert_last_event(Event::CommitmentExpired { commitment }.into());
+        assert_noop!(
+            reveal(commitment, SALT, CONTENT1, capture_metadata()),
+            Error::<Test>::UnknownCommitment
+        );
+    });
+}
+
+#[test]
+fn should_unschedule_expiry_on_reveal() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let first = commit(&did, &device, &CONTENT1, Some(5));
+        let second = commit(&did, &device, &CONTENT2, Some(5));
+
+        assert_ok!(reveal(first, SALT, CONTENT1, capture_metadata()));
+        assert_eq!(CommitmentExpiries::<Test>::get(6).to_vec(), vec![second]);
+
+        System::set_block_number(6);
+        Template::on_initialize(6);
+        assert!(Template::get_content(generate_content_id(&CONTENT1)).is_some());
+        assert_eq!(Template::commitment(second), None);
+    });
+}
+
+#[test]
+fn should_bound_commitment_expiries() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let commitment = |content: [u8; 32]| {
+            Template::commitment_hash(&did, &device, &generate_content_id(&content), &SALT)
+        };
+        assert_noop!(
+            Template::commit_content(RuntimeOrigin::signed(ALICE), did.clone(), device.clone(), commitment([1; 32]), Some(0)),
+            Error::<Test>::InvalidCommitmentExpiry
+        );
+        for content in [[1; 32], [2; 32]] {
+            assert_ok!(Template::commit_content(
+                RuntimeOrigin::signed(ALICE),
+                did.clone(),
+                device.clone(),
+                commitment(content),
+                Some(3)
+            ));
+        }
+        assert_noop!(
+            Template::commit_content(RuntimeOrigin::signed(ALICE), did.clone(), device.clone(), commitment([3; 32]), Some(3)),
+            Error::<Test>::TooManyCommitmentExpiries
+        );
+        // without expiry there is no limit
+        assert_ok!(Template::commit_content(
+            RuntimeOrigin::signed(ALICE),
+            did.clone(),
+            device.clone(),
+            commitment([3; 32]),
+            None
+        ));
+        assert_eq!(Commitments::<Test>::iter().count(), 3);
+    });
+}
+
+#[test]
+fn should_hold_commitment_deposit_until_reveal() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let commitment = commit(&did, &device, &CONTENT1, None);
+        assert_eq!(commitment_deposit_held(ALICE), 10);
+        assert_eq!(Template::commitment(commitment).unwrap().deposit, 10);
+
+        assert_ok!(reveal(commitment, SALT, CONTENT1, capture_metadata()));
+
+        assert_eq!(commitment_deposit_held(ALICE), 0);
+        assert_eq!(proof_deposit_held(ALICE), 100);
+    });
+}
+
+#[test]
+fn should_release_commitment_deposit_on_expiry() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        commit(&did, &device, &CONTENT1, Some(5));
+        assert_eq!(commitment_deposit_held(ALICE), 10);
+
+        System::set_block_number(6);
+        Template::on_initialize(6);
+
+        assert_eq!(commitment_deposit_held(ALICE), 0);
+    });
+}
+
+#[test]
+fn should_withdraw_commitment_and_release_deposit() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let commitment = commit(&did, &device, &CONTENT1, Some(5));
+
+        assert_noop!(
+            Template::withdraw_commitment(RuntimeOrigin::signed(BOB), commitment),
+            Error::<Test>::NotCommitmentSigner
+        );
+        assert_ok!(Template::withdraw_commitment(RuntimeOrigin::signed(ALICE), commitment));
+
+        assert_eq!(commitment_deposit_held(ALICE), 0);
+        assert_eq!(Template::commitment(commitment), None);
+        assert!(CommitmentExpiries::<Test>::get(6).is_empty());
+        System::assert_last_event(Event::CommitmentWithdrawn { commitment }.into());
+        assert_noop!(
+            Template::withdraw_commitment(RuntimeOrigin::signed(ALICE), commitment),
+            Error::<Test>::UnknownCommitment
+        );
+    });
+}
+
+// ============ Attestation Tests ============
+
+const NEWSROOM1: [u8; 5] = [21, 22, 23, 24, 25];
+const NEWSROOM2: [u8; 5] = [31, 32, 33, 34, 35];
+const NEWSROOM3: [u8; 5] = [41, 42, 43, 44, 45];
+
+#[test]
+fn should_attest_content() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+        let newsroom = setup_attester(OSCAR, NEWSROOM1);
+
+        assert_ok!(Template::attest_content(
+            RuntimeOrigin::signed(OSCAR),
+            content_id.clone(),
+            newsroom.clone(),
+            AttestationKind::FactCheck,
+            Some([9; 32])
+        ));
+
+        let attestation = Template::attestation(&content_id, &newsroom).unwrap();
+        assert_eq!(attestation.kind, AttestationKind::FactCheck);
+        assert_eq!(attestation.signer, OSCAR);
+        assert_eq!(attestation.comment_hash, Some([9; 32]));
+        assert_eq!(attestation.attested_at, 1);
+        assert_eq!(Template::attestation_count(&content_id), 1);
+        assert_eq!(Template::attestations(&content_id), vec![(newsroom.clone(), attestation)]);
+        System::assert_last_event(
+            Event::ContentAttested {
+                content_id,
+                attester: newsroom,
+                who: OSCAR,
+                kind: AttestationKind::FactCheck,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn should_require_attest_right() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+        // OSCAR controls the DID but was only given the Impersonate right
+        let newsroom: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(NEWSROOM1.to_vec()).unwrap();
+        setup_did_with_rights(OSCAR, newsroom.clone(), vec![]);
+
+        assert_noop!(
+            Template::attest_content(
+                RuntimeOrigin::signed(OSCAR),
+                content_id.clone(),
+                newsroom,
+                AttestationKind::Witness,
+                None
+            ),
+            Error::<Test>::SignerDoesNotHaveRight
+        );
+        let other = setup_attester(BOB, NEWSROOM2);
+        assert_noop!(
+            Template::attest_content(RuntimeOrigin::signed(OSCAR), content_id, other, AttestationKind::Witness, None),
+            Error::<Test>::SignerDoesNotHaveRight
+        );
+    });
+}
+
+#[test]
+fn should_reject_invalid_attestations() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+        let newsroom = setup_attester(OSCAR, NEWSROOM1);
+
+        assert_noop!(
+            Template::attest_content(
+                RuntimeOrigin::signed(OSCAR),
+                generate_content_id(&CONTENT2),
+                newsroom.clone(),
+                AttestationKind::Editorial,
+                None
+            ),
+            Error::<Test>::ContentNotFound
+        );
+
+        assert_ok!(IdentityRegistry::add_right_for_signatory(
+            RuntimeOrigin::signed(ALICE),
+            did.clone(),
+            ALICE,
+            BaseRight::Attest,
+            pallet_identity_registry::RightDuration::Permanent
+        ));
+        assert_noop!(
+            Template::attest_content(RuntimeOrigin::signed(ALICE), content_id.clone(), did, AttestationKind::Editorial, None),
+            Error::<Test>::CannotAttestOwnContent
+        );
+
+        assert_ok!(Template::attest_content(
+            RuntimeOrigin::signed(OSCAR),
+            content_id.clone(),
+            newsroom.clone(),
+            AttestationKind::Editorial,
+            None
+        ));
+        assert_noop!(
+            Template::attest_content(RuntimeOrigin::signed(OSCAR), content_id, newsroom, AttestationKind::Witness, None),
+            Error::<Test>::AlreadyAttested
+        );
+    });
+}
+
+#[test]
+fn should_bound_attestations_per_content() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+        let newsrooms = [
+            setup_attester(OSCAR, NEWSROOM1),
+            setup_attester(OSCAR, NEWSROOM2),
+            setup_attester(OSCAR, NEWSROOM3),
+        ];
+        for newsroom in &newsrooms[..2] {
+            assert_ok!(Template::attest_content(
+                RuntimeOrigin::signed(OSCAR),
+                content_id.clone(),
+                newsroom.clone(),
+                AttestationKind::Editorial,
+                None
+            ));
+        }
+        assert_noop!(
+            Template::attest_content(
+                RuntimeOrigin::signed(OSCAR),
+                content_id.clone(),
+                newsrooms[2].clone(),
+                AttestationKind::Editorial,
+                None
+            ),
+            Error::<Test>::TooManyAttestations
+        );
+
+        // revoking frees a slot
+        assert_ok!(Template::revoke_attestation(RuntimeOrigin::signed(OSCAR), content_id.clone(), newsrooms[0].clone()));
+        System::assert_last_event(
+            Event::AttestationRevoked { content_id: content_id.clone(), attester: newsrooms[0].clone() }.into(),
+        );
+        assert_eq!(Template::attestation(&content_id, &newsrooms[0]), None);
+        assert_eq!(Template::attestation_count(&content_id), 1);
+        assert_ok!(Template::attest_content(
+            RuntimeOrigin::signed(OSCAR),
+            content_id.clone(),
+            newsrooms[2].clone(),
+            AttestationKind::Editorial,
+            None
+        ));
+        assert_eq!(Template::attestations(&content_id).len(), 2);
+    });
+}
+
+#[test]
+fn should_revoke_only_existing_attestation() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+        let newsroom = setup_attester(OSCAR, NEWSROOM1);
+
+        assert_noop!(
+            Template::revoke_attestation(RuntimeOrigin::signed(OSCAR), content_id.clone(), newsroom.clone()),
+            Error::<Test>::AttestationNotFound
+        );
+        assert_ok!(Template::attest_content(
+            RuntimeOrigin::signed(OSCAR),
+            content_id.clone(),
+            newsroom.clone(),
+            AttestationKind::Witness,
+            None
+        ));
+        assert_noop!(
+            Template::revoke_attestation(RuntimeOrigin::signed(BOB), content_id.clone(), newsroom.clone()),
+            Error::<Test>::SignerDoesNotHaveRight
+        );
+        assert_ok!(Template::revoke_attestation(RuntimeOrigin::signed(OSCAR), content_id.clone(), newsroom));
+        assert!(!AttestationCount::<Test>::contains_key(&content_id));
+    });
+}
+
+// ============ Licensing Tests ============
+
+const PAYOUT: u64 = 500;
+
+#[test]
+fn should_set_license_and_offer() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+        let offer = LicenseOffer { license: spdx("CC-BY-4.0"), price: 500 };
+
+        assert_ok!(Template::set_license(
+            RuntimeOrigin::signed(ALICE),
+            content_id.clone(),
+            spdx("CC-BY-NC-4.0"),
+            Some(offer.clone())
+        ));
+
+        assert_eq!(
+            Template::license(&content_id),
+            Some(ContentLicense { license: spdx("CC-BY-NC-4.0"), offer: Some(offer.clone()) })
+        );
+        System::assert_last_event(
+            Event::LicenseSet { content_id: content_id.clone(), license: spdx("CC-BY-NC-4.0"), offer: Some(offer) }
+                .into(),
+        );
+
+        // a custom license is only known by its hash
+        assert_ok!(Template::set_license(
+            RuntimeOrigin::signed(ALICE),
+            content_id.clone(),
+            License::Custom([3; 32]),
+            None
+        ));
+        assert_eq!(
+            Template::license(&content_id),
+            Some(ContentLicense { license: License::Custom([3; 32]), offer: None })
+        );
+    });
+}
+
+#[test]
+fn should_reject_invalid_license() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+
+        assert_noop!(
+            Template::set_license(RuntimeOrigin::signed(ALICE), content_id.clone(), spdx("cc-by-4.0"), None),
+            Error::<Test>::UnknownLicense
+        );
+        assert_noop!(
+            Template::set_license(
+                RuntimeOrigin::signed(ALICE),
+                content_id.clone(),
+                License::AllRightsReserved,
+                Some(LicenseOffer { license: spdx("Proprietary"), price: 1 })
+            ),
+            Error::<Test>::UnknownLicense
+        );
+        assert_noop!(
+            Template::set_license(
+                RuntimeOrigin::signed(ALICE),
+                generate_content_id(&CONTENT2),
+                License::AllRightsReserved,
+                None
+            ),
+            Error::<Test>::ContentNotFound
+        );
+        // BOB is a signatory of the DID without the Update right
+        assert_noop!(
+            Template::set_license(RuntimeOrigin::signed(BOB), content_id, License::AllRightsReserved, None),
+            Error::<Test>::SignerDoesNotHaveRight
+        );
+    });
+}
+
+#[test]
+fn should_purchase_license() {
+    new_test_ext().execute_with(|| {
+        let content_id = setup_license_offer(500);
+        let oscar_balance = Balances::free_balance(OSCAR);
+
+        assert_ok!(Template::purchase_license(RuntimeOrigin::signed(OSCAR), content_id.clone(), 600));
+
+        assert_eq!(Balances::free_balance(OSCAR), oscar_balance - 500);
+        assert_eq!(Balances::free_balance(PAYOUT), 500);
+        assert_eq!(
+            Template::license_receipt(&content_id, OSCAR),
+            Some(LicenseReceipt { license: spdx("CC-BY-4.0"), price: 500, payee: PAYOUT, purchased_at: 1 })
+        );
+        assert!(Template::holds_license(&content_id, &OSCAR));
+        assert!(!Template::holds_license(&content_id, &BOB));
+        System::assert_last_event(
+            Event::LicensePurchased {
+                content_id: content_id.clone(),
+                buyer: OSCAR,
+                payee: PAYOUT,
+                license: spdx("CC-BY-4.0"),
+                price: 500,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Template::purchase_license(RuntimeOrigin::signed(OSCAR), content_id.clone(), 500),
+            Error::<Test>::AlreadyLicensed
+        );
+
+        // withdrawing the offer keeps the receipts
+        assert_ok!(Template::set_license(
+            RuntimeOrigin::signed(ALICE),
+            content_id.clone(),
+            License::AllRightsReserved,
+            None
+        ));
+        assert!(Template::holds_license(&content_id, &OSCAR));
+        assert_noop!(
+            Template::purchase_license(RuntimeOrigin::signed(BOB), content_id, 500),
+            Error::<Test>::NoLicenseOffer
+        );
+    });
+}
+
+#[test]
+fn should_reject_license_purchase() {
+    new_test_ext().execute_with(|| {
+        let content_id = setup_license_offer(500);
+
+        assert_noop!(
+            Template::purchase_license(RuntimeOrigin::signed(OSCAR), content_id.clone(), 499),
+            Error::<Test>::PriceAboveLimit
+        );
+        assert_noop!(
+            Template::purchase_license(RuntimeOrigin::signed(OSCAR), generate_content_id(&CONTENT2), 500),
+            Error::<Test>::ContentNotFound
+        );
+        // an account without funds
+        assert_noop!(
+            Template::purchase_license(RuntimeOrigin::signed(600), content_id.clone(), 500),
+            TokenError::FundsUnavailable
+        );
+
+        assert_ok!(Template::set_payout_account(
+            RuntimeOrigin::signed(ALICE),
+            BoundedVec::try_from(DID.to_vec()).unwrap(),
+            None
+        ));
+        assert_noop!(
+            Template::purchase_license(RuntimeOrigin::signed(OSCAR), content_id, 500),
+            Error::<Test>::NoPayoutAccount
+        );
+    });
+}
+
+#[test]
+fn should_require_update_right_for_payout_account() {
+    new_test_ext().execute_with(|| {
+        let (did, _) = setup_did_and_device(DID, DEVICE1);
+
+        assert_noop!(
+            Template::set_payout_account(RuntimeOrigin::signed(BOB), did.clone(), Some(BOB)),
+            Error::<Test>::SignerDoesNotHaveRight
+        );
+        assert_ok!(Template::set_payout_account(RuntimeOrigin::signed(ALICE), did.clone(), Some(PAYOUT)));
+        assert_eq!(Template::payout_account(&did), Some(PAYOUT));
+        System::assert_last_event(Event::PayoutAccountSet { did, account: Some(PAYOUT) }.into());
+    });
+}
+
+// ============ Deposit and Quota Tests ============
+
+#[test]
+fn should_hold_deposit_for_proof() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let free = Balances::free_balance(ALICE);
+
+        let content_id = register_content(&did, &device, CONTENT1);
+
+        assert_eq!(proof_deposit_held(ALICE), 100);
+        assert_eq!(Balances::free_balance(ALICE), free - 100);
+        assert_eq!(Template::proof_deposit(&content_id), Some((ALICE, 100)));
+
+        register_content(&did, &device, CONTENT2);
+        assert_eq!(proof_deposit_held(ALICE), 200);
+    });
+}
+
+#[test]
+fn should_fail_to_register_without_deposit() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), ALICE, 50));
+
+        assert_noop!(create_with_metadata(&did, &device, capture_metadata()), Error::<Test>::InsufficientDeposit);
+        assert_eq!(Template::quota_usage(&did), None);
+    });
+}
+
+#[test]
+fn should_reject_registration_over_quota() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        assert_ok!(Template::set_did_quota(RuntimeOrigin::root(), did.clone(), Some(2)));
+
+        register_content(&did, &device, [1; 32]);
+        register_content(&did, &device, [2; 32]);
+        assert_eq!(Template::quota_usage(&did), Some(QuotaUsage { window_start: 1, used: 2 }));
+
+        assert_noop!(
+            register_with_metadata(&did, &device, [3; 32], capture_metadata()),
+            Error::<Test>::QuotaExceeded
+        );
+        // revealing a commitment registers a proof too
+        let commitment = commit(&did, &device, &[3; 32], None);
+        assert_noop!(reveal(commitment, SALT, [3; 32], capture_metadata()), Error::<Test>::QuotaExceeded);
+
+        // the quota is per DID
+        let (other_did, other_device) = (
+            BoundedVec::try_from(DID2.to_vec()).unwrap(),
+            BoundedVec::try_from(DEVICE2.to_vec()).unwrap(),
+        );
+        setup_did_with_rights(ALICE, other_did.clone(), vec![]);
+        register_device_for_did(ALICE, other_did.clone(), other_device.clone());
+        register_content(&other_did, &other_device, [3; 32]);
+    });
+}
+
+#[test]
+fn should_roll_quota_over_after_period() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        assert_ok!(Template::set_did_quota(RuntimeOrigin::root(), did.clone(), Some(2)));
+        register_content(&did, &device, [1; 32]);
+        System::set_block_number(5);
+        register_content(&did, &device, [2; 32]);
+
+        // the window opened in block 1 lasts through block 10
+        System::set_block_number(10);
+        assert_noop!(
+            register_with_metadata(&did, &device, [3; 32], capture_metadata()),
+            Error::<Test>::QuotaExceeded
+        );
+
+        System::set_block_number(11);
+        register_content(&did, &device, [3; 32]);
+        assert_eq!(Template::quota_usage(&did), Some(QuotaUsage { window_start: 11, used: 1 }));
+        register_content(&did, &device, [4; 32]);
+        assert_noop!(
+            register_with_metadata(&did, &device, [5; 32], capture_metadata()),
+            Error::<Test>::QuotaExceeded
+        );
+
+        // a quiet period resets the window on the next registration
+        System::set_block_number(40);
+        register_content(&did, &device, [5; 32]);
+        assert_eq!(Template::quota_usage(&did), Some(QuotaUsage { window_start: 40, used: 1 }));
+    });
+}
+
+#[test]
+fn should_let_governance_raise_quota() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        // the mock default quota is 10
+        for n in 1..=10u8 {
+            register_content(&did, &device, [n; 32]);
+        }
+        assert_noop!(
+            register_with_metadata(&did, &device, [11; 32], capture_metadata()),
+            Error::<Test>::QuotaExceeded
+        );
+
+        assert_noop!(
+            Template::set_did_quota(RuntimeOrigin::signed(ALICE), did.clone(), Some(20)),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Template::set_did_quota(RuntimeOrigin::root(), did.clone(), Some(20)));
+        System::assert_last_event(Event::DidQuotaSet { did: did.clone(), quota: Some(20) }.into());
+        assert_eq!(Template::did_quota(&did), 20);
+        register_content(&did, &device, [11; 32]);
+
+        assert_ok!(Template::set_did_quota(RuntimeOrigin::root(), did.clone(), None));
+        assert_eq!(Template::did_quota(&did), 10);
+        assert_noop!(
+            register_with_metadata(&did, &device, [12; 32], capture_metadata()),
+            Error::<Test>::QuotaExceeded
+        );
+    });
+}
+
+// ============ XCM Query Tests ============
+
+const SIBLING: u64 = 2004;
+const CALLBACK: [u8; 2] = [42, 3];
+
+#[test]
+fn should_answer_proof_status_query() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+        let response_weight = Weight::from_parts(1_000_000, 1024);
+
+        assert_ok!(Template::query_proof_status(
+            RuntimeOrigin::signed(SIBLING),
+            content_id.clone(),
+            7,
+            CALLBACK,
+            response_weight
+        ));
+
+        let status = ProofStatus { exists_from: Some(1), disputed: false };
+        let mut call = CALLBACK.to_vec();
+        (7u64, &content_id, &status).encode_to(&mut call);
+        let querier = Location::new(1, [Junction::Parachain(SIBLING as u32)]);
+        assert_eq!(
+            SentXcm::get(),
+            vec![(
+                querier.clone(),
+                Xcm(vec![
+                    Instruction::UnpaidExecution {
+                        weight_limit: WeightLimit::Limited(response_weight),
+                        check_origin: None,
+                    },
+                    Instruction::Transact {
+                        origin_kind: OriginKind::Xcm,
+                        fallback_max_weight: Some(response_weight),
+                        call: call.into(),
+                    },
+                ])
+            )]
+        );
+        System::assert_last_event(
+            Event::ProofStatusQueried { querier, query_id: 7, content_id, status }.into(),
+        );
+    });
+}
+
+#[test]
+fn should_report_unknown_and_disputed_content() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+
+        assert!(Template::proof_status(&content_id).is_trusted());
+        let unknown = Template::proof_status(&generate_content_id(&CONTENT2));
+        assert_eq!(unknown, ProofStatus { exists_from: None, disputed: false });
+        assert!(!unknown.is_trusted());
+
+        <Template as DisputeObserver<u64>>::on_dispute_opened(&content_id, 1);
+        let disputed = Template::proof_status(&content_id);
+        assert_eq!(disputed, ProofStatus { exists_from: Some(1), disputed: true });
+        assert!(!disputed.is_trusted());
+
+        assert_ok!(Template::query_proof_status(
+            RuntimeOrigin::signed(SIBLING),
+            content_id.clone(),
+            8,
+            CALLBACK,
+            Weight::zero()
+        ));
+        System::assert_last_event(
+            Event::ProofStatusQueried {
+                querier: Location::new(1, [Junction::Parachain(SIBLING as u32)]),
+                query_id: 8,
+                content_id,
+                status: disputed,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn should_only_answer_queries_from_other_chains() {
+    new_test_ext().execute_with(|| {
+        for origin in [RuntimeOrigin::root(), RuntimeOrigin::none()] {
+            assert_noop!(
+                Template::query_proof_status(origin, generate_content_id(&CONTENT1), 1, CALLBACK, Weight::zero()),
+                DispatchError::BadOrigin
+            );
+        }
+        assert!(SentXcm::get().is_empty());
+    });
+}
+
+#[test]
+fn should_cap_response_weight() {
+    new_test_ext().execute_with(|| {
+        let query = |response_weight| {
+            Template::query_proof_status(
+                RuntimeOrigin::signed(SIBLING),
+                generate_content_id(&CONTENT1),
+                1,
+                CALLBACK,
+                response_weight,
+            )
+        };
+        let max = MaxResponseWeight::get();
+        for response_weight in [max.add_ref_time(1), max.add_proof_size(1)] {
+            assert_noop!(query(response_weight), Error::<Test>::ResponseWeightTooHigh);
+        }
+        assert!(SentXcm::get().is_empty());
+
+        assert_ok!(query(max));
+        assert_eq!(SentXcm::get().len(), 1);
+    });
+}
+
+// ============ Read Proof Tests ============
+
+type MockHeader = frame::deps::sp_runtime::generic::Header<u64, BlakeTwo256>;
+
+#[test]
+fn read_proof_verifies_registered_content() {
+    let mut ext = new_test_ext();
+    let content_id = ext.execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        register_content(&did, &device, CONTENT1)
+    });
+    let stored = ext.execute_with(|| Template::get_content(&content_id)).unwrap();
+
+    let (header, read_proof) = prove_proof(&mut ext, &content_id);
+    let verified = content_proof_verifier::verify::<MockHeader, u64>(
+        "Template",
+        &content_id,
+        &header.encode(),
+        read_proof,
+    )
+    .unwrap();
+
+    assert_eq!(verified.block_hash, header.hash());
+    assert_eq!(verified.block_number, 1);
+    let proof = verified.proof.expect("content is registered");
+    assert_eq!(proof.content_id, content_id);
+    assert_eq!(proof.exists_from, stored.exists_from);
+    assert_eq!(proof.registered_at, stored.registered_at);
+    assert_eq!(proof.did, stored.did.into_inner());
+    assert_eq!(proof.signer, ALICE);
+    assert_eq!(proof.content, CONTENT1);
+    assert_eq!(proof.content_metadata, stored.content_metadata);
+    assert_eq!(proof.device, stored.device.into_inner());
+}
+
+#[test]
+fn read_proof_shows_unregistered_content_absent() {
+    let mut ext = new_test_ext();
+    ext.execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        register_content(&did, &device, CONTENT1);
+    });
+    let unregistered = generate_content_id(&CONTENT2);
+
+    let (header, read_proof) = prove_proof(&mut ext, &unregistered);
+    let verified = content_proof_verifier::verify::<MockHeader, u64>(
+        "Template",
+        &unregistered,
+        &header.encode(),
+        read_proof,
+    )
+    .unwrap();
+
+    assert_eq!(verified.proof, None);
+}
+
+#[test]
+fn read_proof_must_match_state_root() {
+    let mut ext = new_test_ext();
+    let content_id = ext.execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        register_content(&did, &device, CONTENT1)
+    });
+
+    let (mut header, read_proof) = prove_proof(&mut ext, &content_id);
+    header.state_root = H256::repeat_byte(1);
+
+    assert_eq!(
+        content_proof_verifier::verify::<MockHeader, u64>(
+            "Template",
+            &content_id,
+            &header.encode(),
+            read_proof,
+        ),
+        Err(content_proof_verifier::Error::InvalidReadProof)
+    );
+}
+
+#[test]
+fn read_proof_of_one_content_does_not_prove_another() {
+    let mut ext = new_test_ext();
+    let (content_id, other) = ext.execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        (register_content(&did, &device, CONTENT1), register_content(&did, &device, CONTENT2))
+    });
+
+    let (header, read_proof) = prove_proof(&mut ext, &content_id);
+
+    assert_eq!(
+        content_proof_verifier::verify::<MockHeader, u64>(
+            "Template",
+            &other,
+            &header.encode(),
+            read_proof,
+        ),
+        Err(content_proof_verifier::Error::InvalidReadProof)
+    );
+}
+
+// ============ Retraction Tests ============
+
+#[test]
+fn should_retract_content() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+        System::set_block_number(4);
+
+        assert_ok!(Template::retract_content(RuntimeOrigin::signed(ALICE), content_id.clone()));
+
+        assert_eq!(Template::retracted_at(&content_id), Some(4));
+        // the proof itself is kept
+        assert!(Template::get_content(&content_id).is_some());
+        System::assert_last_event(
+            Event::ContentRetracted { content_id: content_id.clone(), did, who: ALICE }.into(),
+        );
+        assert_noop!(
+            Template::retract_content(RuntimeOrigin::signed(ALICE), content_id),
+            Error::<Test>::ContentRetracted
+        );
+    });
+}
+
+#[test]
+fn should_release_proof_deposit_on_retraction() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+        assert_eq!(proof_deposit_held(ALICE), 100);
+        let free = Balances::free_balance(ALICE);
+
+        assert_ok!(Template::retract_content(RuntimeOrigin::signed(ALICE), content_id.clone()));
+
+        assert_eq!(proof_deposit_held(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), free + 100);
+        assert_eq!(Template::proof_deposit(&content_id), None);
+    });
+}
+
+#[test]
+fn should_only_let_owner_retract_content() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+
+        // BOB is a signatory of the DID without the Update right
+        assert_noop!(
+            Template::retract_content(RuntimeOrigin::signed(BOB), content_id),
+            Error::<Test>::SignerDoesNotHaveRight
+        );
+        assert_noop!(
+            Template::retract_content(RuntimeOrigin::signed(ALICE), generate_content_id(&CONTENT2)),
+            Error::<Test>::ContentNotFound
+        );
+    });
+}
+
+#[test]
+fn should_not_attest_or_license_retracted_content() {
+    new_test_ext().execute_with(|| {
+        let content_id = setup_license_offer(500);
+        let newsroom = setup_attester(OSCAR, NEWSROOM1);
+        assert_ok!(Template::retract_content(RuntimeOrigin::signed(ALICE), content_id.clone()));
+
+        assert_noop!(
+            Template::attest_content(
+                RuntimeOrigin::signed(OSCAR),
+                content_id.clone(),
+                newsroom,
+                AttestationKind::Witness,
+                None
+            ),
+            Error::<Test>::ContentRetracted
+        );
+        assert_noop!(
+            Template::purchase_license(RuntimeOrigin::signed(OSCAR), content_id, 500),
+            Error::<Test>::ContentRetracted
+        );
+    });
+}
+
+// ============ Court Integration Tests ============
+
+#[test]
+fn should_inspect_registered_content() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+        let unknown = generate_content_id(&CONTENT2);
+
+        assert!(<Template as ContentInspector<_, _, u64>>::exists(&content_id));
+        assert_eq!(<Template as ContentInspector<_, _, u64>>::owner(&content_id), Some(did));
+        assert_eq!(<Template as ContentInspector<_, _, u64>>::device(&content_id), Some(device));
+        assert_eq!(<Template as ContentInspector<_, _, u64>>::registered_at(&content_id), Some(1));
+
+        assert!(!<Template as ContentInspector<_, _, u64>>::exists(&unknown));
+        assert_eq!(<Template as ContentInspector<_, _, u64>>::owner(&unknown), None);
+    });
+}
+
+#[test]
+fn should_track_context_verdicts() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let convicted = register_content(&did, &device, CONTENT1);
+        let acquitted = register_content(&did, &device, CONTENT2);
+
+        for content_id in [&convicted, &acquitted] {
+            <Template as DisputeObserver<u64>>::on_dispute_opened(content_id, 2);
+            System::assert_last_event(Event::ContentDisputed { content_id: content_id.clone() }.into());
+            assert!(Template::proof_status(content_id).disputed);
+        }
+
+        <Template as DisputeObserver<u64>>::on_verdict(&convicted, ContextVerdict::Convicted, 7);
+        <Template as DisputeObserver<u64>>::on_verdict(&acquitted, ContextVerdict::Acquitted, 8);
+        System::assert_last_event(
+            Event::ContextVerdictReached { content_id: acquitted.clone(), verdict: ContextVerdict::Acquitted }
+                .into(),
+        );
+
+        assert_eq!(
+            Template::context_dispute(&convicted).unwrap().verdict,
+            Some((ContextVerdict::Convicted, 7))
+        );
+        assert!(Template::proof_status(&convicted).disputed);
+        assert_eq!(Template::context_dispute(&acquitted).unwrap().opened_at, 2);
+        assert!(Template::proof_status(&acquitted).is_trusted());
+    });
+}
+
+#[test]
+fn should_trust_content_again_when_dispute_is_voided() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let voided = register_content(&did, &device, CONTENT1);
+        let convicted = register_content(&did, &device, CONTENT2);
+        <Template as DisputeObserver<u64>>::on_dispute_opened(&voided, 2);
+        <Template as DisputeObserver<u64>>::on_dispute_opened(&convicted, 2);
+        <Template as DisputeObserver<u64>>::on_verdict(&convicted, ContextVerdict::Convicted, 7);
+
+        <Template as DisputeObserver<u64>>::on_dispute_voided(&voided, 9);
+        System::assert_last_event(Event::ContentDisputeVoided { content_id: voided.clone() }.into());
+        assert_eq!(Template::context_dispute(&voided), None);
+        assert!(Template::proof_status(&voided).is_trusted());
+
+        // a verdict reached before stands
+        <Template as DisputeObserver<u64>>::on_dispute_voided(&convicted, 9);
+        assert!(Template::proof_status(&convicted).disputed);
+    });
+}
+
+// ============ Label Tests ============
+
+#[test]
+fn should_label_convicted_content() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+        <Template as DisputeObserver<u64>>::on_dispute_opened(&content_id, 2);
+        assert!(Template::labels(&content_id).is_empty());
+
+        <Template as DisputeObserver<u64>>::on_verdict(&content_id, ContextVerdict::Convicted, 9);
+
+        assert_eq!(
+            Template::labels(&content_id),
+            vec![(LabelKind::MisleadingContext, ContentLabel { disputed_at: 2, decided_at: 9 })]
+        );
+        System::assert_last_event(
+            Event::LabelAttached {
+                content_id,
+                owner: did,
+                kind: LabelKind::MisleadingContext,
+                disputed_at: 2,
+                decided_at: 9,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn should_not_label_acquitted_content() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+        <Template as DisputeObserver<u64>>::on_dispute_opened(&content_id, 2);
+
+        <Template as DisputeObserver<u64>>::on_verdict(&content_id, ContextVerdict::Acquitted, 9);
+
+        assert!(Template::labels(&content_id).is_empty());
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Template(Event::LabelAttached { .. } | Event::LabelRemoved { .. })
+        )));
+    });
+}
+
+#[test]
+fn should_only_remove_label_on_appeal() {
+    new_test_ext().execute_with(|| {
+        let (did, device) = setup_did_and_device(DID, DEVICE1);
+        let content_id = register_content(&did, &device, CONTENT1);
+        <Template as DisputeObserver<u64>>::on_dispute_opened(&content_id, 2);
+        <Template as DisputeObserver<u64>>::on_verdict(&content_id, ContextVerdict::Convicted, 9);
+
+        // retracting does not take the label off
+        assert_ok!(Template::retract_content(RuntimeOrigin::signed(ALICE), content_id.clone()));
+        assert_eq!(Template::labels(&content_id).len(), 1);
+
+        // an appeal acquits
+        <Template as DisputeObserver<u64>>::on_verdict(&content_id, ContextVerdict::Acquitted, 20);
+
+        assert!(Template::labels(&content_id).is_empty());
+        System::assert_last_event(
+            Event::LabelRemoved {
+                content_id,
+                owner: did,
+                kind: LabelKind::MisleadingContext,
+                decided_at: 20,
+            }
+            .into(),
+        );
+    });
+}
+
 // ============ Helper Functions ============
 
+/// Commits `ext` and proves `Proofs[content_id]` from its backend, with a header at block 1
+/// over the resulting state root.
+fn prove_proof(ext: &mut TestState, content_id: &ContentId) -> (MockHeader, Vec<Vec<u8>>) {
+    use frame::deps::sp_runtime::traits::Header as _;
+
+    let key = ext.execute_with(|| Template::proof_storage_key(content_id));
+    ext.commit_all().unwrap();
+    let backend = ext.as_backend();
+    let header = MockHeader::new(
+        1,
+        Default::default(),
+        *backend.root(),
+        Default::default(),
+        Default::default(),
+    );
+    let read_proof = polkadot_sdk::sp_state_machine::prove_read(backend, [key]).unwrap();
+    (header, read_proof.into_iter_nodes().collect())
+}
+
 fn generate_content_id(content: &[u8; 32]) -> ContentId {
-    use polkadot_sdk::sp_core::hashing::blake2_256;
-    let prefix = b"cid:";
-    let hash = blake2_256(&content.encode());
-    ContentId::new(prefix, &hash)
+    ContentId::new(HashAlgorithm::Blake2b256, content)
+}
+
+/// JPEG metadata claiming the media was captured at the current chain time.
+fn capture_metadata() -> CaptureMetadata {
+    CaptureMetadata::V1(CaptureMetadataV1 {
+        mime_type: BoundedVec::try_from(b"image/jpeg".to_vec()).unwrap(),
+        captured_at: Timestamp::get(),
+        location: Some(GeoLocation { latitude: 5_152, longitude: -13 }),
+        camera: Some(CameraInfo {
+            make: BoundedVec::try_from(b"Era".to_vec()).unwrap(),
+            model: BoundedVec::try_from(b"Cam 1".to_vec()).unwrap(),
+            sensor: None,
+        }),
+        software_version: Some(BoundedVec::try_from(b"1.0.0".to_vec()).unwrap()),
+    })
+}
+
+fn proof_deposit_held(who: u64) -> u128 {
+    <Balances as fungible::InspectHold<u64>>::balance_on_hold(&HoldReason::ProofDeposit.into(), &who)
+}
+
+fn commitment_deposit_held(who: u64) -> u128 {
+    <Balances as fungible::InspectHold<u64>>::balance_on_hold(
+        &HoldReason::CommitmentDeposit.into(),
+        &who,
+    )
+}
+
+fn spdx(id: &str) -> License {
+    License::Spdx(BoundedVec::try_from(id.as_bytes().to_vec()).unwrap())
+}
+
+/// Registers CONTENT1 for DID and offers a CC-BY-4.0 license for it, paid to `PAYOUT`.
+fn setup_license_offer(price: u128) -> ContentId {
+    let (did, device) = setup_did_and_device(DID, DEVICE1);
+    let content_id = register_content(&did, &device, CONTENT1);
+    assert_ok!(Template::set_payout_account(RuntimeOrigin::signed(ALICE), did, Some(PAYOUT)));
+    assert_ok!(Template::set_license(
+        RuntimeOrigin::signed(ALICE),
+        content_id.clone(),
+        License::AllRightsReserved,
+        Some(LicenseOffer { license: spdx("CC-BY-4.0"), price })
+    ));
+    content_id
+}
+
+/// Creates `did`, controlled by `who`, with the Attest right.
+fn setup_attester(who: u64, did: [u8; 5]) -> BoundedVec<u8, ConstU32<1024>> {
+    let did: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(did.to_vec()).unwrap();
+    assert_ok!(IdentityRegistry::create_did(
+        RuntimeOrigin::signed(who),
+        did.clone(),
+        BoundedVec::try_from(vec![who]).unwrap()
+    ));
+    assert_ok!(IdentityRegistry::add_right_for_signatory(
+        RuntimeOrigin::signed(who),
+        did.clone(),
+        who,
+        BaseRight::Attest,
+        pallet_identity_registry::RightDuration::Permanent
+    ));
+    did
+}
+
+/// Commits to `content` from ALICE with [`SALT`].
+fn commit(
+    did: &BoundedVec<u8, ConstU32<1024>>,
+    device: &BoundedVec<u8, ConstU32<1024>>,
+    content: &[u8; 32],
+    expires_in: Option<u64>,
+) -> [u8; 32] {
+    let commitment = Template::commitment_hash(did, device, &generate_content_id(content), &SALT);
+    assert_ok!(Template::commit_content(
+        RuntimeOrigin::signed(ALICE),
+        did.clone(),
+        device.clone(),
+        commitment,
+        expires_in
+    ));
+    commitment
+}
+
+fn reveal(
+    commitment: [u8; 32],
+    salt: [u8; 32],
+    content: [u8; 32],
+    metadata: CaptureMetadata,
+) -> DispatchResult {
+    Template::reveal_content(
+        RuntimeOrigin::signed(ALICE),
+        commitment,
+        salt,
+        content,
+        HashAlgorithm::Blake2b256,
+        BoundedVec::try_from(b"image".to_vec()).unwrap(),
+        BoundedVec::try_from(b"leaked memo".to_vec()).unwrap(),
+        metadata,
+        None,
+    )
+}
+
+fn create_with_metadata(
+    did: &BoundedVec<u8, ConstU32<1024>>,
+    device: &BoundedVec<u8, ConstU32<1024>>,
+    metadata: CaptureMetadata,
+) -> DispatchResult {
+    register_with_metadata(did, device, CONTENT1, metadata)
+}
+
+fn register_with_metadata(
+    did: &BoundedVec<u8, ConstU32<1024>>,
+    device: &BoundedVec<u8, ConstU32<1024>>,
+    content: [u8; 32],
+    metadata: CaptureMetadata,
+) -> DispatchResult {
+    Template::create_content(
+        RuntimeOrigin::signed(ALICE),
+        did.clone(),
+        content,
+        HashAlgorithm::Blake2b256,
+        BoundedVec::try_from(b"image".to_vec()).unwrap(),
+        BoundedVec::try_from(b"profile picture".to_vec()).unwrap(),
+        metadata,
+        device.clone(),
+        None,
+    )
+}
+
+fn capture_metadata_with(f: impl FnOnce(&mut CaptureMetadataV1)) -> CaptureMetadata {
+    let CaptureMetadata::V1(mut metadata) = capture_metadata() else {
+        unreachable!("capture_metadata returns V1");
+    };
+    f(&mut metadata);
+    CaptureMetadata::V1(metadata)
+}
+
+fn setup_did_and_device(
+    did: [u8; 5],
+    device: [u8; 5],
+) -> (BoundedVec<u8, ConstU32<1024>>, BoundedVec<u8, ConstU32<1024>>) {
+    let did = BoundedVec::try_from(did.to_vec()).unwrap();
+    let device = BoundedVec::try_from(device.to_vec()).unwrap();
+    setup_did_with_rights(ALICE, did.clone(), vec![BOB]);
+    register_device_for_did(ALICE, did.clone(), device.clone());
+    (did, device)
+}
+
+fn register_content(
+    did: &BoundedVec<u8, ConstU32<1024>>,
+    device: &BoundedVec<u8, ConstU32<1024>>,
+    content: [u8; 32],
+) -> ContentId {
+    assert_ok!(Template::create_content(
+        RuntimeOrigin::signed(ALICE),
+        did.clone(),
+        content,
+        HashAlgorithm::Blake2b256,
+        BoundedVec::try_from(b"image".to_vec()).unwrap(),
+        BoundedVec::try_from(b"profile picture".to_vec()).unwrap(),
+        capture_metadata(),
+        device.clone(),
+        None
+    ));
+    generate_content_id(&content)
+}
+
+fn register_with_ipfs_cid(content: &[u8; 32], algorithm: HashAlgorithm, cid: &str) -> ContentId {
+    let did: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DID.to_vec()).unwrap();
+    let device: BoundedVec<u8, ConstU32<1024>> = BoundedVec::try_from(DEVICE1.to_vec()).unwrap();
+    if IdentityRegistry::get_did_devices(&did).is_none() {
+        setup_did_with_rights(ALICE, did.clone(), vec![BOB]);
+        register_device_for_did(ALICE, did.clone(), device.clone());
+    }
+
+    assert_ok!(Template::create_content(
+        RuntimeOrigin::signed(ALICE),
+        did,
+        *content,
+        algorithm,
+        BoundedVec::try_from(b"image".to_vec()).unwrap(),
+        BoundedVec::try_from(b"profile picture".to_vec()).unwrap(),
+        capture_metadata(),
+        device,
+        Some(BoundedVec::try_from(cid.as_bytes().to_vec()).unwrap())
+    ));
+    ContentId::new(algorithm, content)
+}
+
+/// Test externalities with mocked offchain HTTP and transaction pool, and `REPORTER` as the
+/// only local key and registered reporter.
+fn offchain_test_ext() -> (TestState, TestOffchainExt, Arc<RwLock<PoolState>>) {
+    let (offchain, _) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    ext.execute_with(|| {
+        UintAuthorityId::set_all_keys(vec![REPORTER]);
+        assert_ok!(Template::set_availability_reporters(
+            RuntimeOrigin::root(),
+            BoundedVec::try_from(vec![REPORTER]).unwrap()
+        ));
+    });
+    (ext, offchain, pool_state)
+}
+
+fn expect_ipfs_get(offchain: &TestOffchainExt, uri: &str, body: &[u8]) {
+    offchain.0.write().expect_request(PendingRequest {
+        method: "GET".into(),
+        uri: uri.into(),
+        response: Some(body.to_vec()),
+        sent: true,
+        ..Default::default()
+    });
+}
+
+fn submitted_report(
+    pool: &RwLock<PoolState>,
+) -> (AvailabilityReport<UintAuthorityId, u64>, TestSignature) {
+    let tx = pool.write().transactions.pop().expect("a report should have been submitted");
+    let tx = Extrinsic::decode(&mut &*tx).unwrap();
+    match tx.function {
+        RuntimeCall::Template(crate::Call::report_availability { report, signature }) => (report, signature),
+        call => panic!("unexpected call: {:?}", call),
+    }
+}
+
+/// Offchain HTTP whose gateway never answers before the deadline.
+struct UnreachableGateway;
+
+impl offchain::Externalities for UnreachableGateway {
+    fn is_validator(&self) -> bool {
+        true
+    }
+
+    fn network_state(&self) -> Result<offchain::OpaqueNetworkState, ()> {
+        Err(())
+    }
+
+    fn timestamp(&mut self) -> offchain::Timestamp {
+        offchain::Timestamp::from_unix_millis(0)
+    }
+
+    fn sleep_until(&mut self, _deadline: offchain::Timestamp) {}
+
+    fn random_seed(&mut self) -> [u8; 32] {
+        [0; 32]
+    }
+
+    fn http_request_start(
+        &mut self,
+        _method: &str,
+        _uri: &str,
+        _meta: &[u8],
+    ) -> Result<offchain::HttpRequestId, ()> {
+        Ok(offchain::HttpRequestId(0))
+    }
+
+    fn http_request_add_header(
+        &mut self,
+        _request_id: offchain::HttpRequestId,
+        _name: &str,
+        _value: &str,
+    ) -> Result<(), ()> {
+        Ok(())
+    }
+
+    fn http_request_write_body(
+        &mut self,
+        _request_id: offchain::HttpRequestId,
+        _chunk: &[u8],
+        _deadline: Option<offchain::Timestamp>,
+    ) -> Result<(), offchain::HttpError> {
+        Ok(())
+    }
+
+    fn http_response_wait(
+        &mut self,
+        ids: &[offchain::HttpRequestId],
+        _deadline: Option<offchain::Timestamp>,
+    ) -> Vec<offchain::HttpRequestStatus> {
+        vec![offchain::HttpRequestStatus::DeadlineReached; ids.len()]
+    }
+
+    fn http_response_headers(&mut self, _request_id: offchain::HttpRequestId) -> Vec<(Vec<u8>, Vec<u8>)> {
+        Vec::new()
+    }
+
+    fn http_response_read_body(
+        &mut self,
+        _request_id: offchain::HttpRequestId,
+        _buffer: &mut [u8],
+        _deadline: Option<offchain::Timestamp>,
+    ) -> Result<usize, offchain::HttpError> {
+        Err(offchain::HttpError::DeadlineReached)
+    }
+
+    fn set_authorized_nodes(&mut self, _nodes: Vec<frame::deps::sp_core::OpaquePeerId>, _authorized_only: bool) {}
 }
@@ -1,29 +1,9 @@
-
-//! Autogenerated weights for pallet_template
+//! Weights for pallet_content_registry.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `_`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ../../target/release/node-template
-// benchmark
-// pallet
-// --chain
-// dev
-// --pallet
-// pallet_template
-// --extrinsic
-// *
-// --steps=50
-// --repeat=20
-// --wasm-execution=compiled
-// --output
-// pallets/template/src/weights.rs
-// --template
-// ../../.maintain/frame-weight-template.hbs
+//! HAND-ESTIMATED PLACEHOLDERS, NOT BENCHMARK OUTPUT. The storage accesses listed on each
+//! function are counted from the code; the execution times and proof sizes are estimates.
+//! Replace this file with the output of `benchmark pallet` for `pallet_content_registry` before
+//! relying on these weights in production.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -32,65 +12,139 @@
 use frame::{deps::frame_support::weights::constants::RocksDbWeight, prelude::*};
 use core::marker::PhantomData;
 
-/// Weight functions needed for pallet_template.
+/// Weight functions needed for pallet_content_registry.
 pub trait WeightInfo {
-	fn do_something() -> Weight;
-	fn cause_error() -> Weight;
+	fn create_content() -> Weight;
+	fn migration_v1_step() -> Weight;
 }
 
-/// Weights for pallet_template using the Substrate node and recommended hardware.
+/// Weights for pallet_content_registry using the Substrate node and recommended hardware.
 #[cfg_attr(
     not(feature = "std"),
     deprecated(
-        note = "SubstrateWeight is auto-generated and should not be used in production. Replace it with runtime benchmarked weights."
+        note = "SubstrateWeight is hand-estimated and should not be used in production. Replace it with runtime benchmarked weights."
     )
 )]
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: Template Something (r:0 w:1)
-	/// Proof: Template Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+	/// Storage: `IdentityRegistry::Signatories` (r:1 w:0)
+	/// Proof: `IdentityRegistry::Signatories` (`max_values`: None, `max_size`: Some(3286), added: 5761, mode: `MaxEncodedLen`)
+	/// Storage: `IdentityRegistry::Devices` (r:1 w:0)
+	/// Proof: `IdentityRegistry::Devices` (`max_values`: None, `max_size`: Some(10352), added: 12827, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::Proofs` (r:1 w:1)
+	/// Proof: `ContentRegistry::Proofs` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DidQuotas` (r:1 w:0)
+	/// Proof: `ContentRegistry::DidQuotas` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::QuotaUsages` (r:1 w:1)
+	/// Proof: `ContentRegistry::QuotaUsages` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DidContentCount` (r:1 w:1)
+	/// Proof: `ContentRegistry::DidContentCount` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DeviceContentCount` (r:1 w:1)
+	/// Proof: `ContentRegistry::DeviceContentCount` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::ContentCount` (r:1 w:1)
+	/// Proof: `ContentRegistry::ContentCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::ProofDeposits` (r:0 w:1)
+	/// Proof: `ContentRegistry::ProofDeposits` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DidContentIndex` (r:0 w:1)
+	/// Proof: `ContentRegistry::DidContentIndex` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DidContentExists` (r:0 w:1)
+	/// Proof: `ContentRegistry::DidContentExists` (`max_values`: None, `max_size`: Some(158), added: 2633, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DeviceContents` (r:0 w:1)
+	/// Proof: `ContentRegistry::DeviceContents` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::BlockContents` (r:0 w:1)
+	/// Proof: `ContentRegistry::BlockContents` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn create_content() -> Weight {
+		Weight::from_parts(74_000_000, 13817)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
 	}
-	/// Storage: Template Something (r:1 w:1)
-	/// Proof: Template Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+	/// Storage: `ContentRegistry::DidContents` (r:1 w:1)
+	/// Proof: `ContentRegistry::DidContents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ContentRegistry::Proofs` (r:1 w:2)
+	/// Proof: `ContentRegistry::Proofs` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DidContentExists` (r:0 w:2)
+	/// Proof: `ContentRegistry::DidContentExists` (`max_values`: None, `max_size`: Some(158), added: 2633, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DidContentIndex` (r:0 w:1)
+	/// Proof: `ContentRegistry::DidContentIndex` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DeviceContents` (r:0 w:1)
+	/// Proof: `ContentRegistry::DeviceContents` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DeviceContentCount` (r:1 w:1)
+	/// Proof: `ContentRegistry::DeviceContentCount` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::BlockContents` (r:0 w:1)
+	/// Proof: `ContentRegistry::BlockContents` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::ContentCount` (r:1 w:1)
+	/// Proof: `ContentRegistry::ContentCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		Weight::from_parts(30_000_000, 3297)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	/// Storage: Template Something (r:0 w:1)
-	/// Proof: Template Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	/// Storage: `IdentityRegistry::Signatories` (r:1 w:0)
+	/// Proof: `IdentityRegistry::Signatories` (`max_values`: None, `max_size`: Some(3286), added: 5761, mode: `MaxEncodedLen`)
+	/// Storage: `IdentityRegistry::Devices` (r:1 w:0)
+	/// Proof: `IdentityRegistry::Devices` (`max_values`: None, `max_size`: Some(10352), added: 12827, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::Proofs` (r:1 w:1)
+	/// Proof: `ContentRegistry::Proofs` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DidQuotas` (r:1 w:0)
+	/// Proof: `ContentRegistry::DidQuotas` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::QuotaUsages` (r:1 w:1)
+	/// Proof: `ContentRegistry::QuotaUsages` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DidContentCount` (r:1 w:1)
+	/// Proof: `ContentRegistry::DidContentCount` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DeviceContentCount` (r:1 w:1)
+	/// Proof: `ContentRegistry::DeviceContentCount` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::ContentCount` (r:1 w:1)
+	/// Proof: `ContentRegistry::ContentCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::ProofDeposits` (r:0 w:1)
+	/// Proof: `ContentRegistry::ProofDeposits` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DidContentIndex` (r:0 w:1)
+	/// Proof: `ContentRegistry::DidContentIndex` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DidContentExists` (r:0 w:1)
+	/// Proof: `ContentRegistry::DidContentExists` (`max_values`: None, `max_size`: Some(158), added: 2633, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DeviceContents` (r:0 w:1)
+	/// Proof: `ContentRegistry::DeviceContents` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::BlockContents` (r:0 w:1)
+	/// Proof: `ContentRegistry::BlockContents` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn create_content() -> Weight {
+		Weight::from_parts(74_000_000, 13817)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
 	}
-	/// Storage: Template Something (r:1 w:1)
-	/// Proof: Template Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	/// Storage: `ContentRegistry::DidContents` (r:1 w:1)
+	/// Proof: `ContentRegistry::DidContents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ContentRegistry::Proofs` (r:1 w:2)
+	/// Proof: `ContentRegistry::Proofs` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DidContentExists` (r:0 w:2)
+	/// Proof: `ContentRegistry::DidContentExists` (`max_values`: None, `max_size`: Some(158), added: 2633, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DidContentIndex` (r:0 w:1)
+	/// Proof: `ContentRegistry::DidContentIndex` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DeviceContents` (r:0 w:1)
+	/// Proof: `ContentRegistry::DeviceContents` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::DeviceContentCount` (r:1 w:1)
+	/// Proof: `ContentRegistry::DeviceContentCount` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::BlockContents` (r:0 w:1)
+	/// Proof: `ContentRegistry::BlockContents` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `ContentRegistry::ContentCount` (r:1 w:1)
+	/// Proof: `ContentRegistry::ContentCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		Weight::from_parts(30_000_000, 3297)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 }
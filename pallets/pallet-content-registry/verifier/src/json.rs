@@ -0,0 +1,136 @@
+//! Verifying `content_ReadProof` responses as they come over JSON-RPC, for the CLI and the
+//! WASM bindings.
+
+use crate::{verify_era_proof, Error, RegisteredProof};
+use polkadot_sdk::sp_runtime::AccountId32;
+use shared::types::ContentId;
+
+/// Result of `content_ReadProof`. Byte strings are `0x`-prefixed hex.
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct ReadProofResponse {
+    /// Hash of the block the proof was read at
+    pub at: String,
+    /// SCALE-encoded header of that block
+    pub header: String,
+    /// Trie nodes of the read proof
+    pub proof: Vec<String>,
+}
+
+/// Either the result alone, or the whole JSON-RPC response around it.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum Input {
+    Envelope { result: ReadProofResponse },
+    Result(ReadProofResponse),
+}
+
+/// A verified proof. Byte strings are `0x`-prefixed hex, as in `content_Proof`.
+#[derive(serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiedProofJson {
+    pub block_hash: String,
+    pub block_number: u32,
+    /// `null` if the content was not registered at the block
+    pub proof: Option<ProofJson>,
+}
+
+#[derive(serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofJson {
+    pub content_id: String,
+    pub exists_from: u32,
+    /// Milliseconds since the unix epoch
+    pub registered_at: u64,
+    pub relay_parent_number: u32,
+    pub did: String,
+    pub signer: String,
+    pub content_type: String,
+    pub content_description: String,
+    pub mime_type: String,
+    /// Milliseconds since the unix epoch
+    pub captured_at: u64,
+    pub device: String,
+    pub ipfs_cid: Option<String>,
+}
+
+impl From<RegisteredProof<u32, AccountId32>> for ProofJson {
+    fn from(proof: RegisteredProof<u32, AccountId32>) -> Self {
+        let text = |bytes: &[u8]| String::from_utf8_lossy(bytes).into_owned();
+        Self {
+            content_id: proof.content_id.to_cid_string(),
+            exists_from: proof.exists_from,
+            registered_at: proof.registered_at,
+            relay_parent_number: proof.relay_parent_number,
+            did: to_hex(&proof.did),
+            signer: to_hex(proof.signer.as_ref()),
+            content_type: text(&proof.content_type),
+            content_description: text(&proof.content_description),
+            mime_type: text(proof.content_metadata.mime_type()),
+            captured_at: proof.content_metadata.captured_at(),
+            device: to_hex(&proof.device),
+            ipfs_cid: proof.ipfs_cid.as_deref().map(text),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ResponseError {
+    /// The content id is not a CID the registry uses
+    InvalidContentId,
+    /// The response is not a `content_ReadProof` result
+    InvalidResponse(String),
+    /// A byte string is not `0x`-prefixed hex
+    InvalidHex,
+    /// The header does not hash to the block the node claims the proof is from
+    BlockHashMismatch,
+    Verification(Error),
+}
+
+impl core::fmt::Display for ResponseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ResponseError::InvalidContentId => f.write_str("invalid content id"),
+            ResponseError::InvalidResponse(reason) => write!(f, "invalid response: {}", reason),
+            ResponseError::InvalidHex => f.write_str("invalid hex string in response"),
+            ResponseError::BlockHashMismatch => f.write_str("header does not hash to `at`"),
+            ResponseError::Verification(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ResponseError {}
+
+/// Verifies a `content_ReadProof` response for the content with CID `content_id`.
+pub fn verify_response(content_id: &str, response: &str) -> Result<VerifiedProofJson, ResponseError> {
+    let content_id =
+        ContentId::from_cid_str(content_id).map_err(|_| ResponseError::InvalidContentId)?;
+    let response = match serde_json::from_str(response)
+        .map_err(|e| ResponseError::InvalidResponse(e.to_string()))?
+    {
+        Input::Envelope { result } | Input::Result(result) => result,
+    };
+
+    let header = from_hex(&response.header)?;
+    let read_proof = response.proof.iter().map(|node| from_hex(node)).collect::<Result<_, _>>()?;
+    let verified =
+        verify_era_proof(&content_id, &header, read_proof).map_err(ResponseError::Verification)?;
+    let block_hash = to_hex(verified.block_hash.as_ref());
+    if block_hash != response.at.to_lowercase() {
+        return Err(ResponseError::BlockHashMismatch);
+    }
+
+    Ok(VerifiedProofJson {
+        block_hash,
+        block_number: verified.block_number,
+        proof: verified.proof.map(Into::into),
+    })
+}
+
+fn from_hex(s: &str) -> Result<Vec<u8>, ResponseError> {
+    let s = s.strip_prefix("0x").ok_or(ResponseError::InvalidHex)?;
+    hex::decode(s).map_err(|_| ResponseError::InvalidHex)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
@@ -0,0 +1,128 @@
+//! Verifies a proof of the content registry without trusting the node that served it.
+//!
+//! `content_ReadProof` returns the SCALE-encoded header of a block and the trie nodes needed to
+//! read `Proofs[content_id]` from its state. [`verify`] checks those nodes against the state root
+//! of the header and decodes the proof. The caller still has to check that the block hash it
+//! returns is one it trusts, e.g. a finalized block it got from the relay chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "json")]
+pub mod json;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+use alloc::vec::Vec;
+use codec::{Decode, DecodeAll, Encode};
+use polkadot_sdk::{
+    sp_core::hashing::{blake2_128, twox_128},
+    sp_runtime::{
+        generic,
+        traits::{BlakeTwo256, Header as HeaderT},
+        AccountId32,
+    },
+    sp_trie::{read_trie_value, LayoutV1, StorageProof},
+};
+use shared::types::{CaptureMetadata, ContentId};
+
+/// Name the content registry has in our runtime, the first part of its storage keys.
+pub const PALLET_NAME: &str = "ContentRegistry";
+
+/// Header of our chain's blocks.
+pub type Header = generic::Header<u32, BlakeTwo256>;
+
+/// A `pallet_content_registry::Proof`, with the runtime's bounded types as plain vectors. The
+/// encoding is the same.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct RegisteredProof<BlockNumber, AccountId> {
+    pub content_id: ContentId,
+    pub exists_from: BlockNumber,
+    /// Milliseconds since the unix epoch
+    pub registered_at: u64,
+    pub relay_parent_number: u32,
+    pub did: Vec<u8>,
+    pub signer: AccountId,
+    pub content: [u8; 32],
+    pub content_type: Vec<u8>,
+    pub content_description: Vec<u8>,
+    pub content_metadata: CaptureMetadata,
+    pub device: Vec<u8>,
+    pub ipfs_cid: Option<Vec<u8>>,
+}
+
+/// What a read proof shows about a content id at a block.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct VerifiedProof<H: HeaderT, AccountId> {
+    /// Hash of the header the proof was checked against
+    pub block_hash: H::Hash,
+    pub block_number: H::Number,
+    /// `None` if the content was not registered at that block
+    pub proof: Option<RegisteredProof<H::Number, AccountId>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Error {
+    /// The header could not be decoded
+    InvalidHeader,
+    /// The trie nodes do not lead from the state root to the proof, or to its absence
+    InvalidReadProof,
+    /// The stored value is not a proof
+    InvalidProof,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Error::InvalidHeader => "header could not be decoded",
+            Error::InvalidReadProof => "read proof does not match the state root of the header",
+            Error::InvalidProof => "stored value is not a content proof",
+        })
+    }
+}
+
+/// Storage key of `Proofs[content_id]` in a pallet called `pallet_name`.
+pub fn proof_storage_key(pallet_name: &str, content_id: &ContentId) -> Vec<u8> {
+    let encoded_id = content_id.encode();
+    [
+        &twox_128(pallet_name.as_bytes())[..],
+        &twox_128(b"Proofs")[..],
+        &blake2_128(&encoded_id)[..],
+        &encoded_id[..],
+    ]
+    .concat()
+}
+
+/// Reads the proof of `content_id` from `read_proof`, checked against the state root of
+/// `encoded_header`. The storage key is derived here rather than taken from the node.
+pub fn verify<H: HeaderT, AccountId: Decode>(
+    pallet_name: &str,
+    content_id: &ContentId,
+    encoded_header: &[u8],
+    read_proof: Vec<Vec<u8>>,
+) -> Result<VerifiedProof<H, AccountId>, Error> {
+    let header = H::decode_all(&mut &encoded_header[..]).map_err(|_| Error::InvalidHeader)?;
+    let db = StorageProof::new(read_proof).into_memory_db::<H::Hashing>();
+    let key = proof_storage_key(pallet_name, content_id);
+    let value = read_trie_value::<LayoutV1<H::Hashing>, _>(&db, header.state_root(), &key, None, None)
+        .map_err(|_| Error::InvalidReadProof)?;
+    let proof = value
+        .map(|value| RegisteredProof::decode_all(&mut &value[..]))
+        .transpose()
+        .map_err(|_| Error::InvalidProof)?;
+    if proof.as_ref().is_some_and(|proof| &proof.content_id != content_id) {
+        return Err(Error::InvalidProof);
+    }
+
+    Ok(VerifiedProof { block_hash: header.hash(), block_number: *header.number(), proof })
+}
+
+/// [`verify`] for our runtime.
+pub fn verify_era_proof(
+    content_id: &ContentId,
+    encoded_header: &[u8],
+    read_proof: Vec<Vec<u8>>,
+) -> Result<VerifiedProof<Header, AccountId32>, Error> {
+    verify(PALLET_NAME, content_id, encoded_header, read_proof)
+}
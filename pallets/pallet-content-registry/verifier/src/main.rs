@@ -0,0 +1,41 @@
+//! Verifies a `content_ReadProof` response offline.
+//!
+//! ```sh
+//! content-proof-verifier <content-id> [response.json]
+//! ```
+//!
+//! Reads the response from stdin if no file is given, prints the verified proof as JSON and
+//! exits with 1 if the response does not verify. Compare the printed block hash with one you
+//! trust before relying on the result.
+
+use std::io::Read;
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let Some(content_id) = args.next() else {
+        eprintln!("usage: content-proof-verifier <content-id> [response.json]");
+        std::process::exit(2);
+    };
+
+    let response = match args.next() {
+        Some(path) => std::fs::read_to_string(&path),
+        None => {
+            let mut response = String::new();
+            std::io::stdin().read_to_string(&mut response).map(|_| response)
+        },
+    };
+    let response = response.unwrap_or_else(|e| {
+        eprintln!("could not read response: {}", e);
+        std::process::exit(2);
+    });
+
+    match content_proof_verifier::json::verify_response(&content_id, &response) {
+        Ok(verified) => {
+            println!("{}", serde_json::to_string_pretty(&verified).expect("serializable"));
+        },
+        Err(e) => {
+            eprintln!("verification failed: {}", e);
+            std::process::exit(1);
+        },
+    }
+}
@@ -0,0 +1,11 @@
+//! Bindings for verifier websites, built with `wasm-pack build -- --features wasm`.
+
+use wasm_bindgen::prelude::*;
+
+/// Verifies a `content_ReadProof` response for the content with CID `content_id`. Returns the
+/// verified proof as a JSON string, see [`crate::json::VerifiedProofJson`].
+#[wasm_bindgen(js_name = verifyReadProof)]
+pub fn verify_read_proof(content_id: &str, response: &str) -> Result<String, JsError> {
+    let verified = crate::json::verify_response(content_id, response)?;
+    serde_json::to_string(&verified).map_err(|e| JsError::new(&e.to_string()))
+}
@@ -2,16 +2,39 @@
 
 extern crate alloc;
 
-//use alloc::vec::Vec;
-use codec::Codec;
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+pub use shared::types::{Evidence, EvidenceParty};
+
+/// Evidence submitted to a dispute. The DID is handed out as the raw bytes of the runtime's type.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct EvidenceDetails<BlockNumber> {
+    pub submitter: Vec<u8>,
+    pub party: EvidenceParty,
+    pub evidence: Evidence<Vec<u8>>,
+    pub submitted_at: BlockNumber,
+}
 
 polkadot_sdk::sp_api::decl_runtime_apis! {
     /// This trait contains all the Api's that can be called into from the runtime
     /// into our pallet. To read or perform certain state actions in our blockchain
-    pub trait PalletContextCourtApi<ContentId>
+    ///
+    /// Methods added after the first release carry the API version they appeared in.
+    pub trait PalletContextCourtApi<ContentId, BlockNumber, Balance>
     where
         ContentId: Codec,
+        BlockNumber: Codec,
+        Balance: Codec,
     {
         fn has_dispute(id: ContentId) -> bool;
+
+        /// Evidence submitted to the dispute over `id`, oldest first.
+        #[api_version(2)]
+        fn evidence(id: ContentId) -> Vec<EvidenceDetails<BlockNumber>>;
+
+        /// Balance of the court pot juror rewards are paid from.
+        #[api_version(3)]
+        fn court_pot() -> Balance;
     }
-}
\ No newline at end of file
+}
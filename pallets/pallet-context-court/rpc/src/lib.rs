@@ -1,11 +1,15 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
-pub use context_runtime_api::PalletContextCourtApi as ContextCourtApi;
+pub use context_runtime_api::{
+    Evidence, EvidenceDetails, EvidenceParty, PalletContextCourtApi as ContextCourtApi,
+};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
 use polkadot_sdk::*;
-use sp_api::ProvideRuntimeApi;
+use shared::types::ContentId;
+use sp_api::{ApiExt, ProvideRuntimeApi, RuntimeApiInfo};
 use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
 use sp_runtime::traits::Block as BlockT;
 use std::sync::Arc;
 
@@ -15,11 +19,68 @@ pub struct Custom {
     sum: u32,
 }
 
+/// What a piece of evidence references, e.g. `{"kind": "ipfsCid", "value": "bafkrei..."}`.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase", tag = "kind", content = "value")]
+pub enum EvidenceRefJson {
+    /// Content registered in the content registry
+    ContentHash(ContentId),
+    IpfsCid(String),
+    /// Blake2-256 hash of a written statement
+    TextHash(Bytes),
+}
+
+/// Evidence submitted to a dispute, as jurors read it.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EvidenceJson<BlockNumber> {
+    pub submitter: Bytes,
+    /// `disputer`, `owner` or `witness`
+    pub party: String,
+    pub evidence: EvidenceRefJson,
+    pub submitted_at: BlockNumber,
+}
+
+impl<BlockNumber> From<EvidenceDetails<BlockNumber>> for EvidenceJson<BlockNumber> {
+    fn from(details: EvidenceDetails<BlockNumber>) -> Self {
+        let party = match details.party {
+            EvidenceParty::Disputer => "disputer",
+            EvidenceParty::Owner => "owner",
+            EvidenceParty::Witness => "witness",
+        };
+        let evidence = match details.evidence {
+            Evidence::ContentHash(content_id) => EvidenceRefJson::ContentHash(content_id),
+            Evidence::IpfsCid(cid) => {
+                EvidenceRefJson::IpfsCid(String::from_utf8_lossy(&cid).into_owned())
+            },
+            Evidence::TextHash(hash) => EvidenceRefJson::TextHash(hash.to_vec().into()),
+        };
+        Self {
+            submitter: details.submitter.into(),
+            party: party.into(),
+            evidence,
+            submitted_at: details.submitted_at,
+        }
+    }
+}
+
 #[rpc(client, server)]
-pub trait PalletContextCourtApi<BlockHash, ContentId: Codec> {
+pub trait PalletContextCourtApi<BlockHash, ContentId: Codec, BlockNumber, Balance> {
     /// get the number of accounts that have approved a particular call hash
     #[method(name = "context_HasDispute")]
     fn has_dispute(&self, id: ContentId, at: Option<BlockHash>) -> RpcResult<bool>;
+
+    /// Evidence submitted to the dispute over `id`, oldest first
+    #[method(name = "context_Evidence")]
+    fn evidence(
+        &self,
+        id: ContentId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<EvidenceJson<BlockNumber>>>;
+
+    /// Balance of the court pot juror rewards are paid from
+    #[method(name = "context_CourtPot")]
+    fn court_pot(&self, at: Option<BlockHash>) -> RpcResult<Balance>;
 }
 
 /// A struct that implements the `TemplateApi`.
@@ -40,12 +101,44 @@ impl<C, Block> PalletContextCourt<C, Block> {
     }
 }
 
-impl<C, Block, ContentId: Codec> PalletContextCourtApiServer<<Block as BlockT>::Hash, ContentId>
+impl<C, Block> PalletContextCourt<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+{
+    /// Fails with a readable error when the runtime at `at` predates `version` of `Api`,
+    /// instead of letting the call trap.
+    fn ensure_api_version<Api: RuntimeApiInfo + ?Sized>(
+        &self,
+        at: <Block as BlockT>::Hash,
+        version: u32,
+    ) -> RpcResult<()> {
+        let found = self
+            .client
+            .runtime_api()
+            .api_version::<Api>(at)
+            .map_err(runtime_error_into_rpc_err)?
+            .unwrap_or_default();
+        if found < version {
+            return Err(ErrorObject::owned(
+                API_VERSION_ERROR,
+                "Method not supported by the runtime at this block",
+                Some(format!("requires api version {}, found {}", version, found)),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<C, Block, ContentId: Codec, BlockNumber: Codec, Balance: Codec>
+    PalletContextCourtApiServer<<Block as BlockT>::Hash, ContentId, BlockNumber, Balance>
     for PalletContextCourt<C, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: ContextCourtApi<Block, ContentId>,
+    C::Api: ContextCourtApi<Block, ContentId, BlockNumber, Balance>,
+    BlockNumber: Send + Sync + 'static + serde::Serialize,
+    Balance: Send + Sync + 'static + serde::Serialize,
 {
     fn has_dispute(
         &self,
@@ -59,9 +152,38 @@ where
         api.has_dispute(block_hash, id)
             .map_err(runtime_error_into_rpc_err)
     }
+
+    fn evidence(
+        &self,
+        id: ContentId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<EvidenceJson<BlockNumber>>> {
+        let block_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<dyn ContextCourtApi<Block, ContentId, BlockNumber, Balance>>(
+            block_hash, 2,
+        )?;
+        let evidence = self
+            .client
+            .runtime_api()
+            .evidence(block_hash, id)
+            .map_err(runtime_error_into_rpc_err)?;
+        Ok(evidence.into_iter().map(Into::into).collect())
+    }
+
+    fn court_pot(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Balance> {
+        let block_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<dyn ContextCourtApi<Block, ContentId, BlockNumber, Balance>>(
+            block_hash, 3,
+        )?;
+        self.client
+            .runtime_api()
+            .court_pot(block_hash)
+            .map_err(runtime_error_into_rpc_err)
+    }
 }
 
 const RUNTIME_ERROR: i32 = 1;
+const API_VERSION_ERROR: i32 = 2;
 
 /// Converts a runtime trap into an RPC error.
 fn runtime_error_into_rpc_err<'a>(err: impl std::fmt::Debug) -> ErrorObject<'a> {
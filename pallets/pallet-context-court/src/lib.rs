@@ -8,6 +8,7 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migrations;
 pub mod weights;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -19,13 +20,22 @@ pub mod pallet {
         fungible::{Mutate, MutateHold},
         *,
     };
-    use polkadot_sdk::frame_support::traits::Randomness;
-    use polkadot_sdk::pallet_insecure_randomness_collective_flip as insecure_randomness;
+    use polkadot_sdk::frame_support::{
+        sp_runtime::traits::AccountIdConversion,
+        storage::with_storage_layer,
+        traits::{tokens::Restriction, Randomness},
+        weights::WeightMeter,
+        PalletId,
+    };
     use polkadot_sdk::sp_std::vec::Vec;
     use scale_info::prelude::collections::BTreeSet;
     use shared::{
-        traits::identity::DidManager,
-        types::{BaseRight, ContentId},
+        traits::{
+            content::ContentInspector,
+            context::{DisputeObserver, JurorReputation},
+            identity::DidManager,
+        },
+        types::{BaseRight, ContentId, ContextVerdict, Evidence, EvidenceParty},
     };
 
     /// Define the type for balance used in the pallet.
@@ -35,7 +45,7 @@ pub mod pallet {
 
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
-    pub trait Config: frame_system::Config + insecure_randomness::Config {
+    pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// A type representing the weights required by the dispatchables of this pallet.
@@ -66,30 +76,110 @@ pub mod pallet {
 
         type DidRegistry: DidManager<Self::AccountId, Self::Did, Self::Device, Self::GivenRight>;
 
+        /// The content registry, disputes can only be opened over registered content.
+        type ContentInspector: ContentInspector<Self::Did, Self::Device, BlockNumberFor<Self>>;
+
+        /// Told when disputes are opened and decided.
+        type DisputeObserver: DisputeObserver<BlockNumberFor<Self>>;
+
+        /// Picks the jurors of a dispute. Collators must not be able to predict or bias it, so
+        /// use e.g. relay-chain VRF randomness rather than anything derived from block hashes.
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+        /// Scales the bonded stake jurors are drawn by, `()` draws by stake alone.
+        type JurorReputation: JurorReputation<Self::Did>;
+
         type MaxJurors: Get<u32>;
 
         type MaxJurorsPerDispute: Get<u32>;
 
+        /// Smallest jury size a dispute category can have.
         type MinJurorsPerDispute: Get<u32>;
 
+        /// Parameters of the dispute categories `CategoryOrigin` has not set.
+        type DefaultCategoryParams: Get<CategoryParams<BlockNumberFor<Self>>>;
+
+        /// Origin that sets the parameters of dispute categories, e.g. governance
+        type CategoryOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         type MaxContextLength: Get<u32>;
 
+        /// Blocks after a dispute is opened in which its parties and the witnesses they invite
+        /// submit evidence. Deliberation starts after it.
+        type EvidencePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Evidence entries each DID can submit to a dispute.
+        type MaxEvidencePerParty: Get<u32>;
+
+        /// Witnesses the parties of a dispute can invite, together.
+        type MaxWitnesses: Get<u32>;
+
+        type MaxIpfsCidLength: Get<u32>;
+
+        /// Stake a juror bonds at least. Jurors slashed below it leave the pool until they bond
+        /// more.
         type HoldAmount: Get<BalanceOf<Self>>;
 
+        /// Blocks after `unregister_juror` before the juror can withdraw its stake.
+        type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+
         type SlashAmount: Get<BalanceOf<Self>>;
 
+        /// Paid to jurors who voted, out of the court pot.
         type RewardAmount: Get<BalanceOf<Self>>;
 
+        /// Paid into the court pot to exclude a juror from duty.
         type ExclusionFee: Get<BalanceOf<Self>>;
 
+        /// Derives the account of the court pot. Slashes, exclusion fees and bonds nobody else
+        /// is paid from go into it, and juror rewards are paid out of it.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Held from whoever opens a dispute, and from the content owner if it posts a
+        /// counter-bond. The party that loses the verdict pays from it.
+        type DisputeBondAmount: Get<BalanceOf<Self>>;
+
+        /// Part of the losing bond shared among the jurors who voted on the verdict, the rest
+        /// compensates the winner.
+        type JurorBondShare: Get<Perbill>;
+
+        /// Held from a party that appeals a decision. It is lost to the jurors of the appeal
+        /// round if they uphold the decision.
+        type AppealBondAmount: Get<BalanceOf<Self>>;
+
+        /// Blocks after a decision in which the parties can appeal it.
+        type AppealPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Appeals a dispute can go through. Each appeal round draws a fresh jury of `2n + 1`
+        /// for the `n` jurors of the round before, up to `MaxJurorsPerDispute`.
+        type MaxAppealRounds: Get<u32>;
+
         type EscalatedVotingPeriod: Get<BlockNumberFor<Self>>;
 
+        /// Blocks before a session expires in which jurors reveal the votes they committed to.
+        type RevealPeriod: Get<BlockNumberFor<Self>>;
+
         type MaxRewardsNumber: Get<u32>;
 
         type BatchRewardSize: Get<u32>;
+
+        /// Court actions that can fall due in one block, see `Deadlines`.
+        type MaxDeadlinesPerBlock: Get<u32>;
+
+        /// Court actions `on_idle` takes in one block at most, however much weight is left.
+        type MaxCourtActionsPerBlock: Get<u32>;
+
+        /// Attempts to start deliberation without a full jury before the dispute is voided.
+        /// The attempts after the first back off exponentially, starting in the next block.
+        type MaxSummonAttempts: Get<u32>;
     }
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Enum representing reasons for holding funds.
@@ -127,6 +217,19 @@ pub mod pallet {
     pub type JurorNativeAccountAdmin<T: Config> =
         StorageMap<_, Blake2_128Concat, T::Did, T::AccountId, OptionQuery>;
 
+    /// Stake bonded by each juror, jurors are drawn with a probability proportional to it.
+    /// Jurors registered before stakes were tracked bonded `HoldAmount`.
+    #[pallet::storage]
+    #[pallet::getter(fn juror_stake)]
+    pub type JurorStakes<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Did, BalanceOf<T>, OptionQuery>;
+
+    /// Jurors leaving the pool, by the block from which they can withdraw their stake.
+    #[pallet::storage]
+    #[pallet::getter(fn unbonding_until)]
+    pub type Unbonding<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Did, BlockNumberFor<T>, OptionQuery>;
+
     #[pallet::storage]
     pub type JuryDuty<T: Config> = StorageDoubleMap<
         _,
@@ -138,6 +241,12 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Sessions a juror was summoned to that have not ended yet, so `withdraw_juror_stake`
+    /// need not go through all the juries it ever served on.
+    #[pallet::storage]
+    #[pallet::getter(fn open_duties)]
+    pub type OpenDuties<T: Config> = StorageMap<_, Blake2_128Concat, T::Did, u32, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn get_amount_of_jury_summoned_for_dispute)]
     pub type JurySummoned<T: Config> = StorageMap<_, Blake2_128Concat, ContentId, u32, OptionQuery>;
@@ -157,11 +266,115 @@ pub mod pallet {
     pub type Dispute<T: Config> =
         StorageMap<_, Blake2_128Concat, ContentId, CourtSession<T>, OptionQuery>;
 
+    /// Commitments of jurors to the vote they reveal later, see `Pallet::vote_commitment`.
+    #[pallet::storage]
+    #[pallet::getter(fn vote_commitment_of)]
+    pub type VoteCommitments<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, ContentId, Blake2_128Concat, T::Did, T::Hash, OptionQuery>;
+
+    /// Sessions entering a new phase in a block, announced in `on_initialize`.
+    #[pallet::storage]
+    pub type PhaseChanges<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(ContentId, SessionPhase), T::MaxJurors>,
+        ValueQuery,
+    >;
+
+    /// Court actions falling due in a block. `on_idle` takes them from that block on, as far as
+    /// the weight left in blocks allows, so disputes progress without anyone calling in.
+    #[pallet::storage]
+    #[pallet::getter(fn deadlines)]
+    pub type Deadlines<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(ContentId, CourtAction), T::MaxDeadlinesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Earliest block `Deadlines` may still have due actions in.
+    #[pallet::storage]
+    pub type DeadlineCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// Attempts `on_idle` made to start deliberation of a dispute without a full jury.
+    #[pallet::storage]
+    #[pallet::getter(fn summon_attempts)]
+    pub type SummonAttempts<T: Config> = StorageMap<_, Blake2_128Concat, ContentId, u32, ValueQuery>;
+
+    /// DIDs invited by the parties of a dispute to submit evidence.
+    #[pallet::storage]
+    #[pallet::getter(fn witnesses)]
+    pub type Witnesses<T: Config> =
+        StorageMap<_, Blake2_128Concat, ContentId, BoundedVec<T::Did, T::MaxWitnesses>, ValueQuery>;
+
+    /// Evidence submitted to a dispute, by the DID that submitted it.
+    #[pallet::storage]
+    #[pallet::getter(fn submitted_evidence)]
+    pub type SubmittedEvidence<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ContentId,
+        Blake2_128Concat,
+        T::Did,
+        BoundedVec<EvidenceEntry<T>, T::MaxEvidencePerParty>,
+        ValueQuery,
+    >;
+
+    /// Parameters of a dispute category set by `CategoryOrigin`, in place of
+    /// `DefaultCategoryParams`.
+    #[pallet::storage]
+    pub type CategoryParameters<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        DisputeCategory,
+        CategoryParams<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Bonds held for a dispute until its decision can no longer be appealed.
+    #[pallet::storage]
+    #[pallet::getter(fn dispute_bond)]
+    pub type DisputeBonds<T: Config> =
+        StorageMap<_, Blake2_128Concat, ContentId, DisputeBond<T>, OptionQuery>;
+
+    /// Jurors who voted on the latest decision of a dispute, they share the losing dispute bond
+    /// once the decision is final.
+    #[pallet::storage]
+    pub type DecisionVoters<T: Config> =
+        StorageMap<_, Blake2_128Concat, ContentId, BoundedVec<T::Did, T::MaxJurors>, ValueQuery>;
+
+    /// Bonds held for an appeal round that has not been decided yet.
+    #[pallet::storage]
+    #[pallet::getter(fn appeal_bond)]
+    pub type AppealBonds<T: Config> =
+        StorageMap<_, Blake2_128Concat, ContentId, AppealBond<T>, OptionQuery>;
+
+    /// Decision of the latest round of a dispute, it overrides the decisions appealed before.
     #[pallet::storage]
     #[pallet::getter(fn get_decision)]
     pub type Decisions<T: Config> =
         StorageMap<_, Blake2_128Concat, ContentId, Decision, OptionQuery>;
 
+    /// Decision of every round of a dispute, the first round is round 0.
+    #[pallet::storage]
+    #[pallet::getter(fn round_decision)]
+    pub type RoundDecisions<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, ContentId, Twox64Concat, u32, Decision, OptionQuery>;
+
+    /// Block a dispute was opened in.
+    #[pallet::storage]
+    #[pallet::getter(fn disputed_at)]
+    pub type DisputedAt<T: Config> =
+        StorageMap<_, Blake2_128Concat, ContentId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Block the decision of a dispute was reached in, by the jury or after escalation.
+    #[pallet::storage]
+    #[pallet::getter(fn decided_at)]
+    pub type DecidedAt<T: Config> =
+        StorageMap<_, Blake2_128Concat, ContentId, BlockNumberFor<T>, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn pending_slashes)]
     pub(super) type PendingSlashes<T: Config> =
@@ -177,10 +390,138 @@ pub mod pallet {
     pub struct CourtSession<T: Config> {
         pub jurors: BoundedVec<T::Did, T::MaxJurorsPerDispute>,
         pub started_at: Option<BlockNumberFor<T>>,
+        /// First block of the reveal phase, set when deliberation starts.
+        pub reveal_from: Option<BlockNumberFor<T>>,
         pub ended_at: Option<BlockNumberFor<T>>,
         pub verdict: Verdict<T>,
         pub context: BoundedVec<u8, T::MaxContextLength>,
         pub expires_at: BlockNumberFor<T>,
+        pub disputer: T::Did,
+        /// Evidence can be submitted before this block, deliberation starts from it.
+        pub evidence_until: BlockNumberFor<T>,
+        /// 0 for the first round, every appeal starts the next one.
+        pub round: u32,
+        /// Jurors to summon before deliberation can start.
+        pub jury_size: u32,
+        pub category: DisputeCategory,
+        /// Parameters of the category when the dispute was opened.
+        pub params: CategoryParams<BlockNumberFor<T>>,
+        /// Block the randomness known when the round was opened is known since. The jury is
+        /// only drawn from `Randomness` known since a later block, which no one could know
+        /// when submitting the dispute.
+        pub randomness_after: BlockNumberFor<T>,
+    }
+
+    impl<T: Config> CourtSession<T> {
+        /// The phase of the session in block `now`: evidence is submitted until
+        /// `evidence_until`, jurors commit from `started_at`, reveal from `reveal_from` and
+        /// voting closes after `expires_at`.
+        pub fn phase(&self, now: BlockNumberFor<T>) -> SessionPhase {
+            if self.ended_at.is_some() || now > self.expires_at {
+                return SessionPhase::Closed;
+            }
+            match (self.started_at, self.reveal_from) {
+                (Some(_), Some(reveal_from)) if now < reveal_from => SessionPhase::Commit,
+                (Some(_), _) => SessionPhase::Reveal,
+                (None, _) if now < self.evidence_until => SessionPhase::Evidence,
+                (None, _) => SessionPhase::Summoning,
+            }
+        }
+    }
+
+    pub type EvidenceCid<T> = BoundedVec<u8, <T as Config>::MaxIpfsCidLength>;
+
+    /// An evidence entry of a dispute, see `SubmittedEvidence`.
+    #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct EvidenceEntry<T: Config> {
+        pub party: EvidenceParty,
+        pub evidence: Evidence<EvidenceCid<T>>,
+        pub submitted_at: BlockNumberFor<T>,
+    }
+
+    /// What a dispute claims is misleading about content.
+    #[derive(
+        Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, Copy, MaxEncodedLen, DecodeWithMemTracking,
+    )]
+    pub enum DisputeCategory {
+        /// The content is presented as captured at another time
+        #[codec(index = 0)]
+        MisleadingDate,
+        /// The content is presented as captured at another place
+        #[codec(index = 1)]
+        MisleadingLocation,
+        /// The content was edited to show something that did not happen
+        #[codec(index = 2)]
+        ManipulatedContent,
+    }
+
+    /// How disputes in a category are decided.
+    #[derive(
+        Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, Copy, MaxEncodedLen, DecodeWithMemTracking,
+    )]
+    pub struct CategoryParams<BlockNumber> {
+        /// Jurors summoned for the first round
+        pub jury_size: u32,
+        /// Blocks from the end of the evidence phase until voting closes
+        pub voting_period: BlockNumber,
+        /// Part of the jury that has to vote for the jury to decide
+        pub quorum: Perbill,
+        /// Part of the Yay and Nay votes a side needs to win, more than half. Without it the
+        /// dispute escalates.
+        pub supermajority: Perbill,
+    }
+
+    /// Bond of a party that appealed the `appealed` decision, held with
+    /// `HoldReason::CallCreation`.
+    #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct AppealBond<T: Config> {
+        pub appellant: T::AccountId,
+        pub amount: BalanceOf<T>,
+        pub appealed: Decision,
+    }
+
+    /// Bonds at stake in a dispute, held with `HoldReason::CallCreation`.
+    #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct DisputeBond<T: Config> {
+        pub disputer: T::AccountId,
+        pub amount: BalanceOf<T>,
+        /// Account and bond of the content owner, if it defends the content.
+        pub counter_bond: Option<(T::AccountId, BalanceOf<T>)>,
+    }
+
+    #[derive(
+        Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, Copy, MaxEncodedLen, DecodeWithMemTracking,
+    )]
+    pub enum SessionPhase {
+        /// Jurors are being summoned
+        Summoning,
+        /// Jurors commit to a hash of their vote
+        Commit,
+        /// Jurors reveal the vote they committed to
+        Reveal,
+        /// Votes are counted, commitments never revealed count as not voting
+        Closed,
+        /// The parties of the dispute and their witnesses submit evidence
+        Evidence,
+    }
+
+    /// Step of the court lifecycle `on_idle` takes once it falls due, see `Deadlines`.
+    #[derive(
+        Debug, Encode, Decode, TypeInfo, PartialEq, Eq, Clone, Copy, MaxEncodedLen, DecodeWithMemTracking,
+    )]
+    pub enum CourtAction {
+        /// Start deliberation, see `start_deliberation`
+        StartDeliberation,
+        /// Calculate the result and settle the jury, see `calculate_result` and
+        /// `get_reward_for_duty`
+        CalculateResult,
+        /// Decide an escalated dispute, see `calculate_escalated_result`
+        CalculateEscalatedResult,
+        /// Settle the dispute bonds once the decision is final, see `settle_dispute_bonds`
+        SettleBonds,
     }
 
     #[pallet::storage]
@@ -255,6 +596,127 @@ pub mod pallet {
             did: T::Did,
             admin: T::AccountId,
         },
+        /// A session entered `phase`.
+        SessionPhaseChanged {
+            content_id: ContentId,
+            phase: SessionPhase,
+        },
+        /// A juror committed to a vote.
+        VoteCommitted {
+            content_id: ContentId,
+            did: T::Did,
+        },
+        /// A juror revealed the vote it committed to.
+        VoteRevealed {
+            content_id: ContentId,
+            did: T::Did,
+            vote: Vote,
+        },
+        /// A dispute was opened with a bond of `amount` held from `who`.
+        DisputeBonded {
+            content_id: ContentId,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The owner `did` of disputed content posted a counter-bond from `who`.
+        CounterBondPosted {
+            content_id: ContentId,
+            did: T::Did,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The losing bond of a decided dispute was paid out: `juror_rewards` in total to the
+        /// jurors who voted, `compensation` to the winner, if it had a bond at stake.
+        BondsSettled {
+            content_id: ContentId,
+            winner: Option<T::AccountId>,
+            compensation: BalanceOf<T>,
+            juror_rewards: BalanceOf<T>,
+        },
+        /// A party of a dispute invited `witness` to submit evidence.
+        WitnessInvited {
+            content_id: ContentId,
+            did: T::Did,
+            witness: T::Did,
+        },
+        /// `did` submitted evidence to a dispute as `party`.
+        EvidenceSubmitted {
+            content_id: ContentId,
+            did: T::Did,
+            party: EvidenceParty,
+            evidence: Evidence<EvidenceCid<T>>,
+        },
+        /// `did` appealed the decision of a dispute with a bond held from `who`, starting
+        /// `round` with a jury of `jury_size`.
+        AppealLodged {
+            content_id: ContentId,
+            did: T::Did,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+            round: u32,
+            jury_size: u32,
+        },
+        /// An appeal round was decided. If it `upheld` the appealed decision, `juror_rewards`
+        /// of the appeal bond went to the jurors who voted, otherwise the bond was released.
+        AppealBondSettled {
+            content_id: ContentId,
+            appellant: T::AccountId,
+            upheld: bool,
+            juror_rewards: BalanceOf<T>,
+        },
+        /// The parameters of a dispute category were set, `None` restores the defaults.
+        CategoryParamsSet {
+            category: DisputeCategory,
+            params: Option<CategoryParams<BlockNumberFor<T>>>,
+        },
+        /// A juror bonded more stake, `stake` is its total now.
+        JurorStakeBonded {
+            did: T::Did,
+            amount: BalanceOf<T>,
+            stake: BalanceOf<T>,
+        },
+        /// A juror was slashed, `stake` is what it has bonded now.
+        JurorSlashed {
+            did: T::Did,
+            amount: BalanceOf<T>,
+            stake: BalanceOf<T>,
+        },
+        /// A juror left the pool and can withdraw its stake from block `until`.
+        JurorUnbonding {
+            did: T::Did,
+            until: BlockNumberFor<T>,
+        },
+        /// A juror cancelled unbonding and is back in the pool.
+        JurorRebonded {
+            did: T::Did,
+        },
+        /// A juror withdrew its stake and is no longer registered.
+        JurorWithdrawn {
+            did: T::Did,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The court pot holds less than a juror reward, the rewards queued stay queued until it
+        /// is refilled.
+        CourtPotUnderfunded {
+            available: BalanceOf<T>,
+            reward: BalanceOf<T>,
+        },
+        /// A due court action was not possible and is dropped, the dispute waits for the
+        /// permissionless call instead.
+        CourtActionFailed {
+            content_id: ContentId,
+            action: CourtAction,
+            error: DispatchError,
+        },
+        /// `round` of a dispute could not be decided for `error` and was voided, the bonds held
+        /// for it were released. A voided first round frees the content to be disputed again,
+        /// a voided appeal round leaves the appealed decision standing.
+        DisputeVoided {
+            content_id: ContentId,
+            round: u32,
+            error: DispatchError,
+        },
     }
 
     #[pallet::error]
@@ -297,76 +759,76 @@ pub mod pallet {
         NoRewardForEscalatedSession,
         /// Session has been rewarded
         SessionHasBeenRewarded,
+        /// No proof is registered under this content id
+        ContentNotFound,
+        /// Signer is not the account that registered the juror
+        NotJurorAdmin,
+        /// Votes can no longer be committed, only revealed
+        CommitPhaseOver,
+        /// Votes can only be revealed once the commit phase is over
+        RevealPhaseNotStarted,
+        /// Juror did not commit to a vote
+        VoteNotCommitted,
+        /// Revealed vote and salt do not match the commitment
+        CommitmentMismatch,
+        /// The DID does not own the disputed content
+        NotContentOwner,
+        /// The content owner already posted a counter-bond
+        CounterBondAlreadyPosted,
+        /// The dispute has been decided
+        DisputeDecided,
+        /// Deliberation can only start once the evidence phase is over
+        EvidencePhaseInProgress,
+        /// Evidence can no longer be submitted to the dispute
+        EvidencePhaseOver,
+        /// The DID is neither a party of the dispute nor an invited witness
+        NotDisputeParty,
+        /// The DID already is a party of the dispute or an invited witness
+        AlreadyDisputeParty,
+        /// The dispute has as many witnesses as it can have
+        TooManyWitnesses,
+        /// The DID submitted as much evidence as it can
+        TooMuchEvidence,
+        /// IPFS CID contains characters outside of the multibase alphabets
+        InvalidIpfsCid,
+        /// The current round of the dispute has not been decided
+        DisputeNotDecided,
+        /// The decision can no longer be appealed
+        AppealPeriodOver,
+        /// The decision can still be appealed, its bonds are settled after `AppealPeriod`
+        AppealPeriodNotOver,
+        /// The dispute went through `MaxAppealRounds` appeals already
+        MaxAppealRoundsReached,
+        /// Jury size, voting period or supermajority of the category are out of bounds
+        InvalidCategoryParams,
+        /// The juror is unbonding already
+        AlreadyUnbonding,
+        /// The juror is not unbonding
+        NotUnbonding,
+        /// The juror can only withdraw its stake once the unbonding period is over
+        UnbondingPeriodNotOver,
+        /// The juror still serves on a jury or has rewards or slashes queued
+        JuryDutyNotFinished,
+        /// The juror has bonded less than `HoldAmount`
+        StakeBelowMinimum,
+        /// The jury can only be drawn from randomness newer than the one known when its round
+        /// was opened
+        RandomnessUnavailable,
+        /// The DID was not selected for the jury of the session
+        NotSelected,
+        /// The jury of the current round is summoned already
+        JuryAlreadySummoned,
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
-            let (random_value, _) = insecure_randomness::Pallet::<T>::random(&b"rewards"[..]);
-            let random_number: u32 = random_value.encode().into_iter().collect::<Vec<u8>>().iter().sum::<u8>() as u32;
-            let punish_offenders = random_number % 2 == 0;
-           
-            match punish_offenders {
-                true => {
-                    let mut queue = <PendingSlashes<T>>::get();
-                    let batch_size: u32 = T::BatchRewardSize::get(); // number of jurors to slash per block
-                    let mut processed: u32 = 0;
-                    let amount = T::SlashAmount::get();
-        
-                    while processed < batch_size {
-                        if let Some(juror) = queue.pop() {
-                            if let Some(admin) = <JurorNativeAccountAdmin<T>>::get(&juror) {
-                                let result = <T as Config>::NativeBalance::burn_from(
-                                    &admin,
-                                    amount,
-                                    Preservation::Expendable,
-                                    Precision::BestEffort,
-                                    Fortitude::Force,
-                                );
-                                if let Err(_) = result {
-                                    let mut jurors = <Jurors<T>>::get();
-                                    let index = jurors.iter().position(|x| x == &juror);
-                                    if let Some(i) = index {
-                                        jurors.remove(i);
-                                    }
-                                    <Jurors<T>>::set(jurors);
-                                }
-                            };
-                            processed += 1;
-                        } else {
-                            break;
-                        }
-                    }
-                    <PendingSlashes<T>>::put(queue);
-                    return T::DbWeight::get().writes(processed.into());
-                },
-                false => {
-                    let mut queue = <PendingRewards<T>>::get();
-                    let batch_size: u32 = T::BatchRewardSize::get(); // number of jurors to slash per block
-                    let mut processed: u32 = 0;
-                    let amount = T::RewardAmount::get();
-                    let mut retrials = Vec::new();
-                    while processed < batch_size {
-                        if let Some(juror) = queue.pop() {
-                            if let Some(admin) = <JurorNativeAccountAdmin<T>>::get(&juror) {
-                                let result = <T as Config>::NativeBalance::mint_into(
-                                    &admin,
-                                    amount
-                                );
-                                if let Err(_) = result {
-                                   retrials.push(juror);
-                                }
-                            };
-                            processed += 1;
-                        } else {
-                            break;
-                        }
-                    }
-                    let _ = queue.try_append(&mut retrials);
-                    <PendingRewards<T>>::put(queue);
-                    return T::DbWeight::get().writes(processed.into());
-                }
-            }
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let used = Self::take_due_actions(now, remaining_weight);
+            used.saturating_add(Self::process_payouts(remaining_weight.saturating_sub(used)))
+        }
+
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            Self::announce_phase_changes(now)
         }
     }
 
@@ -385,7 +847,10 @@ pub mod pallet {
 
             ensure!(is_valid, Error::<T>::SignerDoesNotHaveRight);
             let mut jurors = <Jurors<T>>::get();
-            ensure!(!jurors.contains(&did), Error::<T>::DidAlreadyExists);
+            ensure!(
+                !jurors.contains(&did) && !<JurorNativeAccountAdmin<T>>::contains_key(&did),
+                Error::<T>::DidAlreadyExists
+            );
 
             jurors
                 .try_push(did.clone())
@@ -398,6 +863,8 @@ pub mod pallet {
                 &who,
                 T::HoldAmount::get(),
             )?;
+            <JurorNativeAccountAdmin<T>>::insert(&did, &who);
+            <JurorStakes<T>>::insert(&did, T::HoldAmount::get());
             Self::deposit_event(Event::JurorRegistered {
                 block_number: <frame_system::Pallet<T>>::block_number(),
                 admin: who,
@@ -407,19 +874,20 @@ pub mod pallet {
         }
 
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(10))]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4, 10))]
         pub fn dispute_content(
             origin: OriginFor<T>,
             did: T::Did,
             content_id: ContentId,
             context: BoundedVec<u8, T::MaxContextLength>,
-            expires_at: BlockNumberFor<T>,
+            category: DisputeCategory,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(
                 <Dispute<T>>::get(&content_id).is_none(),
                 Error::<T>::ContentAlreadyExists
             );
+            ensure!(T::ContentInspector::exists(&content_id), Error::<T>::ContentNotFound);
             let is_valid = <T as Config>::DidRegistry::is_signer_valid(
                 &who,
                 &did,
@@ -427,54 +895,69 @@ pub mod pallet {
             )
             .map_err(|_| Error::<T>::CouldNotGetResponse)?;
             ensure!(is_valid, Error::<T>::SignerDoesNotHaveRight);
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let evidence_until = current_block.saturating_add(T::EvidencePeriod::get());
+            let params = Self::category_params(category);
             let session = CourtSession {
                 jurors: BoundedVec::new(),
                 started_at: None,
+                reveal_from: None,
                 ended_at: None,
                 verdict: <Verdict<T>>::new(),
                 context,
-                expires_at,
+                expires_at: evidence_until.saturating_add(params.voting_period),
+                disputer: did,
+                evidence_until,
+                round: 0,
+                jury_size: params.jury_size.min(T::MaxJurorsPerDispute::get()),
+                category,
+                params,
+                randomness_after: Self::randomness_known_since(),
             };
-            Self::summon_jurors(&content_id)?;
-            <Dispute<T>>::set(content_id, Some(session));
+            <Dispute<T>>::set(&content_id, Some(session));
+            // the jury is drawn once the evidence phase is over
+            Self::schedule_action(evidence_until, &content_id, CourtAction::StartDeliberation)?;
+            let amount = T::DisputeBondAmount::get();
+            <T as Config>::NativeBalance::hold(&HoldReason::CallCreation.into(), &who, amount)?;
+            <DisputeBonds<T>>::insert(
+                &content_id,
+                DisputeBond { disputer: who.clone(), amount, counter_bond: None },
+            );
+            Self::deposit_event(Event::DisputeBonded { content_id: content_id.clone(), who, amount });
+            if evidence_until > current_block {
+                Self::schedule_phase_change(evidence_until, &content_id, SessionPhase::Summoning)?;
+                Self::deposit_event(Event::SessionPhaseChanged {
+                    content_id: content_id.clone(),
+                    phase: SessionPhase::Evidence,
+                });
+            }
+            <DisputedAt<T>>::insert(&content_id, current_block);
+            T::DisputeObserver::on_dispute_opened(&content_id, current_block);
             Ok(())
         }
 
+        /// Summons the rest of the jury to a session whose evidence phase is over and that has
+        /// not started deliberating yet. Permissionless fallback, the `StartDeliberation` action
+        /// draws the jury once the evidence phase is over.
         #[pallet::call_index(2)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(10))]
+        #[pallet::weight(Pallet::<T>::summon_weight() + T::DbWeight::get().reads(1))]
         pub fn summon_jurors_ext(origin: OriginFor<T>, content_id: ContentId) -> DispatchResult {
             ensure_signed(origin)?;
+            let session = <Dispute<T>>::get(&content_id).ok_or(Error::<T>::SessionNotFound)?;
+            ensure!(session.started_at.is_none(), Error::<T>::SessionALreadyStarted);
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(now < session.expires_at, Error::<T>::SessionHasEnded);
+            ensure!(now >= session.evidence_until, Error::<T>::EvidencePhaseInProgress);
             Self::summon_jurors(&content_id)?;
             Ok(())
         }
 
         #[pallet::call_index(3)]
         #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(10))]
+        /// Permissionless fallback, `on_idle` starts deliberation once the evidence phase is over.
         pub fn start_deliberation(origin: OriginFor<T>, content_id: ContentId) -> DispatchResult {
             ensure_signed(origin)?;
-            let session = <Dispute<T>>::get(&content_id);
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            if let Some(mut session) = session {
-                if current_block.ge(&session.expires_at) {
-                    return Err(Error::<T>::SessionHasEnded.into());
-                }
-                if session.started_at.is_some() {
-                    return Err(Error::<T>::SessionALreadyStarted.into());
-                }
-                let summoned_jury = <JurySummoned<T>>::get(&content_id).unwrap_or_default();
-                if summoned_jury < T::MinJurorsPerDispute::get() {
-                    Self::summon_jurors(&content_id)?;
-                    return Err(Error::<T>::JuryReqNotMet.into());
-                }
-                let selected_jurors = <JurySelection<T>>::get(&content_id);
-                session.jurors = selected_jurors;
-                session.started_at = Some(current_block);
-                <Dispute<T>>::set(&content_id, Some(session));
-                <JurySelection<T>>::remove(&content_id);
-            } else {
-                return Err(Error::<T>::SessionNotFound.into());
-            }
-            Ok(())
+            Self::do_start_deliberation(content_id)
         }
 
         #[pallet::call_index(4)]
@@ -495,46 +978,56 @@ pub mod pallet {
 
             let session = <Dispute<T>>::get(&content_id);
             let current_block = <frame_system::Pallet<T>>::block_number();
-            if let Some(session) = session {
-                if current_block.ge(&session.expires_at) {
-                    return Err(Error::<T>::SessionHasEnded.into());
-                }
-                if session.started_at.is_some() {
-                    return Err(Error::<T>::SessionALreadyStarted.into());
-                }
-            } else {
+            let Some(mut session) = session else {
                 return Err(Error::<T>::SessionNotFound.into());
+            };
+            if current_block.ge(&session.expires_at) {
+                return Err(Error::<T>::SessionHasEnded.into());
+            }
+            if session.started_at.is_some() {
+                return Err(Error::<T>::SessionALreadyStarted.into());
             }
 
+            ensure!(<JuryDuty<T>>::take(&did, &content_id), Error::<T>::NotSelected);
             let amount = T::ExclusionFee::get();
-            <T as Config>::NativeBalance::burn_from(
+            <T as Config>::NativeBalance::transfer(
                 &who,
+                &Self::pot_account(),
                 amount,
                 Preservation::Expendable,
-                Precision::BestEffort,
-                Fortitude::Polite,
             )?;
-            <JuryDuty<T>>::remove(&did, &content_id);
-            <JurySummoned<T>>::mutate(&content_id, |n| n.unwrap_or_default() - 1);
+            Self::end_duty(&did);
+            <JurySummoned<T>>::mutate(&content_id, |n| *n = n.map(|n| n.saturating_sub(1)));
 
             let mut selected = <JurySelection<T>>::get(&content_id);
             let index = selected.iter().position(|x| x == &did);
             if let Some(i) = index {
                 selected.remove(i);
             }
-            <JurySelection<T>>::insert(content_id, selected);
+            <JurySelection<T>>::insert(&content_id, selected);
+            // the seat is only filled by the deferred draw, from randomness the excluding party
+            // does not know yet
+            session.randomness_after = Self::randomness_known_since();
+            <Dispute<T>>::insert(content_id, session);
             Ok(())
         }
 
-        #[pallet::call_index(5)]
+        #[pallet::call_index(6)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(10))]
+        /// Permissionless fallback, `on_idle` calculates the result once the session closed.
+        pub fn calculate_result(origin: OriginFor<T>, content_id: ContentId) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::do_calculate_result(content_id)
+        }
+
+        #[pallet::call_index(7)]
         #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(10))]
-        pub fn cast_vote(
+        pub fn vote_escalated_content(
             origin: OriginFor<T>,
             did: T::Did,
             content_id: ContentId,
             vote: Vote,
         ) -> DispatchResult {
-            // Implementation of finalize_duty_and_get_reward
             let who = ensure_signed(origin)?;
             let is_valid = <T as Config>::DidRegistry::is_signer_valid(
                 &who,
@@ -543,33 +1036,30 @@ pub mod pallet {
             )
             .map_err(|_| Error::<T>::CouldNotGetResponse)?;
             ensure!(is_valid, Error::<T>::SignerDoesNotHaveRight);
-            let session = <Dispute<T>>::get(&content_id);
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            if let Some(mut session) = session {
-                if current_block.ge(&session.expires_at) && session.ended_at.is_some() {
+
+            let escalated_session = <EscalatedSession<T>>::get(&content_id);
+
+            if let Some(mut session) = escalated_session {
+                let current_block = <frame_system::Pallet<T>>::block_number();
+
+                let expires_at = current_block + T::EscalatedVotingPeriod::get();
+                if current_block > expires_at {
                     return Err(Error::<T>::SessionHasEnded.into());
                 }
-                if session.started_at.is_none() {
-                    return Err(Error::<T>::SessionHasNotStarted.into());
-                }
-                if !session.jurors.contains(&did) {
-                    return Err(Error::<T>::JurorNotInSession.into());
-                }
-
-                for vote in session.verdict.votes.iter() {
+                for vote in session.votes.iter() {
                     if &vote.juror == &did {
                         return Err(Error::<T>::JurorAlreadyVoted.into());
                     }
                 }
+                session.escalated_at = Some(current_block);
                 session
-                    .verdict
                     .votes
                     .try_push(VoteRegistry {
                         juror: did,
                         vote: vote,
                     })
                     .map_err(|_| Error::<T>::StorageOverflow)?;
-                <Dispute<T>>::insert(&content_id, session);
+                <EscalatedSession<T>>::insert(&content_id, session);
             } else {
                 return Err(Error::<T>::SessionNotFound.into());
             }
@@ -577,79 +1067,129 @@ pub mod pallet {
             Ok(())
         }
 
-        #[pallet::call_index(6)]
+        #[pallet::call_index(8)]
         #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(10))]
-        pub fn calculate_result(origin: OriginFor<T>, content_id: ContentId) -> DispatchResult {
+        /// Permissionless fallback, `on_idle` calculates the result once the escalated voting
+        /// period is over.
+        pub fn calculate_escalated_result(
+            origin: OriginFor<T>,
+            content_id: ContentId,
+        ) -> DispatchResult {
             ensure_signed(origin)?;
+            Self::do_calculate_escalated_result(content_id)
+        }
 
-            if <Decisions<T>>::get(&content_id).is_some() {
-                return Ok(());
-            }
-
-            let session = <Dispute<T>>::get(&content_id);
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            if let Some(mut session) = session {
-                if current_block.le(&session.expires_at) {
-                    return Err(Error::<T>::SessionInProgress.into());
-                }
-                if session.started_at.is_none() {
-                    return Err(Error::<T>::SessionHasNotStarted.into());
-                }
+        // #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Clone, MaxEncodedLen)]
+        // #[scale_info(skip_type_params(T))]
+        // pub struct CourtSession<T: Config> {
+        //     pub jurors: BoundedVec<T::Did, T::MaxJurorsPerDispute>,
+        //     pub started_at: Option<BlockNumberFor<T>>,
+        //     pub ended_at: Option<BlockNumberFor<T>>,
+        //     pub verdict: Verdict<T>,
+        //     pub context: BoundedVec<u8, T::MaxContextLength>,
+        //     pub expires_at: BlockNumberFor<T>,
+        // }
 
-                if session.verdict.escalated {
-                    return Err(Error::<T>::SessionEscalated.into());
-                }
+        // #[pallet::storage]
+        // #[pallet::getter(fn get_escalated_dispute)]
+        // pub type EscalatedSession<T: Config> =
+        //     StorageMap<_, Blake2_128Concat, ContentId, Escalated<T>, OptionQuery>;
 
-                if session.verdict.votes.len() < 2 {
-                    return Err(Error::<T>::NotEnoughVotes.into());
-                }
+        // #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Clone, MaxEncodedLen)]
+        // #[scale_info(skip_type_params(T))]
+        // pub struct Verdict<T: Config> {
+        //     pub escalated: bool,
+        //     pub decision: Decision,
+        //     pub votes: BoundedVec<VoteRegistry<T>, T::MaxJurorsPerDispute>,
+        //     pub rewarded_and_slashed: bool,
+        // }
 
-                let votes_required_to_pass = session.verdict.votes.len() / 2;
+        #[pallet::call_index(9)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(10))]
+        /// Permissionless fallback, `on_idle` settles the jury with the result of the session.
+        pub fn get_reward_for_duty(origin: OriginFor<T>, content_id: ContentId) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::settle_jury_duty(content_id)
+        }
 
-                let convict_votes = session
-                    .verdict
-                    .votes
-                    .iter()
-                    .filter(|vote| vote.vote.eq(&Vote::Yay))
-                    .count();
+        /// Bonds `amount` more for the juror `did`, making it proportionally more likely to be
+        /// drawn into juries. A juror slashed below `HoldAmount` rejoins the pool once it bonds
+        /// enough again, unless it is unbonding.
+        #[pallet::call_index(10)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2, 2))]
+        pub fn bond_juror_stake(
+            origin: OriginFor<T>,
+            did: T::Did,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                <JurorNativeAccountAdmin<T>>::get(&did).as_ref() == Some(&who),
+                Error::<T>::NotJurorAdmin
+            );
+            <T as Config>::NativeBalance::hold(
+                &HoldReason::JurorAccountCreation.into(),
+                &who,
+                amount,
+            )?;
+            let stake = Self::stake_of(&did).saturating_add(amount);
+            <JurorStakes<T>>::insert(&did, stake);
+            if stake >= T::HoldAmount::get() && !<Unbonding<T>>::contains_key(&did) {
+                Self::join_pool(&did)?;
+            }
+            Self::deposit_event(Event::JurorStakeBonded { did, amount, stake });
+            Ok(())
+        }
 
-                if convict_votes == votes_required_to_pass {
-                    session.verdict.escalated = true;
-                    <Dispute<T>>::insert(&content_id, session);
-                    let escalted = Escalated {
-                        escalated_at: Some(<frame_system::Pallet<T>>::block_number()),
-                        decision_at: None,
-                        decision: Decision::Pending,
-                        votes: BoundedVec::new(),
-                    };
-                    <EscalatedSession<T>>::insert(&content_id, escalted);
+        /// Commits the juror `did` to a vote without revealing it, see `vote_commitment`. Only
+        /// possible in the commit phase of the session.
+        #[pallet::call_index(11)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(3, 1))]
+        pub fn commit_vote(
+            origin: OriginFor<T>,
+            did: T::Did,
+            content_id: ContentId,
+            commitment: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let is_valid = <T as Config>::DidRegistry::is_signer_valid(
+                &who,
+                &did,
+                &T::GivenRight::from(BaseRight::Dispute),
+            )
+            .map_err(|_| Error::<T>::CouldNotGetResponse)?;
+            ensure!(is_valid, Error::<T>::SignerDoesNotHaveRight);
 
-                    <EscalatedDisputes<T>>::mutate(|disputes| -> Result<(), Error<T>> {
-                        disputes
-                            .try_push(content_id)
-                            .map_err(|_| Error::<T>::StorageOverflow.into())?;
-                        Ok(())
-                    })?;
-                    return Ok(());
-                }
-                let result = match convict_votes > votes_required_to_pass {
-                    true => Decision::Convict,
-                    false => Decision::Acquittal,
-                };
-                <Decisions<T>>::insert(&content_id, result);
-            } else {
-                return Err(Error::<T>::SessionNotFound.into());
+            let session = <Dispute<T>>::get(&content_id).ok_or(Error::<T>::SessionNotFound)?;
+            match session.phase(frame_system::Pallet::<T>::block_number()) {
+                SessionPhase::Evidence | SessionPhase::Summoning => {
+                    return Err(Error::<T>::SessionHasNotStarted.into())
+                },
+                SessionPhase::Commit => {},
+                SessionPhase::Reveal => return Err(Error::<T>::CommitPhaseOver.into()),
+                SessionPhase::Closed => return Err(Error::<T>::SessionHasEnded.into()),
             }
+            ensure!(session.jurors.contains(&did), Error::<T>::JurorNotInSession);
+            ensure!(
+                !<VoteCommitments<T>>::contains_key(&content_id, &did),
+                Error::<T>::JurorAlreadyVoted
+            );
+
+            <VoteCommitments<T>>::insert(&content_id, &did, commitment);
+            Self::deposit_event(Event::VoteCommitted { content_id, did });
             Ok(())
         }
 
-        #[pallet::call_index(7)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(10))]
-        pub fn vote_escalated_content(
+        /// Reveals the vote the juror `did` committed to. Only possible in the reveal phase of the
+        /// session, a vote that is never revealed counts as not voting.
+        #[pallet::call_index(12)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(3, 2))]
+        pub fn reveal_vote(
             origin: OriginFor<T>,
             did: T::Did,
             content_id: ContentId,
             vote: Vote,
+            salt: [u8; 32],
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             let is_valid = <T as Config>::DidRegistry::is_signer_valid(
@@ -660,52 +1200,561 @@ pub mod pallet {
             .map_err(|_| Error::<T>::CouldNotGetResponse)?;
             ensure!(is_valid, Error::<T>::SignerDoesNotHaveRight);
 
-            let escalated_session = <EscalatedSession<T>>::get(&content_id);
+            let mut session = <Dispute<T>>::get(&content_id).ok_or(Error::<T>::SessionNotFound)?;
+            match session.phase(frame_system::Pallet::<T>::block_number()) {
+                SessionPhase::Evidence | SessionPhase::Summoning => {
+                    return Err(Error::<T>::SessionHasNotStarted.into())
+                },
+                SessionPhase::Commit => return Err(Error::<T>::RevealPhaseNotStarted.into()),
+                SessionPhase::Reveal => {},
+                SessionPhase::Closed => return Err(Error::<T>::SessionHasEnded.into()),
+            }
+            ensure!(
+                !session.verdict.votes.iter().any(|registered| registered.juror == did),
+                Error::<T>::JurorAlreadyVoted
+            );
+            let commitment =
+                <VoteCommitments<T>>::get(&content_id, &did).ok_or(Error::<T>::VoteNotCommitted)?;
+            ensure!(
+                commitment == Self::vote_commitment(&content_id, &did, &vote, &salt),
+                Error::<T>::CommitmentMismatch
+            );
 
-            if let Some(mut session) = escalated_session {
-                let current_block = <frame_system::Pallet<T>>::block_number();
+            session
+                .verdict
+                .votes
+                .try_push(VoteRegistry { juror: did.clone(), vote: vote.clone() })
+                .map_err(|_| Error::<T>::StorageOverflow)?;
+            <Dispute<T>>::insert(&content_id, session);
+            <VoteCommitments<T>>::remove(&content_id, &did);
+            Self::deposit_event(Event::VoteRevealed { content_id, did, vote });
+            Ok(())
+        }
 
-                let expires_at = current_block + T::EscalatedVotingPeriod::get();
-                if current_block > expires_at {
+        /// Lets the owner `did` of disputed content defend it with a counter-bond of
+        /// `DisputeBondAmount`. If the content is convicted the counter-bond pays the disputer
+        /// and the jurors, if it is acquitted the disputer's bond compensates the owner.
+        #[pallet::call_index(13)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4, 2))]
+        pub fn post_counter_bond(
+            origin: OriginFor<T>,
+            did: T::Did,
+            content_id: ContentId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let is_valid = <T as Config>::DidRegistry::is_signer_valid(
+                &who,
+                &did,
+                &T::GivenRight::from(BaseRight::Update),
+            )
+            .map_err(|_| Error::<T>::CouldNotGetResponse)?;
+            ensure!(is_valid, Error::<T>::SignerDoesNotHaveRight);
+            ensure!(
+                T::ContentInspector::owner(&content_id).as_ref() == Some(&did),
+                Error::<T>::NotContentOwner
+            );
+
+            let session = <Dispute<T>>::get(&content_id).ok_or(Error::<T>::SessionNotFound)?;
+            ensure!(
+                session.round == 0 && session.verdict.decision == Decision::Pending,
+                Error::<T>::DisputeDecided
+            );
+            let mut bond = <DisputeBonds<T>>::get(&content_id).ok_or(Error::<T>::DisputeDecided)?;
+            ensure!(bond.counter_bond.is_none(), Error::<T>::CounterBondAlreadyPosted);
+            let amount = T::DisputeBondAmount::get();
+            <T as Config>::NativeBalance::hold(&HoldReason::CallCreation.into(), &who, amount)?;
+            bond.counter_bond = Some((who.clone(), amount));
+            <DisputeBonds<T>>::insert(&content_id, bond);
+            Self::deposit_event(Event::CounterBondPosted { content_id, did, who, amount });
+            Ok(())
+        }
+
+        /// Lets a party `did` of a dispute, the disputer or the content owner, invite `witness`
+        /// to submit evidence during the evidence phase.
+        #[pallet::call_index(14)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4, 1))]
+        pub fn invite_witness(
+            origin: OriginFor<T>,
+            did: T::Did,
+            content_id: ContentId,
+            witness: T::Did,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let session = <Dispute<T>>::get(&content_id).ok_or(Error::<T>::SessionNotFound)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(session.phase(now) == SessionPhase::Evidence, Error::<T>::EvidencePhaseOver);
+            let party = Self::evidence_party(&content_id, &session, &did);
+            ensure!(
+                matches!(party, Some(EvidenceParty::Disputer | EvidenceParty::Owner)),
+                Error::<T>::NotDisputeParty
+            );
+            Self::ensure_party_signer(&who, &did, party)?;
+            ensure!(
+                Self::evidence_party(&content_id, &session, &witness).is_none(),
+                Error::<T>::AlreadyDisputeParty
+            );
+
+            <Witnesses<T>>::try_mutate(&content_id, |witnesses| witnesses.try_push(witness.clone()))
+                .map_err(|_| Error::<T>::TooManyWitnesses)?;
+            Self::deposit_event(Event::WitnessInvited { content_id, did, witness });
+            Ok(())
+        }
+
+        /// Submits `evidence` to a dispute as `did`, which must be the disputer, the content
+        /// owner or an invited witness. Each of them can submit up to `MaxEvidencePerParty`
+        /// entries before the evidence phase ends.
+        #[pallet::call_index(15)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4, 1))]
+        pub fn submit_evidence(
+            origin: OriginFor<T>,
+            did: T::Did,
+            content_id: ContentId,
+            evidence: Evidence<EvidenceCid<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let session = <Dispute<T>>::get(&content_id).ok_or(Error::<T>::SessionNotFound)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(session.phase(now) == SessionPhase::Evidence, Error::<T>::EvidencePhaseOver);
+            let party = Self::evidence_party(&content_id, &session, &did)
+                .ok_or(Error::<T>::NotDisputeParty)?;
+            Self::ensure_party_signer(&who, &did, Some(party))?;
+            if let Evidence::IpfsCid(cid) = &evidence {
+                // the CID ends up in gateway URLs, so keep it to the multibase alphabets
+                ensure!(
+                    !cid.is_empty() && cid.iter().all(u8::is_ascii_alphanumeric),
+                    Error::<T>::InvalidIpfsCid
+                );
+            }
+
+            let entry = EvidenceEntry { party, evidence: evidence.clone(), submitted_at: now };
+            <SubmittedEvidence<T>>::try_mutate(&content_id, &did, |entries| entries.try_push(entry))
+                .map_err(|_| Error::<T>::TooMuchEvidence)?;
+            Self::deposit_event(Event::EvidenceSubmitted { content_id, did, party, evidence });
+            Ok(())
+        }
+
+        /// Appeals the decision of the latest round of a dispute as one of its parties, the
+        /// disputer or the content owner. It holds `AppealBondAmount` and starts the next round
+        /// with a fresh jury of `2n + 1`, drawn in a later block, which deliberates as long as the
+        /// round before. Jurors of the appealed round are settled as with `get_reward_for_duty`.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(
+            u64::from(T::MaxJurorsPerDispute::get()).saturating_add(10),
+            u64::from(T::MaxJurorsPerDispute::get()).saturating_add(10),
+        ))]
+        pub fn appeal(origin: OriginFor<T>, did: T::Did, content_id: ContentId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut session = <Dispute<T>>::get(&content_id).ok_or(Error::<T>::SessionNotFound)?;
+            let appealed = session.verdict.decision.clone();
+            ensure!(appealed != Decision::Pending, Error::<T>::DisputeNotDecided);
+            let now = <frame_system::Pallet<T>>::block_number();
+            let decided_at = <DecidedAt<T>>::get(&content_id).unwrap_or_default();
+            ensure!(
+                now <= decided_at.saturating_add(T::AppealPeriod::get()),
+                Error::<T>::AppealPeriodOver
+            );
+            ensure!(session.round < T::MaxAppealRounds::get(), Error::<T>::MaxAppealRoundsReached);
+            let party = Self::evidence_party(&content_id, &session, &did);
+            ensure!(
+                matches!(party, Some(EvidenceParty::Disputer | EvidenceParty::Owner)),
+                Error::<T>::NotDisputeParty
+            );
+            Self::ensure_party_signer(&who, &did, party)?;
+
+            let amount = T::AppealBondAmount::get();
+            <T as Config>::NativeBalance::hold(&HoldReason::CallCreation.into(), &who, amount)?;
+            <AppealBonds<T>>::insert(
+                &content_id,
+                AppealBond { appellant: who.clone(), amount, appealed },
+            );
+            if session.ended_at.is_none() {
+                Self::close_jury_duty(&content_id, &mut session)?;
+            }
+
+            let jury_size = (session.jurors.len() as u32)
+                .saturating_mul(2)
+                .saturating_add(1)
+                .min(T::MaxJurorsPerDispute::get());
+            let round = session.round + 1;
+            let next = CourtSession {
+                jurors: BoundedVec::new(),
+                started_at: None,
+                reveal_from: None,
+                ended_at: None,
+                verdict: <Verdict<T>>::new(),
+                context: session.context,
+                expires_at: now.saturating_add(session.params.voting_period),
+                disputer: session.disputer,
+                evidence_until: now,
+                round,
+                jury_size,
+                category: session.category,
+                params: session.params,
+                randomness_after: Self::randomness_known_since(),
+            };
+            // jurors of earlier rounds keep their `JuryDuty`, so the new jury is drawn without them
+            <JurySummoned<T>>::remove(&content_id);
+            for juror in <JurySelection<T>>::take(&content_id) {
+                Self::end_duty(&juror);
+            }
+            <Dispute<T>>::insert(&content_id, next);
+            // drawn from the randomness of a later block than the appeal
+            Self::schedule_action(
+                now.saturating_add(One::one()),
+                &content_id,
+                CourtAction::StartDeliberation,
+            )?;
+            Self::deposit_event(Event::AppealLodged { content_id, did, who, amount, round, jury_size });
+            Ok(())
+        }
+
+        /// Sets the parameters of disputes opened in `category` from now on, `None` restores
+        /// `DefaultCategoryParams`. Disputes already opened keep the parameters they were
+        /// opened with.
+        #[pallet::call_index(17)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+        pub fn set_category_params(
+            origin: OriginFor<T>,
+            category: DisputeCategory,
+            params: Option<CategoryParams<BlockNumberFor<T>>>,
+        ) -> DispatchResult {
+            T::CategoryOrigin::ensure_origin(origin)?;
+            if let Some(params) = &params {
+                ensure!(
+                    (T::MinJurorsPerDispute::get()..=T::MaxJurorsPerDispute::get())
+                        .contains(&params.jury_size)
+                        && params.voting_period > T::RevealPeriod::get()
+                        && params.supermajority > Perbill::from_percent(50),
+                    Error::<T>::InvalidCategoryParams
+                );
+            }
+            <CategoryParameters<T>>::set(category, params);
+            Self::deposit_event(Event::CategoryParamsSet { category, params });
+            Ok(())
+        }
+
+        /// Starts unbonding the juror `did`. It is no longer drawn into juries, but serves on
+        /// the juries it was drawn into. Its stake can be withdrawn after `UnbondingPeriod`.
+        #[pallet::call_index(18)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(3, 2))]
+        pub fn unregister_juror(origin: OriginFor<T>, did: T::Did) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                <JurorNativeAccountAdmin<T>>::get(&did).as_ref() == Some(&who),
+                Error::<T>::NotJurorAdmin
+            );
+            ensure!(!<Unbonding<T>>::contains_key(&did), Error::<T>::AlreadyUnbonding);
+            let until = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::UnbondingPeriod::get());
+            Self::leave_pool(&did);
+            <Unbonding<T>>::insert(&did, until);
+            Self::deposit_event(Event::JurorUnbonding { did, until });
+            Ok(())
+        }
+
+        /// Cancels unbonding the juror `did` and puts it back into the pool.
+        #[pallet::call_index(19)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4, 2))]
+        pub fn rebond_juror(origin: OriginFor<T>, did: T::Did) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                <JurorNativeAccountAdmin<T>>::get(&did).as_ref() == Some(&who),
+                Error::<T>::NotJurorAdmin
+            );
+            ensure!(<Unbonding<T>>::contains_key(&did), Error::<T>::NotUnbonding);
+            Self::join_pool(&did)?;
+            <Unbonding<T>>::remove(&did);
+            Self::deposit_event(Event::JurorRebonded { did });
+            Ok(())
+        }
+
+        /// Releases the stake of the juror `did` once it unbonded and finished its jury duty,
+        /// and forgets the juror.
+        #[pallet::call_index(20)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(10, 4))]
+        pub fn withdraw_juror_stake(origin: OriginFor<T>, did: T::Did) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                <JurorNativeAccountAdmin<T>>::get(&did).as_ref() == Some(&who),
+                Error::<T>::NotJurorAdmin
+            );
+            let until = <Unbonding<T>>::get(&did).ok_or(Error::<T>::NotUnbonding)?;
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() >= until,
+                Error::<T>::UnbondingPeriodNotOver
+            );
+            ensure!(!Self::has_open_duty(&did), Error::<T>::JuryDutyNotFinished);
+            let amount = <T as Config>::NativeBalance::release(
+                &HoldReason::JurorAccountCreation.into(),
+                &who,
+                Self::stake_of(&did),
+                Precision::BestEffort,
+            )?;
+            <Unbonding<T>>::remove(&did);
+            <JurorStakes<T>>::remove(&did);
+            <JurorNativeAccountAdmin<T>>::remove(&did);
+            Self::deposit_event(Event::JurorWithdrawn { did, who, amount });
+            Ok(())
+        }
+
+        /// Permissionless fallback, `on_idle` settles the dispute bonds against the final
+        /// decision once it can no longer be appealed.
+        #[pallet::call_index(21)]
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0)
+                + T::DbWeight::get().reads_writes(
+                    u64::from(T::MaxJurors::get()).saturating_mul(2).saturating_add(5),
+                    u64::from(T::MaxJurors::get()).saturating_add(5),
+                )
+        )]
+        pub fn settle_dispute_bonds(origin: OriginFor<T>, content_id: ContentId) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::do_settle_dispute_bonds(content_id)
+        }
+
+        // pub fn finalize_duty_and_get_reward(){}
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The commitment the juror `did` submits with `commit_vote` to later reveal `vote` with
+        /// `salt`. It binds the juror, so others cannot copy it and reveal the same vote.
+        pub fn vote_commitment(
+            content_id: &ContentId,
+            did: &T::Did,
+            vote: &Vote,
+            salt: &[u8; 32],
+        ) -> T::Hash {
+            T::Hashing::hash_of(&(content_id, did, vote, salt))
+        }
+
+        /// Parameters disputes opened in `category` are decided by.
+        pub fn category_params(category: DisputeCategory) -> CategoryParams<BlockNumberFor<T>> {
+            <CategoryParameters<T>>::get(category).unwrap_or_else(T::DefaultCategoryParams::get)
+        }
+
+        /// Evidence submitted to a dispute, oldest first.
+        pub fn evidence(content_id: &ContentId) -> Vec<(T::Did, EvidenceEntry<T>)> {
+            let mut evidence: Vec<_> = <SubmittedEvidence<T>>::iter_prefix(content_id)
+                .flat_map(|(did, entries)| entries.into_iter().map(move |entry| (did.clone(), entry)))
+                .collect();
+            evidence.sort_by_key(|(_, entry)| entry.submitted_at);
+            evidence
+        }
+
+        /// The side `did` is on in the dispute over `content_id`, if any.
+        fn evidence_party(
+            content_id: &ContentId,
+            session: &CourtSession<T>,
+            did: &T::Did,
+        ) -> Option<EvidenceParty> {
+            if &session.disputer == did {
+                Some(EvidenceParty::Disputer)
+            } else if T::ContentInspector::owner(content_id).as_ref() == Some(did) {
+                Some(EvidenceParty::Owner)
+            } else if <Witnesses<T>>::get(content_id).contains(did) {
+                Some(EvidenceParty::Witness)
+            } else {
+                None
+            }
+        }
+
+        /// The disputer acts with its `Dispute` right, the owner and witnesses with `Update`.
+        fn ensure_party_signer(
+            who: &T::AccountId,
+            did: &T::Did,
+            party: Option<EvidenceParty>,
+        ) -> DispatchResult {
+            let right = match party {
+                Some(EvidenceParty::Disputer) => BaseRight::Dispute,
+                _ => BaseRight::Update,
+            };
+            let is_valid =
+                <T as Config>::DidRegistry::is_signer_valid(who, did, &T::GivenRight::from(right))
+                    .map_err(|_| Error::<T>::CouldNotGetResponse)?;
+            ensure!(is_valid, Error::<T>::SignerDoesNotHaveRight);
+            Ok(())
+        }
+
+        /// Queues the jurors of a closed session for their reward or slash and ends the session.
+        /// Jurors that did not vote are slashed, the others rewarded unless the session
+        /// escalated.
+        fn close_jury_duty(content_id: &ContentId, session: &mut CourtSession<T>) -> DispatchResult {
+            // commitments never revealed are not votes, their jurors are slashed below
+            let _ = <VoteCommitments<T>>::clear_prefix(content_id, T::MaxJurorsPerDispute::get(), None);
+            let jurors_for_session = session.jurors.iter().cloned().collect::<BTreeSet<T::Did>>();
+            let jurors_that_voted = session
+                .verdict
+                .votes
+                .iter()
+                .map(|vote| vote.juror.clone())
+                .collect::<BTreeSet<T::Did>>();
+
+            // prepare to punish users that did not vote
+            let mut diff = jurors_for_session
+                .difference(&jurors_that_voted)
+                .cloned()
+                .collect::<Vec<_>>();
+            let mut pending_slashes = <PendingSlashes<T>>::get();
+            pending_slashes
+                .try_append(&mut diff)
+                .map_err(|_| Error::<T>::StorageOverflow)?;
+            <PendingSlashes<T>>::put(pending_slashes);
+
+            // escalated sessions are decided by all jurors, so their jury gets no reward
+            if !session.verdict.escalated {
+                let mut pending_rewards = <PendingRewards<T>>::get();
+                let mut jurors_that_voted = jurors_that_voted.into_iter().collect::<Vec<_>>();
+                pending_rewards
+                    .try_append(&mut jurors_that_voted)
+                    .map_err(|_| Error::<T>::StorageOverflow)?;
+                <PendingRewards<T>>::put(pending_rewards);
+            }
+
+            for juror in &session.jurors {
+                Self::end_duty(juror);
+            }
+            session.ended_at = Some(frame_system::Pallet::<T>::block_number());
+            Ok(())
+        }
+
+        /// Moves the summoned jury of a session into it and opens the commit phase.
+        fn do_start_deliberation(content_id: ContentId) -> DispatchResult {
+            let session = <Dispute<T>>::get(&content_id);
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            if let Some(mut session) = session {
+                if session.started_at.is_some() {
+                    return Err(Error::<T>::SessionALreadyStarted.into());
+                }
+                if current_block.ge(&session.expires_at) {
                     return Err(Error::<T>::SessionHasEnded.into());
                 }
-                for vote in session.votes.iter() {
-                    if &vote.juror == &did {
-                        return Err(Error::<T>::JurorAlreadyVoted.into());
-                    }
+                if current_block < session.evidence_until {
+                    return Err(Error::<T>::EvidencePhaseInProgress.into());
                 }
-                session.escalated_at = Some(current_block);
-                session
-                    .votes
-                    .try_push(VoteRegistry {
-                        juror: did,
-                        vote: vote,
-                    })
-                    .map_err(|_| Error::<T>::StorageOverflow)?;
-                <EscalatedSession<T>>::insert(&content_id, session);
+                let summoned_jury = <JurySummoned<T>>::get(&content_id).unwrap_or_default();
+                if summoned_jury < session.jury_size {
+                    return Err(Error::<T>::JuryReqNotMet.into());
+                }
+                let selected_jurors = <JurySelection<T>>::get(&content_id);
+                // leave at least one block to commit in
+                let reveal_from = session
+                    .expires_at
+                    .saturating_sub(T::RevealPeriod::get())
+                    .max(current_block.saturating_add(One::one()));
+                Self::schedule_phase_change(reveal_from, &content_id, SessionPhase::Reveal)?;
+                Self::schedule_phase_change(
+                    session.expires_at.saturating_add(One::one()),
+                    &content_id,
+                    SessionPhase::Closed,
+                )?;
+                Self::schedule_action(
+                    session.expires_at.saturating_add(One::one()),
+                    &content_id,
+                    CourtAction::CalculateResult,
+                )?;
+                session.jurors = selected_jurors;
+                session.started_at = Some(current_block);
+                session.reveal_from = Some(reveal_from);
+                <Dispute<T>>::set(&content_id, Some(session));
+                <JurySelection<T>>::remove(&content_id);
+                <SummonAttempts<T>>::remove(&content_id);
+                Self::deposit_event(Event::SessionPhaseChanged {
+                    content_id,
+                    phase: SessionPhase::Commit,
+                });
+            } else {
+                return Err(Error::<T>::SessionNotFound.into());
+            }
+            Ok(())
+        }
+
+        /// Decides a closed session, or escalates it without a supermajority.
+        fn do_calculate_result(content_id: ContentId) -> DispatchResult {
+            let session = <Dispute<T>>::get(&content_id);
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            if let Some(mut session) = session {
+                if session.verdict.decision != Decision::Pending {
+                    return Ok(());
+                }
+                if current_block.le(&session.expires_at) {
+                    return Err(Error::<T>::SessionInProgress.into());
+                }
+                if session.started_at.is_none() {
+                    return Err(Error::<T>::SessionHasNotStarted.into());
+                }
+
+                if session.verdict.escalated {
+                    return Err(Error::<T>::SessionEscalated.into());
+                }
+
+                let votes = session.verdict.votes.len() as u32;
+                let turnout = Perbill::from_rational(votes, session.jurors.len().max(1) as u32);
+                if votes < 2 || turnout < session.params.quorum {
+                    return Err(Error::<T>::NotEnoughVotes.into());
+                }
+
+                let count = |side: Vote| {
+                    session.verdict.votes.iter().filter(|vote| vote.vote == side).count() as u32
+                };
+                let (convict_votes, acquit_votes) = (count(Vote::Yay), count(Vote::Nay));
+                let decisive_votes = convict_votes.saturating_add(acquit_votes).max(1);
+                let supermajority = session.params.supermajority;
+                let result = if Perbill::from_rational(convict_votes, decisive_votes) >= supermajority
+                {
+                    Some(Decision::Convict)
+                } else if Perbill::from_rational(acquit_votes, decisive_votes) >= supermajority {
+                    Some(Decision::Acquittal)
+                } else {
+                    None
+                };
+
+                let Some(result) = result else {
+                    session.verdict.escalated = true;
+                    <Dispute<T>>::insert(&content_id, session);
+                    let escalted = Escalated {
+                        escalated_at: Some(<frame_system::Pallet<T>>::block_number()),
+                        decision_at: None,
+                        decision: Decision::Pending,
+                        votes: BoundedVec::new(),
+                    };
+                    <EscalatedSession<T>>::insert(&content_id, escalted);
+                    Self::schedule_action(
+                        current_block
+                            .saturating_add(T::EscalatedVotingPeriod::get())
+                            .saturating_add(One::one()),
+                        &content_id,
+                        CourtAction::CalculateEscalatedResult,
+                    )?;
+
+                    <EscalatedDisputes<T>>::mutate(|disputes| -> Result<(), Error<T>> {
+                        disputes
+                            .try_push(content_id)
+                            .map_err(|_| Error::<T>::StorageOverflow.into())?;
+                        Ok(())
+                    })?;
+                    return Ok(());
+                };
+                let voters: Vec<T::Did> =
+                    session.verdict.votes.iter().map(|vote| vote.juror.clone()).collect();
+                Self::settle_appeal_bond(&content_id, &result, &voters);
+                <DecisionVoters<T>>::insert(&content_id, BoundedVec::truncate_from(voters));
+                Self::record_decision(&content_id, result, current_block);
+                Self::settle_bonds_when_final(&content_id)?;
             } else {
                 return Err(Error::<T>::SessionNotFound.into());
             }
-
             Ok(())
         }
 
-        #[pallet::call_index(8)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(10))]
-        pub fn calculate_escalated_result(
-            origin: OriginFor<T>,
-            content_id: ContentId,
-        ) -> DispatchResult {
-            // Implementation of finalize_duty_and_get_reward
-            ensure_signed(origin)?;
-
-            if <Decisions<T>>::get(&content_id).is_some() {
-                return Ok(());
-            }
+        /// Decides an escalated session by simple majority once its voting period is over.
+        fn do_calculate_escalated_result(content_id: ContentId) -> DispatchResult {
 
             let session = <EscalatedSession<T>>::get(&content_id);
             let current_block = <frame_system::Pallet<T>>::block_number();
             if let Some(mut session) = session {
+                if session.decision != Decision::Pending {
+                    return Ok(());
+                }
                 let expires_at =
                     session.escalated_at.unwrap_or_default() + T::EscalatedVotingPeriod::get();
                 if current_block.le(&expires_at) {
@@ -728,7 +1777,11 @@ pub mod pallet {
                     true => Decision::Convict,
                     false => Decision::Acquittal,
                 };
-                <Decisions<T>>::insert(&content_id, &result);
+                let voters: Vec<T::Did> = session.votes.iter().map(|vote| vote.juror.clone()).collect();
+                Self::settle_appeal_bond(&content_id, &result, &voters);
+                <DecisionVoters<T>>::insert(&content_id, BoundedVec::truncate_from(voters));
+                Self::record_decision(&content_id, result.clone(), current_block);
+                Self::settle_bonds_when_final(&content_id)?;
                 session.decision_at = Some(<frame_system::Pallet<T>>::block_number());
                 session.decision = result;
                 <EscalatedSession<T>>::insert(&content_id, session);
@@ -745,142 +1798,683 @@ pub mod pallet {
             Ok(())
         }
 
-        // #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Clone, MaxEncodedLen)]
-        // #[scale_info(skip_type_params(T))]
-        // pub struct CourtSession<T: Config> {
-        //     pub jurors: BoundedVec<T::Did, T::MaxJurorsPerDispute>,
-        //     pub started_at: Option<BlockNumberFor<T>>,
-        //     pub ended_at: Option<BlockNumberFor<T>>,
-        //     pub verdict: Verdict<T>,
-        //     pub context: BoundedVec<u8, T::MaxContextLength>,
-        //     pub expires_at: BlockNumberFor<T>,
-        // }
-
-        // #[pallet::storage]
-        // #[pallet::getter(fn get_escalated_dispute)]
-        // pub type EscalatedSession<T: Config> =
-        //     StorageMap<_, Blake2_128Concat, ContentId, Escalated<T>, OptionQuery>;
-
-        // #[derive(Debug, Encode, Decode, TypeInfo, PartialEq, Clone, MaxEncodedLen)]
-        // #[scale_info(skip_type_params(T))]
-        // pub struct Verdict<T: Config> {
-        //     pub escalated: bool,
-        //     pub decision: Decision,
-        //     pub votes: BoundedVec<VoteRegistry<T>, T::MaxJurorsPerDispute>,
-        //     pub rewarded_and_slashed: bool,
-        // }
-
-        #[pallet::call_index(9)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(10))]
-        pub fn get_reward_for_duty(origin: OriginFor<T>, content_id: ContentId) -> DispatchResult {
-            ensure_signed(origin)?;
-            // Implementation of finalize_duty_and_get_reward
+        /// Queues the rewards and slashes of the jury of a closed session.
+        fn settle_jury_duty(content_id: ContentId) -> DispatchResult {
             // if escalated no reward but punishment will still be applied
-            //
             let session = <Dispute<T>>::get(&content_id);
 
             if let Some(mut session) = session {
                 if session.ended_at.is_some() {
                     return Err(Error::<T>::SessionHasBeenRewarded.into());
                 }
-                let jurors_for_session = <JurySelection<T>>::get(&content_id)
-                    .into_iter()
-                    .collect::<BTreeSet<T::Did>>();
-                if session.verdict.escalated {
-                    // slash jurors that did not vote
-                    let jurors_that_voted = session
-                        .verdict
-                        .votes
-                        .iter()
-                        .map(|vote| vote.juror.clone())
-                        .collect::<BTreeSet<T::Did>>();
-
-                    let mut diff = jurors_for_session
-                        .difference(&jurors_that_voted)
-                        .cloned()
-                        .collect::<Vec<_>>();
-                    let mut pending_slashes = <PendingSlashes<T>>::get();
-                    pending_slashes
-                        .try_append(&mut diff)
-                        .map_err(|_| Error::<T>::StorageOverflow)?;
-                    <PendingSlashes<T>>::put(pending_slashes);
-                    session.ended_at = Some(frame_system::Pallet::<T>::block_number());
-                    <Dispute<T>>::insert(&content_id, session);
-                    return Ok(());
+                let now = frame_system::Pallet::<T>::block_number();
+                ensure!(session.phase(now) == SessionPhase::Closed, Error::<T>::SessionInProgress);
+                Self::close_jury_duty(&content_id, &mut session)?;
+                <Dispute<T>>::insert(&content_id, session);
+            } else {
+                return Err(Error::<T>::SessionNotFound.into());
+            }
+            Ok(())
+        }
+
+        fn schedule_action(
+            at: BlockNumberFor<T>,
+            content_id: &ContentId,
+            action: CourtAction,
+        ) -> Result<(), Error<T>> {
+            <Deadlines<T>>::try_mutate(at, |actions| actions.try_push((content_id.clone(), action)))
+                .map_err(|_| Error::<T>::StorageOverflow)?;
+            <DeadlineCursor<T>>::mutate(|cursor| {
+                *cursor = Some(cursor.map_or(at, |cursor| cursor.min(at)));
+            });
+            Ok(())
+        }
+
+        /// Weight `on_idle` reserves for one court action, enough to summon from a full pool,
+        /// settle a full jury and void the dispute after.
+        fn court_action_weight() -> Weight {
+            let jurors = u64::from(T::MaxJurorsPerDispute::get());
+            let witnesses = u64::from(T::MaxWitnesses::get());
+            let accesses = jurors.saturating_mul(3).saturating_add(witnesses).saturating_add(30);
+            Self::summon_weight()
+                + Weight::from_parts(10_000, 0)
+                + T::DbWeight::get().reads_writes(accesses, accesses)
+        }
+
+        /// Weight of `summon_jurors`, which reads the duty, stake and reputation of every juror
+        /// in a pool of up to `MaxJurors` and draws up to `MaxJurorsPerDispute` of them.
+        fn summon_weight() -> Weight {
+            let pool = u64::from(T::MaxJurors::get());
+            let drawn = u64::from(T::MaxJurorsPerDispute::get());
+            Weight::from_parts(10_000, 0)
+                + T::DbWeight::get().reads_writes(
+                    pool.saturating_mul(3).saturating_add(3),
+                    drawn.saturating_mul(2).saturating_add(1),
+                )
+        }
+
+        /// Slashes or rewards, as drawn from `Randomness`, up to `BatchRewardSize` queued jurors.
+        /// Runs in `on_idle`, once the inherents of the block brought in the randomness, and
+        /// skips the block if there is none, i.e. it is known since block 0, or
+        /// `remaining_weight` does not cover a full batch.
+        fn process_payouts(remaining_weight: Weight) -> Weight {
+            if remaining_weight.any_lt(Self::payout_weight(T::BatchRewardSize::get())) {
+                return Weight::zero();
+            }
+            let (random_value, known_since) = T::Randomness::random(&b"rewards"[..]);
+            if known_since.is_zero() {
+                return Self::payout_weight(0);
+            }
+            let punish_offenders = random_value.as_ref().first().is_none_or(|byte| byte % 2 == 0);
+
+            match punish_offenders {
+                true => {
+                    let mut queue = <PendingSlashes<T>>::get();
+                    let batch_size: u32 = T::BatchRewardSize::get(); // number of jurors to slash per block
+                    let mut processed: u32 = 0;
+                    let amount = T::SlashAmount::get();
+
+                    while processed < batch_size {
+                        if let Some(juror) = queue.pop() {
+                            if let Some(admin) = <JurorNativeAccountAdmin<T>>::get(&juror) {
+                                Self::slash_stake(&juror, &admin, amount);
+                            };
+                            processed += 1;
+                        } else {
+                            break;
+                        }
+                    }
+                    <PendingSlashes<T>>::put(queue);
+                    Self::payout_weight(processed)
+                },
+                false => {
+                    let mut queue = <PendingRewards<T>>::get();
+                    let batch_size: u32 = T::BatchRewardSize::get(); // number of jurors to slash per block
+                    let mut processed: u32 = 0;
+                    let amount = T::RewardAmount::get();
+                    // rewards are only taken off the queue once paid, the rest keep their place
+                    let mut index = queue.len();
+                    while processed < batch_size && index > 0 {
+                        index -= 1;
+                        let paid = match <JurorNativeAccountAdmin<T>>::get(&queue[index]) {
+                            Some(admin) => <T as Config>::NativeBalance::transfer(
+                                &Self::pot_account(),
+                                &admin,
+                                amount,
+                                Preservation::Preserve,
+                            )
+                            .is_ok(),
+                            None => true,
+                        };
+                        processed += 1;
+                        if paid {
+                            queue.remove(index);
+                            continue;
+                        }
+                        let available = Self::pot_balance();
+                        if available < amount {
+                            // rewards stay queued until the pot is refilled
+                            Self::deposit_event(Event::CourtPotUnderfunded {
+                                available,
+                                reward: amount,
+                            });
+                            break;
+                        }
+                    }
+                    <PendingRewards<T>>::put(queue);
+                    Self::payout_weight(processed)
                 }
+            }
+        }
 
-                // prepare to punish users that did not vote
-                let jurors_that_voted = session
-                    .verdict
-                    .votes
-                    .iter()
-                    .map(|vote| vote.juror.clone())
-                    .collect::<BTreeSet<T::Did>>();
-                let mut diff = jurors_for_session
-                    .difference(&jurors_that_voted)
-                    .cloned()
-                    .collect::<Vec<_>>();
-                let mut pending_slashes = <PendingSlashes<T>>::get();
-                pending_slashes
-                    .try_append(&mut diff)
-                    .map_err(|_| Error::<T>::StorageOverflow)?;
-                <PendingSlashes<T>>::put(pending_slashes);
+        /// Weight of `process_payouts` paying out or slashing `processed` jurors.
+        fn payout_weight(processed: u32) -> Weight {
+            let accesses = u64::from(processed).saturating_mul(3).saturating_add(2);
+            Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(accesses, accesses)
+        }
 
-                // prepare reward jurors that voted jurors
-                let mut pending_rewards = <PendingRewards<T>>::get();
-                let mut jurors_that_voted = jurors_that_voted.into_iter().collect::<Vec<_>>();
-                pending_rewards
-                    .try_append(&mut jurors_that_voted)
-                    .map_err(|_| Error::<T>::StorageOverflow)?;
-                <PendingRewards<T>>::put(pending_rewards);
+        /// Takes the court actions due by block `now` in the order they fell due, as many as
+        /// `remaining_weight` and `MaxCourtActionsPerBlock` allow. The rest stay in `Deadlines`
+        /// for the blocks after.
+        fn take_due_actions(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut meter = WeightMeter::with_limit(remaining_weight);
+            if meter.try_consume(db_weight.reads_writes(1, 1)).is_err() {
+                return meter.consumed();
+            }
+            let Some(mut block) = <DeadlineCursor<T>>::get() else {
+                return meter.consumed();
+            };
+            let mut taken = 0u32;
+            while block <= now {
+                if meter.try_consume(db_weight.reads_writes(1, 1)).is_err() {
+                    break;
+                }
+                let mut actions = <Deadlines<T>>::take(block).into_inner().into_iter();
+                while let Some((content_id, action)) = actions.next() {
+                    if taken >= T::MaxCourtActionsPerBlock::get()
+                        || meter.try_consume(Self::court_action_weight()).is_err()
+                    {
+                        let rest: Vec<_> =
+                            core::iter::once((content_id, action)).chain(actions).collect();
+                        <Deadlines<T>>::insert(block, BoundedVec::truncate_from(rest));
+                        <DeadlineCursor<T>>::put(block);
+                        return meter.consumed();
+                    }
+                    Self::take_action(now, content_id, action);
+                    taken += 1;
+                }
+                block = block.saturating_add(One::one());
+            }
+            <DeadlineCursor<T>>::put(block);
+            meter.consumed()
+        }
 
-                session.ended_at = Some(frame_system::Pallet::<T>::block_number());
-                <Dispute<T>>::insert(&content_id, session);
-            } else {
-                return Err(Error::<T>::SessionNotFound.into());
+        /// Takes a due court `action` as its permissionless call would. Actions that are not
+        /// possible yet are retried later, those already taken by a call are dropped, rounds
+        /// that can no longer be decided are voided and the rest are dropped with
+        /// `CourtActionFailed`.
+        fn take_action(now: BlockNumberFor<T>, content_id: ContentId, action: CourtAction) {
+            let result = match action {
+                CourtAction::StartDeliberation => {
+                    let start =
+                        || with_storage_layer(|| Self::do_start_deliberation(content_id.clone()));
+                    let mut result = start();
+                    if result == Err(Error::<T>::JuryReqNotMet.into()) {
+                        // draw from the jurors that registered since the last summons
+                        let summoned = with_storage_layer(|| {
+                            Self::summon_jurors(&content_id).map_err(DispatchError::from)
+                        });
+                        if let Err(error) = summoned {
+                            frame::log::warn!(
+                                target: "runtime::context-court",
+                                "could not summon jurors for {:?}: {:?}", content_id, error
+                            );
+                        }
+                        result = start();
+                    }
+                    if result == Err(Error::<T>::JuryReqNotMet.into()) {
+                        let attempts = <SummonAttempts<T>>::mutate(&content_id, |attempts| {
+                            *attempts = attempts.saturating_add(1);
+                            *attempts
+                        });
+                        // the pool rarely grows from one block to the next, so back off
+                        let delay = 2u32.saturating_pow(attempts.saturating_sub(1));
+                        let next = now.saturating_add(delay.into());
+                        if attempts < T::MaxSummonAttempts::get()
+                            && Self::schedule_action(next, &content_id, action).is_ok()
+                        {
+                            return;
+                        }
+                    }
+                    result
+                },
+                CourtAction::CalculateResult => {
+                    let result =
+                        with_storage_layer(|| Self::do_calculate_result(content_id.clone()));
+                    // the jury is settled even if too few of it voted for a decision
+                    let settled = with_storage_layer(|| Self::settle_jury_duty(content_id.clone()));
+                    result.and(settled)
+                },
+                CourtAction::CalculateEscalatedResult => {
+                    let result =
+                        with_storage_layer(|| Self::do_calculate_escalated_result(content_id.clone()));
+                    if result == Err(Error::<T>::SessionInProgress.into()) {
+                        // every escalated vote restarts the voting period
+                        let escalated_at = <EscalatedSession<T>>::get(&content_id)
+                            .and_then(|session| session.escalated_at);
+                        if let Some(escalated_at) = escalated_at {
+                            let at = escalated_at
+                                .saturating_add(T::EscalatedVotingPeriod::get())
+                                .saturating_add(One::one())
+                                .max(now.saturating_add(One::one()));
+                            let _ = Self::schedule_action(at, &content_id, action);
+                        }
+                        return;
+                    }
+                    result
+                },
+                CourtAction::SettleBonds => {
+                    with_storage_layer(|| Self::do_settle_dispute_bonds(content_id.clone()))
+                },
+            };
+            // a decision appealed since settles the bonds once the appeal is decided
+            let taken_by_call = [
+                Error::<T>::SessionALreadyStarted.into(),
+                Error::<T>::SessionEscalated.into(),
+                Error::<T>::SessionHasBeenRewarded.into(),
+                Error::<T>::DisputeNotDecided.into(),
+                Error::<T>::AppealPeriodNotOver.into(),
+            ];
+            // no call can decide these rounds any more, their bonds would be held for good
+            let undecidable = [
+                Error::<T>::NotEnoughVotes.into(),
+                Error::<T>::SessionHasEnded.into(),
+                Error::<T>::JuryReqNotMet.into(),
+            ];
+            if let Err(error) = result {
+                if undecidable.contains(&error) {
+                    Self::void_dispute(&content_id, error);
+                } else if !taken_by_call.contains(&error) {
+                    Self::deposit_event(Event::CourtActionFailed { content_id, action, error });
+                }
+            }
+        }
+
+        fn schedule_phase_change(
+            at: BlockNumberFor<T>,
+            content_id: &ContentId,
+            phase: SessionPhase,
+        ) -> Result<(), Error<T>> {
+            <PhaseChanges<T>>::try_mutate(at, |changes| changes.try_push((content_id.clone(), phase)))
+                .map_err(|_| Error::<T>::StorageOverflow)
+        }
+
+        /// Emits `SessionPhaseChanged` for the sessions entering a new phase in block `now`.
+        fn announce_phase_changes(now: BlockNumberFor<T>) -> Weight {
+            let changes = <PhaseChanges<T>>::take(now);
+            for (content_id, phase) in changes {
+                Self::deposit_event(Event::SessionPhaseChanged { content_id, phase });
+            }
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+
+        /// Settles the dispute bonds if the decision just recorded is final, otherwise schedules
+        /// `SettleBonds` for when it can no longer be appealed.
+        fn settle_bonds_when_final(content_id: &ContentId) -> DispatchResult {
+            match Self::do_settle_dispute_bonds(content_id.clone()) {
+                Err(error) if error == Error::<T>::AppealPeriodNotOver.into() => {
+                    let at = <DecidedAt<T>>::get(content_id)
+                        .unwrap_or_default()
+                        .saturating_add(T::AppealPeriod::get())
+                        .saturating_add(One::one());
+                    Ok(Self::schedule_action(at, content_id, CourtAction::SettleBonds)?)
+                },
+                result => result,
+            }
+        }
+
+        /// Settles the dispute bonds against the decision of the latest round, once it is
+        /// final: after `AppealPeriod` or when no more appeals are left.
+        fn do_settle_dispute_bonds(content_id: ContentId) -> DispatchResult {
+            if !<DisputeBonds<T>>::contains_key(&content_id) {
+                <DecisionVoters<T>>::remove(&content_id);
+                return Ok(());
             }
+            let session = <Dispute<T>>::get(&content_id).ok_or(Error::<T>::SessionNotFound)?;
+            let decision = session.verdict.decision;
+            ensure!(decision != Decision::Pending, Error::<T>::DisputeNotDecided);
+            let now = <frame_system::Pallet<T>>::block_number();
+            let appealable_until = <DecidedAt<T>>::get(&content_id)
+                .unwrap_or_default()
+                .saturating_add(T::AppealPeriod::get());
+            ensure!(
+                session.round >= T::MaxAppealRounds::get() || now > appealable_until,
+                Error::<T>::AppealPeriodNotOver
+            );
+            let voters = <DecisionVoters<T>>::take(&content_id);
+            Self::settle_bonds(&content_id, &decision, &voters);
             Ok(())
         }
 
-        // pub fn finalize_duty_and_get_reward(){}
-    }
+        /// Pays out the bond of the party that lost the dispute. `JurorBondShare` of it is split
+        /// among the `voters`, the rest compensates the winner and the winner's own bond is
+        /// released. Without a winner bond at stake, all of it goes to the voters.
+        fn settle_bonds(content_id: &ContentId, decision: &Decision, voters: &[T::Did]) {
+            let Some(bond) = <DisputeBonds<T>>::take(content_id) else {
+                return;
+            };
+            let disputer = (bond.disputer, bond.amount);
+            let (winner, loser) = match decision {
+                Decision::Convict => (Some(disputer), bond.counter_bond),
+                Decision::Acquittal => (bond.counter_bond, Some(disputer)),
+                Decision::Pending => return,
+            };
+            let reason = HoldReason::CallCreation.into();
+            if let Some((winner, amount)) = &winner {
+                let _ = <T as Config>::NativeBalance::release(
+                    &reason,
+                    winner,
+                    *amount,
+                    Precision::BestEffort,
+                );
+            }
+            let Some((loser, loser_bond)) = loser else {
+                Self::deposit_event(Event::BondsSettled {
+                    content_id: content_id.clone(),
+                    winner: winner.map(|(winner, _)| winner),
+                    compensation: Zero::zero(),
+                    juror_rewards: Zero::zero(),
+                });
+                return;
+            };
+
+            let pot = match winner {
+                Some(_) => T::JurorBondShare::get().mul_floor(loser_bond),
+                None => loser_bond,
+            };
+            let juror_rewards = Self::pay_from_bond(&loser, pot, voters);
+            let rest = loser_bond.saturating_sub(juror_rewards);
+            let compensation = match &winner {
+                Some((winner, _)) => Self::pay_on_hold(&loser, winner, rest),
+                None => Zero::zero(),
+            };
+            // whatever could not be paid out, like rounding dust, goes into the court pot
+            let unpaid = rest.saturating_sub(compensation);
+            if !unpaid.is_zero() {
+                Self::pay_on_hold(&loser, &Self::pot_account(), unpaid);
+            }
+            Self::deposit_event(Event::BondsSettled {
+                content_id: content_id.clone(),
+                winner: winner.map(|(winner, _)| winner),
+                compensation,
+                juror_rewards,
+            });
+        }
+
+        /// Voids the current round of a dispute that can no longer be decided, releasing the
+        /// bonds held for it. Its jury was settled when the round closed, if it ever started.
+        /// A voided first round removes the dispute, so the content can be disputed again. A
+        /// voided appeal round closes the session with the decision it appealed.
+        fn void_dispute(content_id: &ContentId, error: DispatchError) {
+            let Some(mut session) = <Dispute<T>>::get(content_id) else {
+                return;
+            };
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(bond) = <AppealBonds<T>>::take(content_id) {
+                Self::release_bond(&bond.appellant, bond.amount);
+            }
+            <JurySummoned<T>>::remove(content_id);
+            <SummonAttempts<T>>::remove(content_id);
+            let selected = <JurySelection<T>>::take(content_id);
+            // the jury of a round that started was relieved when the round closed
+            let serving = session.ended_at.is_none().then_some(&session.jurors).into_iter().flatten();
+            for juror in selected.iter().chain(serving) {
+                Self::end_duty(juror);
+            }
+            let _ = <VoteCommitments<T>>::clear_prefix(content_id, T::MaxJurorsPerDispute::get(), None);
+            <EscalatedSession<T>>::remove(content_id);
+            <EscalatedDisputes<T>>::mutate(|disputes| disputes.retain(|disputed| disputed != content_id));
+
+            if session.round > 0 {
+                // like the jurors of earlier rounds, this jury keeps its `JuryDuty`
+                session.verdict.decision =
+                    <Decisions<T>>::get(content_id).unwrap_or(Decision::Pending);
+                session.ended_at.get_or_insert(now);
+                <Dispute<T>>::insert(content_id, &session);
+                // the appealed decision is final now, or once it can no longer be appealed
+                let _ = Self::settle_bonds_when_final(content_id);
+            } else {
+                if let Some(bond) = <DisputeBonds<T>>::take(content_id) {
+                    Self::release_bond(&bond.disputer, bond.amount);
+                    if let Some((owner, amount)) = bond.counter_bond {
+                        Self::release_bond(&owner, amount);
+                    }
+                }
+                for juror in session.jurors.iter().chain(selected.iter()) {
+                    <JuryDuty<T>>::remove(juror, content_id);
+                }
+                let parties = T::MaxWitnesses::get().saturating_add(2);
+                let _ = <SubmittedEvidence<T>>::clear_prefix(content_id, parties, None);
+                <Witnesses<T>>::remove(content_id);
+                <DisputedAt<T>>::remove(content_id);
+                <Dispute<T>>::remove(content_id);
+                T::DisputeObserver::on_dispute_voided(content_id, now);
+            }
+            Self::deposit_event(Event::DisputeVoided {
+                content_id: content_id.clone(),
+                round: session.round,
+                error,
+            });
+        }
+
+        fn release_bond(who: &T::AccountId, amount: BalanceOf<T>) {
+            let _ = <T as Config>::NativeBalance::release(
+                &HoldReason::CallCreation.into(),
+                who,
+                amount,
+                Precision::BestEffort,
+            );
+        }
+
+        /// Settles the bond of an appeal round. The bond is released if the round reversed the
+        /// appealed decision, if it upheld it the jurors who voted share all of it and the
+        /// rounding dust goes into the court pot.
+        fn settle_appeal_bond(content_id: &ContentId, decision: &Decision, voters: &[T::Did]) {
+            let Some(bond) = <AppealBonds<T>>::take(content_id) else {
+                return;
+            };
+            let upheld = &bond.appealed == decision;
+            let juror_rewards = match upheld {
+                true => Self::pay_from_bond(&bond.appellant, bond.amount, voters),
+                false => Zero::zero(),
+            };
+            let unpaid = bond.amount.saturating_sub(juror_rewards);
+            if upheld && !unpaid.is_zero() {
+                Self::pay_on_hold(&bond.appellant, &Self::pot_account(), unpaid);
+            } else if !unpaid.is_zero() {
+                let _ = <T as Config>::NativeBalance::release(
+                    &HoldReason::CallCreation.into(),
+                    &bond.appellant,
+                    unpaid,
+                    Precision::BestEffort,
+                );
+            }
+            Self::deposit_event(Event::AppealBondSettled {
+                content_id: content_id.clone(),
+                appellant: bond.appellant,
+                upheld,
+                juror_rewards,
+            });
+        }
+
+        /// Splits `pot` of the bond held from `from` evenly among the admin accounts of the
+        /// `voters`, returning how much was paid.
+        fn pay_from_bond(from: &T::AccountId, pot: BalanceOf<T>, voters: &[T::Did]) -> BalanceOf<T> {
+            let juror_accounts: Vec<T::AccountId> =
+                voters.iter().filter_map(<JurorNativeAccountAdmin<T>>::get).collect();
+            let mut paid: BalanceOf<T> = Zero::zero();
+            if !juror_accounts.is_empty() {
+                let share = pot / (juror_accounts.len() as u32).into();
+                for account in &juror_accounts {
+                    paid = paid.saturating_add(Self::pay_on_hold(from, account, share));
+                }
+            }
+            paid
+        }
+
+        fn pay_on_hold(from: &T::AccountId, to: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            <T as Config>::NativeBalance::transfer_on_hold(
+                &HoldReason::CallCreation.into(),
+                from,
+                to,
+                amount,
+                Precision::BestEffort,
+                Restriction::Free,
+                Fortitude::Polite,
+            )
+            .unwrap_or_default()
+        }
+
+        /// Stores the decision of the current round of a dispute, overriding the decisions of
+        /// earlier rounds, and tells `DisputeObserver` about it.
+        fn record_decision(content_id: &ContentId, decision: Decision, now: BlockNumberFor<T>) {
+            let verdict = match decision {
+                Decision::Convict => Some(ContextVerdict::Convicted),
+                Decision::Acquittal => Some(ContextVerdict::Acquitted),
+                Decision::Pending => None,
+            };
+            let round = <Dispute<T>>::mutate(content_id, |session| {
+                session.as_mut().map_or(0, |session| {
+                    session.verdict.decision = decision.clone();
+                    session.round
+                })
+            });
+            <RoundDecisions<T>>::insert(content_id, round, decision.clone());
+            <Decisions<T>>::insert(content_id, decision);
+            <DecidedAt<T>>::insert(content_id, now);
+            if let Some(verdict) = verdict {
+                T::DisputeObserver::on_verdict(content_id, verdict, now);
+            }
+        }
+
+        /// Block the randomness `Randomness` hands out now is known since.
+        fn randomness_known_since() -> BlockNumberFor<T> {
+            T::Randomness::random(&b"known since"[..]).1
+        }
 
-    impl<T: Config> Pallet<T> {
         /// We strongly advocate that the max number of jurors per session be 40
+        ///
+        /// Draws jurors by stake-weighted sortition until the jury of the current round is
+        /// summoned, and fails with `JuryAlreadySummoned` if it already is. Jurors are sampled
+        /// without replacement, each with a probability proportional to its stake scaled by its
+        /// reputation.
         pub fn summon_jurors(content_id: &ContentId) -> Result<(), Error<T>> {
-            use polkadot_sdk::sp_std::collections::btree_set::BTreeSet;
-
             let prev_number_of_summons = <JurySummoned<T>>::get(content_id).unwrap_or_default();
             if prev_number_of_summons > T::MaxJurorsPerDispute::get() {
                 return Err(Error::<T>::MaxJurorsReached);
             }
-            let jurors = <Jurors<T>>::get();
-            let (random_value, _) = insecure_randomness::Pallet::<T>::random(&b"summon jurors"[..]);
-            let numbers: BTreeSet<u8> = random_value.encode().into_iter().collect();
+            let session = <Dispute<T>>::get(content_id);
+            let jury_size =
+                session.as_ref().map_or_else(T::MinJurorsPerDispute::get, |session| session.jury_size);
+            let randomness_after =
+                session.map_or_else(Zero::zero, |session| session.randomness_after);
+            let wanted = jury_size
+                .saturating_sub(prev_number_of_summons)
+                .min(T::MaxJurorsPerDispute::get().saturating_sub(prev_number_of_summons));
+            ensure!(wanted > 0, Error::<T>::JuryAlreadySummoned);
+
+            let mut candidates: Vec<(T::Did, u128)> = <Jurors<T>>::get()
+                .into_iter()
+                .filter(|juror| !<JuryDuty<T>>::contains_key(juror, content_id))
+                .map(|juror| {
+                    let weight = Self::juror_weight(&juror);
+                    (juror, weight)
+                })
+                .filter(|(_, weight)| *weight > 0)
+                .collect();
+            let mut total_weight =
+                candidates.iter().fold(0u128, |total, (_, weight)| total.saturating_add(*weight));
+
+            // every dispute gets its own draw, even when several are summoned in the same block
+            let subject = (b"summon jurors", content_id).encode();
+            let (seed, known_since) = T::Randomness::random(&subject);
+            ensure!(known_since > randomness_after, Error::<T>::RandomnessUnavailable);
+            let mut nonce = 0u32;
             let mut summoned = 0;
-            for number in numbers {
-                if let Some(juror) = jurors.get(number as usize) {
-                    if !<JuryDuty<T>>::contains_key(juror, content_id) {
-                        if (prev_number_of_summons + 1) > T::MaxJurorsPerDispute::get() {
-                            break;
-                        }
-                        <JuryDuty<T>>::insert(juror, content_id, true);
-                        <JurySelection<T>>::mutate(
-                            content_id,
-                            |selected| -> Result<(), Error<T>> {
-                                selected
-                                    .try_push(juror.clone())
-                                    .map_err(|_| Error::<T>::StorageOverflow.into())?;
-                                Ok(())
-                            },
-                        )?;
-                        summoned += 1;
-                    }
-                }
+            while summoned < wanted && total_weight > 0 {
+                let mut point = Self::uniform_below(&seed, &mut nonce, total_weight);
+                let index = candidates
+                    .iter()
+                    .position(|(_, weight)| {
+                        let hit = point < *weight;
+                        point = point.saturating_sub(*weight);
+                        hit
+                    })
+                    .unwrap_or(candidates.len() - 1);
+                let (juror, weight) = candidates.swap_remove(index);
+                total_weight = total_weight.saturating_sub(weight);
+
+                <JuryDuty<T>>::insert(&juror, content_id, true);
+                <OpenDuties<T>>::mutate(&juror, |duties| *duties = duties.saturating_add(1));
+                <JurySelection<T>>::try_mutate(content_id, |selected| selected.try_push(juror))
+                    .map_err(|_| Error::<T>::StorageOverflow)?;
+                summoned += 1;
             }
-            <JurySummoned<T>>::mutate(content_id, |n| n.unwrap_or_default() + summoned);
+            <JurySummoned<T>>::insert(content_id, prev_number_of_summons + summoned);
             Ok(())
         }
+
+        /// Moves `amount` of the stake bonded by `juror` into the court pot. A juror left with
+        /// less than `HoldAmount` leaves the pool until it bonds enough again.
+        fn slash_stake(juror: &T::Did, admin: &T::AccountId, amount: BalanceOf<T>) {
+            let stake = Self::stake_of(juror);
+            let slashed = <T as Config>::NativeBalance::transfer_on_hold(
+                &HoldReason::JurorAccountCreation.into(),
+                admin,
+                &Self::pot_account(),
+                amount.min(stake),
+                Precision::BestEffort,
+                Restriction::Free,
+                Fortitude::Force,
+            )
+            .unwrap_or_default();
+            let stake = stake.saturating_sub(slashed);
+            <JurorStakes<T>>::insert(juror, stake);
+            if stake < T::HoldAmount::get() {
+                Self::leave_pool(juror);
+            }
+            Self::deposit_event(Event::JurorSlashed { did: juror.clone(), amount: slashed, stake });
+        }
+
+        fn leave_pool(juror: &T::Did) {
+            <Jurors<T>>::mutate(|jurors| jurors.retain(|did| did != juror));
+        }
+
+        /// Puts `juror` back into the pool if it bonds at least `HoldAmount`.
+        fn join_pool(juror: &T::Did) -> DispatchResult {
+            ensure!(Self::stake_of(juror) >= T::HoldAmount::get(), Error::<T>::StakeBelowMinimum);
+            <Jurors<T>>::try_mutate(|jurors| {
+                if !jurors.contains(juror) {
+                    jurors.try_push(juror.clone()).map_err(|_| Error::<T>::StorageOverflow)?;
+                }
+                Ok(())
+            })
+        }
+
+        /// Whether `juror` still has to serve on a jury, or has rewards or slashes queued.
+        fn has_open_duty(juror: &T::Did) -> bool {
+            <OpenDuties<T>>::get(juror) > 0
+                || <PendingSlashes<T>>::get().contains(juror)
+                || <PendingRewards<T>>::get().contains(juror)
+        }
+
+        /// Relieves `juror` of the jury of a session that ended or that it was excluded from.
+        fn end_duty(juror: &T::Did) {
+            <OpenDuties<T>>::mutate_exists(juror, |duties| {
+                *duties = duties.and_then(|duties| duties.checked_sub(1)).filter(|duties| *duties > 0);
+            });
+        }
+
+        /// Account of the court pot, see `Config::PalletId`.
+        pub fn pot_account() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Balance of the court pot juror rewards can be paid from, keeping the pot alive.
+        pub fn pot_balance() -> BalanceOf<T> {
+            <T::NativeBalance as fungible::Inspect<T::AccountId>>::reducible_balance(
+                &Self::pot_account(),
+                Preservation::Preserve,
+                Fortitude::Polite,
+            )
+        }
+
+        fn stake_of(juror: &T::Did) -> BalanceOf<T> {
+            <JurorStakes<T>>::get(juror).unwrap_or_else(T::HoldAmount::get)
+        }
+
+        /// Weight of `juror` in jury draws, its bonded stake scaled by its reputation.
+        fn juror_weight(juror: &T::Did) -> u128 {
+            let stake: u128 = Self::stake_of(juror).unique_saturated_into();
+            T::JurorReputation::reputation(juror).mul_floor(stake)
+        }
+
+        /// A number drawn uniformly from `0..bound`. Values that would make some results more
+        /// likely than others under the modulo are rejected and drawn again.
+        fn uniform_below(seed: &T::Hash, nonce: &mut u32, bound: u128) -> u128 {
+            let threshold = bound.wrapping_neg() % bound;
+            loop {
+                let hash = (seed, *nonce).using_encoded(frame::hashing::blake2_256);
+                *nonce += 1;
+                let mut bytes = [0u8; 16];
+                bytes.copy_from_slice(&hash[..16]);
+                let value = u128::from_le_bytes(bytes);
+                if value >= threshold {
+                    return value % bound;
+                }
+            }
+        }
     }
 }
 
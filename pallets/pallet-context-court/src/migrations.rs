@@ -0,0 +1,211 @@
+//! Storage migrations of the context court pallet.
+
+/// Identifier of the context court migrations in `pallet-migrations`.
+pub const PALLET_MIGRATIONS_ID: &[u8; 24] = b"pallet-context-court-mbm";
+
+pub mod v1 {
+    //! Moves the court off the storage layout it had before content ids became multihash
+    //! digests and sessions gained phases, bonds, rounds and categories.
+    //!
+    //! Open sessions of that layout cannot be resumed: they hold no dispute bond, have no
+    //! evidence period and were voted on in the clear. They are dropped together with their
+    //! jury bookkeeping; nothing was held for them, so no funds are affected. Decisions already
+    //! reached are kept under the content id the content registry migrates the decided proof
+    //! to, which it only knows until its own migration moved the proof, so this migration has
+    //! to run first. Decisions on content without a proof are dropped. Jurors, their admins and
+    //! the pending reward and slash queues did not change layout and are left as they are.
+    //!
+    //! The migration is stepped: each step removes or moves as many entries as the weight meter
+    //! allows and resumes from the phase it stopped in.
+
+    use super::PALLET_MIGRATIONS_ID;
+    use crate::{Config, Decision, Decisions, Pallet};
+    use frame::prelude::*;
+    use polkadot_sdk::frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        storage::{unhashed, StoragePrefixedMap},
+        storage_alias,
+        weights::WeightMeter,
+    };
+    use polkadot_sdk::sp_io;
+    #[cfg(feature = "try-runtime")]
+    use polkadot_sdk::sp_std::vec::Vec;
+    use shared::{traits::content::ContentInspector, types::ContentId};
+
+    /// The storage layout before version 1.
+    pub mod old {
+        use super::*;
+
+        /// Content id before it became a multihash digest: a `cid:` tag and the blake2-256
+        /// hash of the encoded content.
+        #[derive(Encode, Decode, MaxEncodedLen)]
+        pub(crate) struct OldContentId {
+            prefix: [u8; 4],
+            pub(crate) hash: [u8; 32],
+        }
+
+        #[storage_alias]
+        pub type Decisions<T: Config> =
+            StorageMap<Pallet<T>, Blake2_128Concat, OldContentId, Decision, OptionQuery>;
+
+        #[storage_alias]
+        pub type Dispute<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, OldContentId, ()>;
+
+        #[storage_alias]
+        pub type EscalatedSession<T: Config> =
+            StorageMap<Pallet<T>, Blake2_128Concat, OldContentId, ()>;
+
+        #[storage_alias]
+        pub type EscalatedDisputes<T: Config> = StorageValue<Pallet<T>, ()>;
+
+        #[storage_alias]
+        pub type JurySummoned<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, OldContentId, ()>;
+
+        #[storage_alias]
+        pub type JurySelection<T: Config> =
+            StorageMap<Pallet<T>, Blake2_128Concat, OldContentId, ()>;
+
+        #[storage_alias]
+        pub type JuryDuty<T: Config> = StorageDoubleMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as Config>::Did,
+            Blake2_128Concat,
+            OldContentId,
+            (),
+        >;
+    }
+
+    /// Decisions taken off the old `Decisions` keys, under their new content id. They are put
+    /// back once every old key is gone, since old and new keys share the map prefix.
+    #[storage_alias]
+    pub type MigratingDecisions<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, ContentId, Decision, OptionQuery>;
+
+    /// Progress of the migration.
+    #[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+    pub enum Cursor {
+        /// Removing the entries of the old session map at this position of `session_prefixes`.
+        ClearSessions(u8),
+        /// Taking the decisions off their old keys.
+        TakeDecisions,
+        /// Putting the decisions back under their new keys.
+        PutDecisions,
+    }
+
+    /// Storage prefixes of the old open session and jury bookkeeping maps.
+    fn session_prefixes<T: Config>() -> [[u8; 32]; 5] {
+        [
+            old::Dispute::<T>::final_prefix(),
+            old::EscalatedSession::<T>::final_prefix(),
+            old::JurySummoned::<T>::final_prefix(),
+            old::JurySelection::<T>::final_prefix(),
+            old::JuryDuty::<T>::final_prefix(),
+        ]
+    }
+
+    /// Drops the open sessions of the old layout and re-keys their decisions, in as many steps
+    /// as `pallet-migrations` needs. Only runs on a version 0 court.
+    pub struct LazyMigrationV1<T>(PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for LazyMigrationV1<T> {
+        type Cursor = Cursor;
+        type Identifier = MigrationId<24>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
+        }
+
+        fn step(
+            cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+                return Ok(None);
+            }
+
+            // moving a decision reads the decided proof from the registry as well
+            let required = T::DbWeight::get().reads_writes(2, 2);
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            let mut cursor = cursor.unwrap_or(Cursor::ClearSessions(0));
+            loop {
+                match cursor {
+                    Cursor::ClearSessions(map) => {
+                        let Some(prefix) = session_prefixes::<T>().get(map as usize).copied()
+                        else {
+                            old::EscalatedDisputes::<T>::kill();
+                            cursor = Cursor::TakeDecisions;
+                            continue;
+                        };
+                        loop {
+                            if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+                                return Ok(Some(cursor));
+                            }
+                            match sp_io::storage::next_key(&prefix)
+                                .filter(|key| key.starts_with(&prefix))
+                            {
+                                Some(key) => unhashed::kill(&key),
+                                None => break,
+                            }
+                        }
+                        cursor = Cursor::ClearSessions(map.saturating_add(1));
+                    },
+                    Cursor::TakeDecisions => loop {
+                        if meter.try_consume(required).is_err() {
+                            return Ok(Some(cursor));
+                        }
+                        // only old keys are left in the map, so draining takes the next one
+                        let Some((old_id, decision)) = old::Decisions::<T>::drain().next() else {
+                            cursor = Cursor::PutDecisions;
+                            break;
+                        };
+                        match T::ContentInspector::legacy_content_id(&old_id.hash) {
+                            Some(content_id) => MigratingDecisions::<T>::insert(content_id, decision),
+                            None => frame::log::warn!(
+                                target: "runtime::context-court",
+                                "dropping decision on content without a proof"
+                            ),
+                        }
+                    },
+                    Cursor::PutDecisions => loop {
+                        if meter.try_consume(T::DbWeight::get().reads_writes(1, 2)).is_err() {
+                            return Ok(Some(cursor));
+                        }
+                        let Some((content_id, decision)) = MigratingDecisions::<T>::drain().next()
+                        else {
+                            StorageVersion::new(1).put::<Pallet<T>>();
+                            return Ok(None);
+                        };
+                        Decisions::<T>::insert(content_id, decision);
+                    },
+                }
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame::deps::sp_runtime::TryRuntimeError> {
+            let kept = old::Decisions::<T>::iter_keys()
+                .filter(|old_id| T::ContentInspector::legacy_content_id(&old_id.hash).is_some())
+                .count() as u64;
+            Ok(kept.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame::deps::sp_runtime::TryRuntimeError> {
+            let kept = u64::decode(&mut &state[..])
+                .map_err(|_| "context court v1: cannot decode pre-upgrade state")?;
+            ensure!(
+                MigratingDecisions::<T>::iter_keys().next().is_none(),
+                "context court v1: decisions left behind"
+            );
+            ensure!(
+                Decisions::<T>::iter_keys().count() as u64 == kept,
+                "context court v1: decision count mismatch"
+            );
+            Ok(())
+        }
+    }
+}
@@ -1,11 +1,18 @@
 use frame::{
-    deps::{frame_support::weights::constants::RocksDbWeight},
+    deps::frame_support::{traits::Randomness, weights::constants::RocksDbWeight},
     prelude::*,
     runtime::prelude::*,
     testing_prelude::*,
 };
-use polkadot_sdk::{pallet_balances, sp_io};
-use shared::types::BaseRight;
+use polkadot_sdk::{frame_support::PalletId, pallet_balances, sp_io};
+use crate::CategoryParams;
+use shared::{
+    traits::{
+        content::ContentInspector,
+        context::{DisputeObserver, JurorReputation},
+    },
+    types::{BaseRight, ContentId, ContextVerdict},
+};
 
 type Balance = u128;
 // Configure a mock runtime to test the pallet.
@@ -34,8 +41,6 @@ mod test_runtime {
     pub type IdentityPallet = pallet_identity_registry;
     #[runtime::pallet_index(3)]
     pub type Balances = pallet_balances;
-    #[runtime::pallet_index(4)]
-    pub type Randomness = polkadot_sdk::pallet_insecure_randomness_collective_flip;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -60,8 +65,6 @@ impl pallet_identity_registry::Config for Test {
     type RuntimeHoldReason = RuntimeHoldReason;
 }
 
-impl polkadot_sdk::pallet_insecure_randomness_collective_flip::Config for Test {}
-
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
 impl pallet_balances::Config for Test {
     type Balance = Balance;
@@ -78,26 +81,136 @@ impl pallet_balances::Config for Test {
     type MaxFreezes = ConstU32<10>;
 }
 
+parameter_types! {
+    pub static RegisteredContents: Vec<ContentId> = vec![];
+    pub static LegacyContentIds: Vec<([u8; 32], ContentId)> = vec![];
+    pub static ObservedDisputes: Vec<(ContentId, u64, Option<ContextVerdict>)> = vec![];
+    pub static VoidedDisputes: Vec<(ContentId, u64)> = vec![];
+    pub static RelayRandomness: [u8; 32] = [0; 32];
+    pub static RelayRandomnessMissing: bool = false;
+    pub static MaxSummonAttempts: u32 = 10;
+    pub const CourtPalletId: PalletId = PalletId(*b"py/court");
+    pub static JurorReputations: Vec<(BoundedVec<u8, ConstU32<1024>>, Perbill)> = vec![];
+    pub static ContentOwner: BoundedVec<u8, ConstU32<1024>> = BoundedVec::default();
+    pub const JurorBondShare: Perbill = Perbill::from_percent(50);
+    // votes close in block 100 for disputes opened in block 1
+    pub const DefaultCategoryParams: CategoryParams<u64> = CategoryParams {
+        jury_size: 10,
+        voting_period: 94,
+        quorum: Perbill::from_percent(20),
+        supermajority: Perbill::from_percent(60),
+    };
+}
+
+/// Stands in for relay-chain VRF randomness: the output only depends on the subject and
+/// `RelayRandomness`, never on the block hashes of this chain. It is known since the current
+/// block, as the runtime's is since its relay parent. With `RelayRandomnessMissing` it is known
+/// since genesis, like the runtime's before the validation data inherent.
+pub struct MockRandomness;
+impl Randomness<H256, u64> for MockRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        if RelayRandomnessMissing::get() {
+            return (H256::zero(), 0);
+        }
+        let seed = (subject, RelayRandomness::get()).using_encoded(sp_io::hashing::blake2_256);
+        (seed.into(), System::block_number())
+    }
+}
+
+/// Stands in for the content registry: content in `RegisteredContents` is registered, by
+/// `ContentOwner` and the default device, in block 1. `LegacyContentIds` holds the proofs of
+/// the old layout it has yet to migrate.
+pub struct MockContentInspector;
+impl ContentInspector<BoundedVec<u8, ConstU32<1024>>, BoundedVec<u8, ConstU32<1024>>, u64>
+    for MockContentInspector
+{
+    fn owner(content_id: &ContentId) -> Option<BoundedVec<u8, ConstU32<1024>>> {
+        RegisteredContents::get().contains(content_id).then(ContentOwner::get)
+    }
+
+    fn device(content_id: &ContentId) -> Option<BoundedVec<u8, ConstU32<1024>>> {
+        RegisteredContents::get().contains(content_id).then(Default::default)
+    }
+
+    fn registered_at(content_id: &ContentId) -> Option<u64> {
+        RegisteredContents::get().contains(content_id).then_some(1)
+    }
+
+    fn legacy_content_id(hash: &[u8; 32]) -> Option<ContentId> {
+        LegacyContentIds::get()
+            .into_iter()
+            .find_map(|(old_hash, content_id)| (old_hash == *hash).then_some(content_id))
+    }
+}
+
+/// Records what the court reports in `ObservedDisputes` and `VoidedDisputes`.
+pub struct MockDisputeObserver;
+impl DisputeObserver<u64> for MockDisputeObserver {
+    fn on_dispute_opened(content_id: &ContentId, at: u64) {
+        ObservedDisputes::mutate(|observed| observed.push((content_id.clone(), at, None)));
+    }
+
+    fn on_verdict(content_id: &ContentId, verdict: ContextVerdict, at: u64) {
+        ObservedDisputes::mutate(|observed| observed.push((content_id.clone(), at, Some(verdict))));
+    }
+
+    fn on_dispute_voided(content_id: &ContentId, at: u64) {
+        VoidedDisputes::mutate(|voided| voided.push((content_id.clone(), at)));
+    }
+}
+
+/// Jurors in `JurorReputations` have the reputation given there, everyone else full reputation.
+pub struct MockJurorReputation;
+impl JurorReputation<BoundedVec<u8, ConstU32<1024>>> for MockJurorReputation {
+    fn reputation(did: &BoundedVec<u8, ConstU32<1024>>) -> Perbill {
+        JurorReputations::get()
+            .into_iter()
+            .find_map(|(juror, reputation)| (&juror == did).then_some(reputation))
+            .unwrap_or(Perbill::one())
+    }
+}
+
 impl crate::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type Did = BoundedVec<u8, ConstU32<1024>>;
-    type MaxJurors = ConstU32<100>;
+    type MaxJurors = ConstU32<1000>;
     type MaxJurorsPerDispute = ConstU32<30>;
     type GivenRight = BaseRight;
     type Device = BoundedVec<u8, ConstU32<1024>>;
     type DidRegistry = pallet_identity_registry::Pallet<Test>;
+    type ContentInspector = MockContentInspector;
+    type DisputeObserver = MockDisputeObserver;
+    type Randomness = MockRandomness;
+    type JurorReputation = MockJurorReputation;
     type NativeBalance = Balances;
     type ExclusionFee = ConstU128<100>;
+    type PalletId = CourtPalletId;
+    type DisputeBondAmount = ConstU128<500>;
+    type JurorBondShare = JurorBondShare;
+    type AppealBondAmount = ConstU128<800>;
+    type AppealPeriod = ConstU64<20>;
+    type MaxAppealRounds = ConstU32<2>;
     type RuntimeHoldReason = RuntimeHoldReason;
     type MinJurorsPerDispute = ConstU32<10>;
+    type DefaultCategoryParams = DefaultCategoryParams;
+    type CategoryOrigin = EnsureRoot<u64>;
     type MaxContextLength = ConstU32<1000>;
+    type EvidencePeriod = ConstU64<5>;
+    type MaxEvidencePerParty = ConstU32<3>;
+    type MaxWitnesses = ConstU32<2>;
+    type MaxIpfsCidLength = ConstU32<64>;
     type HoldAmount = ConstU128<1000>;
+    type UnbondingPeriod = ConstU64<50>;
     type RewardAmount = ConstU128<200>;
     type SlashAmount = ConstU128<200>;
     type EscalatedVotingPeriod = ConstU64<100000>;
+    type RevealPeriod = ConstU64<10>;
     type MaxRewardsNumber = ConstU32<1000>;
     type BatchRewardSize = ConstU32<10>;
+    type MaxDeadlinesPerBlock = ConstU32<100>;
+    type MaxCourtActionsPerBlock = ConstU32<2>;
+    type MaxSummonAttempts = MaxSummonAttempts;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -171,6 +171,20 @@ pub mod pallet {
     pub type DidDevices<T: Config> =
         StorageMap<_, Blake2_128Concat, T::Did, BoundedVec<T::Device, T::MaxKeySize>, OptionQuery>;
 
+    /// Devices a DID reported as compromised, and the block they were revoked in. Content
+    /// registered from them should no longer be trusted.
+    #[pallet::storage]
+    #[pallet::getter(fn device_revoked_at)]
+    pub type RevokedDevices<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::Did,
+        Blake2_128Concat,
+        T::Device,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
     /// Pallets use events to inform users when important changes are made.
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -205,6 +219,12 @@ pub mod pallet {
             did: T::Did,
             device: T::Device,
         },
+        DeviceRevoked {
+            block_number: BlockNumberFor<T>,
+            who: T::AccountId,
+            did: T::Did,
+            device: T::Device,
+        },
     }
 
     /// Errors inform users that something went wrong.
@@ -222,6 +242,10 @@ pub mod pallet {
         DidAlreadyExists,
         /// Too many devices for a DID
         TooManyDevices,
+        /// Device is not registered for the DID
+        DeviceNotFound,
+        /// Device was revoked and cannot be registered again
+        DeviceRevoked,
     }
 
     #[pallet::hooks]
@@ -350,6 +374,10 @@ pub mod pallet {
                 Self::is_valid_signatory(&did, &who, &T::GivenRight::from(BaseRight::Update)),
                 Error::<T>::SignerDoesNotHaveRight
             );
+            ensure!(
+                !RevokedDevices::<T>::contains_key(&did, &device),
+                Error::<T>::DeviceRevoked
+            );
             // DidDevices::<T>::try_mutate(&did, |devices| -> DispatchResult {
             //     devices
             //         .take()
@@ -400,6 +428,36 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Removes a device whose keys leaked or that was tampered with. Unlike
+        /// `remove_device`, the revocation is recorded, content registered from the device is
+        /// reported as compromised and the device cannot be registered again.
+        #[pallet::call_index(5)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(3, 2))]
+        pub fn revoke_device(
+            origin: OriginFor<T>,
+            did: T::Did,
+            device: T::Device,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Self::is_valid_signatory(&did, &who, &T::GivenRight::from(BaseRight::Update)),
+                Error::<T>::SignerDoesNotHaveRight
+            );
+            DidDevices::<T>::try_mutate(&did, |devices| -> DispatchResult {
+                let devices = devices.as_mut().ok_or(Error::<T>::DeviceNotFound)?;
+                let index = devices
+                    .iter()
+                    .position(|d| d == &device)
+                    .ok_or(Error::<T>::DeviceNotFound)?;
+                devices.remove(index);
+                Ok(())
+            })?;
+            let block_number = <frame_system::Pallet<T>>::block_number();
+            RevokedDevices::<T>::insert(&did, &device, block_number);
+            Self::deposit_event(Event::DeviceRevoked { block_number, who, did, device });
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
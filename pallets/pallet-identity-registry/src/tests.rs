@@ -687,6 +687,73 @@ fn should_remove_device_that_does_not_exist() {
     });
 }
 
+#[test]
+fn should_revoke_device() {
+    new_test_ext().execute_with(|| {
+        let origin = RuntimeOrigin::signed(ALICE);
+        let did = BoundedVec::try_from(DID.to_vec()).unwrap();
+        let signatories = BoundedVec::try_from(vec![BOB]).unwrap();
+        let device1 = BoundedVec::try_from(DEVICE1.to_vec()).unwrap();
+        let device2 = BoundedVec::try_from(DEVICE2.to_vec()).unwrap();
+
+        assert_ok!(PalletIndentity::create_did(origin.clone(), did.clone(), signatories));
+        assert_ok!(PalletIndentity::register_device(origin.clone(), did.clone(), device1.clone()));
+        assert_ok!(PalletIndentity::register_device(origin.clone(), did.clone(), device2.clone()));
+
+        System::set_block_number(5);
+        assert_ok!(PalletIndentity::revoke_device(origin, did.clone(), device1.clone()));
+
+        assert_eq!(PalletIndentity::get_did_devices(&did).unwrap().to_vec(), vec![device2.clone()]);
+        assert_eq!(PalletIndentity::device_revoked_at(&did, &device1), Some(5));
+        assert_eq!(PalletIndentity::device_revoked_at(&did, &device2), None);
+        System::assert_last_event(
+            Event::DeviceRevoked { block_number: 5, who: ALICE, did, device: device1 }.into(),
+        );
+    });
+}
+
+#[test]
+fn should_not_register_revoked_device_again() {
+    new_test_ext().execute_with(|| {
+        let origin = RuntimeOrigin::signed(ALICE);
+        let did = BoundedVec::try_from(DID.to_vec()).unwrap();
+        let signatories = BoundedVec::try_from(vec![BOB]).unwrap();
+        let device = BoundedVec::try_from(DEVICE1.to_vec()).unwrap();
+
+        assert_ok!(PalletIndentity::create_did(origin.clone(), did.clone(), signatories));
+        assert_ok!(PalletIndentity::register_device(origin.clone(), did.clone(), device.clone()));
+        assert_ok!(PalletIndentity::revoke_device(origin.clone(), did.clone(), device.clone()));
+
+        assert_noop!(
+            PalletIndentity::register_device(origin, did, device),
+            Error::<Test>::DeviceRevoked
+        );
+    });
+}
+
+#[test]
+fn should_fail_to_revoke_unknown_device_or_without_permission() {
+    new_test_ext().execute_with(|| {
+        let origin = RuntimeOrigin::signed(ALICE);
+        let did = BoundedVec::try_from(DID.to_vec()).unwrap();
+        let signatories = BoundedVec::try_from(vec![BOB]).unwrap();
+        let device1 = BoundedVec::try_from(DEVICE1.to_vec()).unwrap();
+        let device2 = BoundedVec::try_from(DEVICE2.to_vec()).unwrap();
+
+        assert_ok!(PalletIndentity::create_did(origin.clone(), did.clone(), signatories));
+        assert_ok!(PalletIndentity::register_device(origin.clone(), did.clone(), device1.clone()));
+
+        assert_noop!(
+            PalletIndentity::revoke_device(origin, did.clone(), device2),
+            Error::<Test>::DeviceNotFound
+        );
+        assert_noop!(
+            PalletIndentity::revoke_device(RuntimeOrigin::signed(BOB), did, device1),
+            Error::<Test>::SignerDoesNotHaveRight
+        );
+    });
+}
+
 // ============ Permission Validation Tests ============
 
 #[test]
@@ -1 +1,26 @@
+use crate::types::ContentId;
 
+/// Read access to the proofs of the content registry, for pallets that act on registered
+/// content.
+pub trait ContentInspector<Did, Device, BlockNumber> {
+    /// Whether a proof is registered under `content_id`.
+    fn exists(content_id: &ContentId) -> bool {
+        Self::owner(content_id).is_some()
+    }
+
+    /// DID that registered the proof.
+    fn owner(content_id: &ContentId) -> Option<Did>;
+
+    /// Device the content was captured with.
+    fn device(content_id: &ContentId) -> Option<Device>;
+
+    /// Block the proof exists from.
+    fn registered_at(content_id: &ContentId) -> Option<BlockNumber>;
+
+    /// Id the proof registered under the blake2-256 `hash` of its content, before content ids
+    /// became multihash digests, is stored under once the registry migrated it. Only known
+    /// until the registry migration moved the proof.
+    fn legacy_content_id(_hash: &[u8; 32]) -> Option<ContentId> {
+        None
+    }
+}
@@ -1 +1,37 @@
+use crate::types::{ContentId, ContextVerdict};
+use frame::arithmetic::Perbill;
 
+/// Told by the context court about disputes over registered content.
+pub trait DisputeObserver<BlockNumber> {
+    /// A dispute over `content_id` was opened in block `at`.
+    fn on_dispute_opened(content_id: &ContentId, at: BlockNumber);
+
+    /// The dispute over `content_id` was decided in block `at`.
+    fn on_verdict(content_id: &ContentId, verdict: ContextVerdict, at: BlockNumber);
+
+    /// The dispute over `content_id` was voided in block `at` without reaching a verdict, the
+    /// content can be disputed again.
+    fn on_dispute_voided(content_id: &ContentId, at: BlockNumber);
+}
+
+impl<BlockNumber> DisputeObserver<BlockNumber> for () {
+    fn on_dispute_opened(_content_id: &ContentId, _at: BlockNumber) {}
+
+    fn on_verdict(_content_id: &ContentId, _verdict: ContextVerdict, _at: BlockNumber) {}
+
+    fn on_dispute_voided(_content_id: &ContentId, _at: BlockNumber) {}
+}
+
+/// How far the context court should trust a juror, scaling the weight of its bonded stake in
+/// jury draws.
+pub trait JurorReputation<Did> {
+    /// `Perbill::one()` counts the full stake, `Perbill::zero()` keeps `did` out of juries.
+    fn reputation(did: &Did) -> Perbill;
+}
+
+/// Every juror is drawn by stake alone.
+impl<Did> JurorReputation<Did> for () {
+    fn reputation(_did: &Did) -> Perbill {
+        Perbill::one()
+    }
+}
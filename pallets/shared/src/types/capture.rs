@@ -0,0 +1,127 @@
+use frame::prelude::*;
+
+/// Longest MIME type accepted in capture metadata.
+pub const MAX_MIME_TYPE_LENGTH: u32 = 64;
+/// Longest camera make, model or sensor name.
+pub const MAX_CAMERA_FIELD_LENGTH: u32 = 64;
+/// Longest capture software version string.
+pub const MAX_SOFTWARE_VERSION_LENGTH: u32 = 64;
+/// Longest free-form metadata of proofs registered before capture metadata was versioned.
+pub const MAX_LEGACY_METADATA_LENGTH: u32 = 100;
+
+/// Latitudes and longitudes are stored in hundredths of a degree, about 1.1 km at the equator.
+pub const GEO_PRECISION: i32 = 100;
+
+/// MIME types of the media a proof can be registered for.
+pub const KNOWN_MIME_TYPES: &[&str] = &[
+    "image/jpeg",
+    "image/png",
+    "image/heic",
+    "image/heif",
+    "image/webp",
+    "image/avif",
+    "image/tiff",
+    "image/x-adobe-dng",
+    "video/mp4",
+    "video/quicktime",
+    "video/webm",
+    "audio/mpeg",
+    "audio/mp4",
+    "audio/wav",
+    "audio/flac",
+    "audio/ogg",
+];
+
+/// Whether `mime_type` is one of [`KNOWN_MIME_TYPES`]. MIME types are matched as given, so
+/// clients are expected to send them in lowercase.
+pub fn is_known_mime_type(mime_type: &[u8]) -> bool {
+    KNOWN_MIME_TYPES.iter().any(|known| known.as_bytes() == mime_type)
+}
+
+/// Coarse location of a capture, in hundredths of a degree.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug,
+)]
+pub struct GeoLocation {
+    /// `-9000..=9000`
+    pub latitude: i16,
+    /// `-18000..=18000`
+    pub longitude: i16,
+}
+
+impl GeoLocation {
+    pub fn is_valid(&self) -> bool {
+        i32::from(self.latitude).abs() <= 90 * GEO_PRECISION
+            && i32::from(self.longitude).abs() <= 180 * GEO_PRECISION
+    }
+}
+
+/// The camera or sensor that produced the media, as reported by the device.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug,
+)]
+pub struct CameraInfo {
+    pub make: BoundedVec<u8, ConstU32<MAX_CAMERA_FIELD_LENGTH>>,
+    pub model: BoundedVec<u8, ConstU32<MAX_CAMERA_FIELD_LENGTH>>,
+    pub sensor: Option<BoundedVec<u8, ConstU32<MAX_CAMERA_FIELD_LENGTH>>>,
+}
+
+/// First version of the capture metadata attached to a proof.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug,
+)]
+pub struct CaptureMetadataV1 {
+    /// One of [`KNOWN_MIME_TYPES`]
+    pub mime_type: BoundedVec<u8, ConstU32<MAX_MIME_TYPE_LENGTH>>,
+    /// When the device claims the media was captured, in milliseconds since the unix epoch
+    pub captured_at: u64,
+    pub location: Option<GeoLocation>,
+    pub camera: Option<CameraInfo>,
+    /// Version of the capture app or firmware
+    pub software_version: Option<BoundedVec<u8, ConstU32<MAX_SOFTWARE_VERSION_LENGTH>>>,
+}
+
+/// Capture metadata of a proof. New versions are added as new variants, so proofs registered
+/// under an older version keep decoding.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug,
+)]
+pub enum CaptureMetadata {
+    /// Free-form bytes of a proof registered before capture metadata was versioned. Only
+    /// produced by the storage migration, it is never accepted for new proofs.
+    #[codec(index = 0)]
+    Legacy(BoundedVec<u8, ConstU32<MAX_LEGACY_METADATA_LENGTH>>),
+    #[codec(index = 1)]
+    V1(CaptureMetadataV1),
+}
+
+impl CaptureMetadata {
+    /// Version number of the metadata layout.
+    pub fn version(&self) -> u8 {
+        match self {
+            CaptureMetadata::Legacy(_) => 0,
+            CaptureMetadata::V1(_) => 1,
+        }
+    }
+
+    pub fn mime_type(&self) -> &[u8] {
+        match self {
+            CaptureMetadata::Legacy(_) => &[],
+            CaptureMetadata::V1(metadata) => &metadata.mime_type,
+        }
+    }
+
+    pub fn captured_at(&self) -> u64 {
+        match self {
+            CaptureMetadata::Legacy(_) => 0,
+            CaptureMetadata::V1(metadata) => metadata.captured_at,
+        }
+    }
+
+    pub fn location(&self) -> Option<&GeoLocation> {
+        match self {
+            CaptureMetadata::Legacy(_) => None,
+            CaptureMetadata::V1(metadata) => metadata.location.as_ref(),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use frame::prelude::*;
+
+/// Longest SPDX license identifier accepted on chain.
+pub const MAX_LICENSE_ID_LENGTH: u32 = 32;
+
+/// SPDX identifiers content can be licensed under. Creative Commons licenses are listed under
+/// their SPDX identifiers.
+pub const KNOWN_LICENSE_IDS: &[&str] = &[
+    "CC0-1.0",
+    "CC-BY-4.0",
+    "CC-BY-SA-4.0",
+    "CC-BY-ND-4.0",
+    "CC-BY-NC-4.0",
+    "CC-BY-NC-SA-4.0",
+    "CC-BY-NC-ND-4.0",
+    "CC-BY-3.0",
+    "CC-BY-SA-3.0",
+    "CC-BY-NC-3.0",
+    "CC-BY-NC-SA-3.0",
+    "CC-BY-NC-ND-3.0",
+    "CC-PDDC",
+    "ODbL-1.0",
+    "MIT",
+    "Apache-2.0",
+];
+
+/// Whether `id` is one of [`KNOWN_LICENSE_IDS`]. Identifiers are matched as given, in the case
+/// the SPDX list uses.
+pub fn is_known_license_id(id: &[u8]) -> bool {
+    KNOWN_LICENSE_IDS.iter().any(|known| known.as_bytes() == id)
+}
+
+/// Terms content may be used under.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug,
+)]
+pub enum License {
+    /// No use without a license bought from the owner
+    #[codec(index = 0)]
+    AllRightsReserved,
+    /// One of [`KNOWN_LICENSE_IDS`]
+    #[codec(index = 1)]
+    Spdx(BoundedVec<u8, ConstU32<MAX_LICENSE_ID_LENGTH>>),
+    /// Blake2-256 hash of a license text kept off chain
+    #[codec(index = 2)]
+    Custom([u8; 32]),
+}
+
+impl License {
+    /// Whether the license is one the chain knows how to name. Custom licenses are always
+    /// accepted, as only their hash is known.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            License::Spdx(id) => is_known_license_id(id),
+            License::AllRightsReserved | License::Custom(_) => true,
+        }
+    }
+}
@@ -1,4 +1,12 @@
+use frame::deps::sp_io;
 use frame::prelude::*;
+use polkadot_sdk::sp_std::vec::Vec;
+use scale_info::prelude::string::String;
+
+mod capture;
+mod license;
+pub use capture::*;
+pub use license::*;
 
 #[derive(
     Encode,
@@ -17,6 +25,232 @@ pub enum BaseRight {
     Update,
     Impersonate,
     Dispute,
+    /// Co-attest content registered by other DIDs
+    Attest,
+}
+
+/// What a third-party DID vouches for when it attests content.
+#[derive(
+    Encode,
+    Decode,
+    TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    DecodeWithMemTracking,
+    Eq,
+    MaxEncodedLen,
+    Debug,
+)]
+pub enum AttestationKind {
+    /// An editor reviewed the content before publication
+    #[codec(index = 0)]
+    Editorial,
+    /// A fact-checker verified what the content shows
+    #[codec(index = 1)]
+    FactCheck,
+    /// The attester was present when the content was captured
+    #[codec(index = 2)]
+    Witness,
+}
+
+/// Label the content registry attaches to content because of a court outcome.
+#[derive(
+    Encode,
+    Decode,
+    TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    DecodeWithMemTracking,
+    Eq,
+    MaxEncodedLen,
+    Debug,
+)]
+pub enum LabelKind {
+    /// The court found the content was published in a misleading context
+    #[codec(index = 0)]
+    MisleadingContext,
+}
+
+/// What the registry knows about a `ContentId`, as answered to other chains.
+#[derive(
+    Encode,
+    Decode,
+    TypeInfo,
+    Clone,
+    PartialEq,
+    DecodeWithMemTracking,
+    Eq,
+    MaxEncodedLen,
+    Debug,
+)]
+pub struct ProofStatus<BlockNumber> {
+    /// Block the proof exists from, `None` if the content is not registered
+    pub exists_from: Option<BlockNumber>,
+    /// The content is under dispute, or was found to be misleading
+    pub disputed: bool,
+}
+
+impl<BlockNumber> ProofStatus<BlockNumber> {
+    /// Whether the content is registered and not disputed.
+    pub fn is_trusted(&self) -> bool {
+        self.exists_from.is_some() && !self.disputed
+    }
+}
+
+/// Outcome of a dispute over the context content was published in.
+#[derive(
+    Encode,
+    Decode,
+    TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    DecodeWithMemTracking,
+    Eq,
+    MaxEncodedLen,
+    Debug,
+)]
+pub enum ContextVerdict {
+    /// The content was found to be published in a misleading context
+    Convicted,
+    /// The dispute was rejected
+    Acquitted,
+}
+
+/// Evidence submitted to the court. Only a reference is kept on chain, the evidence itself
+/// lives off chain.
+#[derive(
+    Encode,
+    Decode,
+    TypeInfo,
+    Clone,
+    PartialEq,
+    DecodeWithMemTracking,
+    Eq,
+    MaxEncodedLen,
+    Debug,
+)]
+pub enum Evidence<Cid> {
+    /// Content registered in the content registry
+    #[codec(index = 0)]
+    ContentHash(ContentId),
+    /// A file on IPFS, by its multibase CID string
+    #[codec(index = 1)]
+    IpfsCid(Cid),
+    /// Blake2-256 hash of a written statement
+    #[codec(index = 2)]
+    TextHash([u8; 32]),
+}
+
+/// The side of a dispute that submitted evidence.
+#[derive(
+    Encode,
+    Decode,
+    TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    DecodeWithMemTracking,
+    Eq,
+    MaxEncodedLen,
+    Debug,
+)]
+pub enum EvidenceParty {
+    /// The DID that opened the dispute
+    #[codec(index = 0)]
+    Disputer,
+    /// The DID that registered the disputed content
+    #[codec(index = 1)]
+    Owner,
+    /// A DID invited by either of them
+    #[codec(index = 2)]
+    Witness,
+}
+
+/// CID version written in front of every serialized `ContentId`.
+pub const CID_VERSION: u64 = 0x01;
+/// Multicodec for raw binary content, which is what IPFS uses for single-block files.
+pub const RAW_CODEC: u64 = 0x55;
+/// Length in bytes of every digest supported by `ContentId`.
+pub const DIGEST_LENGTH: usize = 32;
+
+/// Hash functions a client may use to compute a `ContentId`.
+///
+/// Each variant maps to its code in the multihash table, so the id can be
+/// rendered as a CIDv1 that IPFS and C2PA tooling understands.
+#[derive(
+    Encode,
+    Decode,
+    TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    DecodeWithMemTracking,
+    Default,
+    Eq,
+    MaxEncodedLen,
+    Debug,
+)]
+pub enum HashAlgorithm {
+    #[default]
+    #[codec(index = 0)]
+    Blake2b256,
+    #[codec(index = 1)]
+    Sha2_256,
+    #[codec(index = 2)]
+    Blake3,
+}
+
+impl HashAlgorithm {
+    /// The multihash code of the algorithm.
+    pub fn multihash_code(&self) -> u64 {
+        match self {
+            HashAlgorithm::Blake2b256 => 0xb220,
+            HashAlgorithm::Sha2_256 => 0x12,
+            HashAlgorithm::Blake3 => 0x1e,
+        }
+    }
+
+    pub fn from_multihash_code(code: u64) -> Option<Self> {
+        match code {
+            0xb220 => Some(HashAlgorithm::Blake2b256),
+            0x12 => Some(HashAlgorithm::Sha2_256),
+            0x1e => Some(HashAlgorithm::Blake3),
+            _ => None,
+        }
+    }
+
+    /// Hashes `data` with the algorithm, producing the digest a `ContentId` is built from.
+    pub fn digest(&self, data: &[u8]) -> [u8; DIGEST_LENGTH] {
+        match self {
+            HashAlgorithm::Blake2b256 => sp_io::hashing::blake2_256(data),
+            HashAlgorithm::Sha2_256 => sp_io::hashing::sha2_256(data),
+            HashAlgorithm::Blake3 => blake3::hash(data).into(),
+        }
+    }
+}
+
+/// Reasons a CID string or byte sequence could not be turned into a `ContentId`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CidError {
+    /// The multibase prefix is missing or not supported.
+    UnsupportedMultibase,
+    /// The string contains characters outside of the multibase alphabet.
+    InvalidEncoding,
+    /// A varint is truncated or overlong.
+    InvalidVarint,
+    /// Only CIDv1 is supported.
+    UnsupportedVersion,
+    /// Only the raw multicodec is supported.
+    UnsupportedCodec,
+    /// The multihash code is not one of the supported algorithms.
+    UnsupportedHash,
+    /// The digest is not 32 bytes long.
+    InvalidDigestLength,
+    /// Bytes were left over after the digest.
+    TrailingBytes,
 }
 
 #[derive(
@@ -32,28 +266,165 @@ pub enum BaseRight {
     DecodeWithMemTracking,
 )]
 pub struct ContentId {
-    prefix: [u8; 4],
-    hash: [u8; 32],
+    algorithm: HashAlgorithm,
+    hash: [u8; DIGEST_LENGTH],
 }
 
 impl ContentId {
-    pub fn new(prefix: &[u8], hash: &[u8]) -> Self {
-        let mut content_id = ContentId::default();
-        content_id.prefix.copy_from_slice(prefix);
-        content_id.hash.copy_from_slice(hash);
-        content_id
+    pub fn new(algorithm: HashAlgorithm, hash: &[u8; DIGEST_LENGTH]) -> Self {
+        ContentId {
+            algorithm,
+            hash: *hash,
+        }
+    }
+
+    /// Builds a `ContentId` from a client-computed digest, rejecting digests of the wrong size.
+    pub fn from_digest(algorithm: HashAlgorithm, digest: &[u8]) -> Result<Self, CidError> {
+        let hash: [u8; DIGEST_LENGTH] = digest
+            .try_into()
+            .map_err(|_| CidError::InvalidDigestLength)?;
+        Ok(Self::new(algorithm, &hash))
+    }
+
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    pub fn hash(&self) -> &[u8; DIGEST_LENGTH] {
+        &self.hash
+    }
+
+    /// Binary CIDv1: `<version><codec><multihash code><digest length><digest>`.
+    pub fn to_cid_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(DIGEST_LENGTH + 8);
+        write_varint(CID_VERSION, &mut bytes);
+        write_varint(RAW_CODEC, &mut bytes);
+        write_varint(self.algorithm.multihash_code(), &mut bytes);
+        write_varint(DIGEST_LENGTH as u64, &mut bytes);
+        bytes.extend_from_slice(&self.hash);
+        bytes
+    }
+
+    pub fn from_cid_bytes(bytes: &[u8]) -> Result<Self, CidError> {
+        let mut rest = bytes;
+        if read_varint(&mut rest)? != CID_VERSION {
+            return Err(CidError::UnsupportedVersion);
+        }
+        if read_varint(&mut rest)? != RAW_CODEC {
+            return Err(CidError::UnsupportedCodec);
+        }
+        let algorithm = HashAlgorithm::from_multihash_code(read_varint(&mut rest)?)
+            .ok_or(CidError::UnsupportedHash)?;
+        if read_varint(&mut rest)? != DIGEST_LENGTH as u64 || rest.len() < DIGEST_LENGTH {
+            return Err(CidError::InvalidDigestLength);
+        }
+        if rest.len() > DIGEST_LENGTH {
+            return Err(CidError::TrailingBytes);
+        }
+        Self::from_digest(algorithm, rest)
+    }
+
+    /// Multibase base32 (lowercase, unpadded) rendering, e.g. `bafkrei...`.
+    pub fn to_cid_string(&self) -> String {
+        let mut out = String::from("b");
+        out.extend(base32_encode(&self.to_cid_bytes()).into_iter().map(char::from));
+        out
+    }
+
+    /// Parses a multibase CIDv1 string. Base32 (`b`) and base16 (`f`) are accepted.
+    pub fn from_cid_str(s: &str) -> Result<Self, CidError> {
+        let mut chars = s.chars();
+        let bytes = match chars.next() {
+            Some('b') => base32_decode(chars.as_str().as_bytes())?,
+            Some('f') => {
+                let hex_part = chars.as_str();
+                let mut bytes = polkadot_sdk::sp_std::vec![0u8; hex_part.len() / 2];
+                hex::decode_to_slice(hex_part, &mut bytes).map_err(|_| CidError::InvalidEncoding)?;
+                bytes
+            },
+            _ => return Err(CidError::UnsupportedMultibase),
+        };
+        Self::from_cid_bytes(&bytes)
     }
 }
 
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+fn write_varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn read_varint(input: &mut &[u8]) -> Result<u64, CidError> {
+    let mut value: u64 = 0;
+    // multiformats caps unsigned varints at 9 bytes
+    for i in 0..9 {
+        let (byte, rest) = input.split_first().ok_or(CidError::InvalidVarint)?;
+        *input = rest;
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            // reject non-minimal encodings such as `0x80 0x00`
+            if i > 0 && *byte == 0 {
+                return Err(CidError::InvalidVarint);
+            }
+            return Ok(value);
+        }
+    }
+    Err(CidError::InvalidVarint)
+}
+
+fn base32_encode(input: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity((input.len() * 8).div_ceil(5));
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+    for byte in input {
+        buffer = (buffer << 8) | u16::from(*byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize]);
+        }
+    }
+    if bits > 0 {
+        out.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize]);
+    }
+    out
+}
+
+fn base32_decode(input: &[u8]) -> Result<Vec<u8>, CidError> {
+    let mut out = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+    for c in input {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|a| a == c)
+            .ok_or(CidError::InvalidEncoding)?;
+        buffer = (buffer << 5) | value as u16;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    // leftover bits are padding and must be zero
+    if bits >= 5 || buffer & ((1 << bits) - 1) != 0 {
+        return Err(CidError::InvalidEncoding);
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "std")]
 impl serde::Serialize for ContentId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        // "cid:<hex>"
-        let s = format!("cid:{}", hex::encode(&self.hash));
-        serializer.serialize_str(&s)
+        // multibase base32 CIDv1, e.g. "bafkrei..."
+        serializer.serialize_str(&self.to_cid_string())
     }
 }
 
@@ -64,23 +435,7 @@ impl<'de> serde::Deserialize<'de> for ContentId {
         D: serde::Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        // expect "cid:<hex>"
-        let pref = s
-            .get(0..4)
-            .ok_or_else(|| serde::de::Error::custom("invalid cid"))?;
-        if pref != "cid:" {
-            return Err(serde::de::Error::custom("missing cid: prefix"));
-        }
-        let hex_part = &s[4..];
-        let bytes = hex::decode(hex_part).map_err(|e| serde::de::Error::custom(e.to_string()))?;
-        if bytes.len() != 32 {
-            return Err(serde::de::Error::custom("invalid hash length"));
-        }
-        let mut hash = [0u8; 32];
-        hash.copy_from_slice(&bytes);
-        Ok(ContentId {
-            prefix: *b"cid:",
-            hash,
-        })
+        ContentId::from_cid_str(&s)
+            .map_err(|e| serde::de::Error::custom(format!("invalid cid: {:?}", e)))
     }
 }
@@ -46,7 +46,7 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
+    AccountId, Balance, Block, BlockNumber, ConsensusHook, Device, Did, Executive, InherentDataExt, Nonce, ParachainSystem,
     Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
     SLOT_DURATION, VERSION,
 };
@@ -68,25 +68,250 @@ impl Runtime {
 }
 
 impl_runtime_apis! {
+    #[api_version(8)]
     impl content_runtime_api::PalletContentRegistryApi<
             Block,
             ContentId,
-            // RuntimeApiTypes::ContentId,
-            // RuntimeApiTypes::Content
+            BlockNumber,
+            AccountId,
         > for Runtime {
             fn check_proof_of_reality(content_id: ContentId) -> bool {
                 pallet_content_registry::Pallet::<Runtime>::get_content(content_id).is_some()
             }
+
+            fn registration_time(
+                content_id: ContentId,
+            ) -> Option<content_runtime_api::RegistrationTime<BlockNumber>> {
+                pallet_content_registry::Pallet::<Runtime>::get_content(content_id).map(|proof| {
+                    content_runtime_api::RegistrationTime {
+                        exists_from: proof.exists_from,
+                        relay_parent_number: proof.relay_parent_number,
+                        moment: proof.registered_at,
+                    }
+                })
+            }
+
+            fn proof(
+                content_id: ContentId,
+            ) -> Option<content_runtime_api::ProofDetails<ContentId, AccountId, BlockNumber>> {
+                pallet_content_registry::Pallet::<Runtime>::get_content(content_id).map(|proof| {
+                    content_runtime_api::ProofDetails {
+                        content_id: proof.content_id,
+                        exists_from: proof.exists_from,
+                        registered_at: proof.registered_at,
+                        relay_parent_number: proof.relay_parent_number,
+                        did: proof.did.into_inner(),
+                        signer: proof.signer,
+                        content_type: proof.content_type.into_inner(),
+                        content_description: proof.content_description.into_inner(),
+                        content_metadata: codec::Encode::encode(&proof.content_metadata),
+                        device: proof.device.into_inner(),
+                        ipfs_cid: proof.ipfs_cid.map(|cid| cid.into_inner()),
+                    }
+                })
+            }
+
+            fn did_contents(
+                did: Vec<u8>,
+                cursor: Option<u32>,
+                limit: u32,
+            ) -> content_runtime_api::Page<ContentId, u32> {
+                // a DID that does not fit the runtime type cannot have content
+                let (items, next) = Did::try_from(did)
+                    .map(|did| {
+                        pallet_content_registry::Pallet::<Runtime>::did_contents_page(
+                            &did,
+                            cursor.unwrap_or_default(),
+                            limit,
+                        )
+                    })
+                    .unwrap_or_default();
+                content_runtime_api::Page { items, next }
+            }
+
+            fn device_contents(
+                device: Vec<u8>,
+                cursor: Option<ContentId>,
+                limit: u32,
+            ) -> content_runtime_api::Page<ContentId, ContentId> {
+                let (items, next) = Device::try_from(device)
+                    .map(|device| {
+                        pallet_content_registry::Pallet::<Runtime>::device_contents_page(
+                            &device, cursor, limit,
+                        )
+                    })
+                    .unwrap_or_default();
+                content_runtime_api::Page { items, next }
+            }
+
+            fn contents_in_block_range(
+                from: BlockNumber,
+                to: BlockNumber,
+                cursor: Option<content_runtime_api::BlockCursor<BlockNumber, ContentId>>,
+                limit: u32,
+            ) -> content_runtime_api::Page<ContentId, content_runtime_api::BlockCursor<BlockNumber, ContentId>> {
+                let (items, next) = pallet_content_registry::Pallet::<Runtime>::block_range_page(
+                    from,
+                    to,
+                    cursor.map(|cursor| (cursor.block, cursor.after)),
+                    limit,
+                );
+                content_runtime_api::Page {
+                    items,
+                    next: next.map(|(block, after)| content_runtime_api::BlockCursor { block, after }),
+                }
+            }
+
+            fn content_count() -> u64 {
+                pallet_content_registry::Pallet::<Runtime>::content_count()
+            }
+
+            fn did_content_count(did: Vec<u8>) -> u32 {
+                Did::try_from(did)
+                    .map(|did| pallet_content_registry::Pallet::<Runtime>::did_content_count(&did))
+                    .unwrap_or_default()
+            }
+
+            fn device_content_count(device: Vec<u8>) -> u32 {
+                Device::try_from(device)
+                    .map(|device| {
+                        pallet_content_registry::Pallet::<Runtime>::device_content_count(&device)
+                    })
+                    .unwrap_or_default()
+            }
+
+            fn attestations(
+                content_id: ContentId,
+            ) -> Vec<content_runtime_api::AttestationDetails<AccountId, BlockNumber>> {
+                pallet_content_registry::Pallet::<Runtime>::attestations(&content_id)
+                    .into_iter()
+                    .map(|(attester, attestation)| content_runtime_api::AttestationDetails {
+                        attester: attester.into_inner(),
+                        kind: attestation.kind,
+                        signer: attestation.signer,
+                        comment_hash: attestation.comment_hash,
+                        attested_at: attestation.attested_at,
+                    })
+                    .collect()
+            }
+
+            fn license(content_id: ContentId) -> Option<content_runtime_api::LicenseDetails> {
+                pallet_content_registry::Pallet::<Runtime>::license(&content_id).map(|license| {
+                    content_runtime_api::LicenseDetails {
+                        license: license.license,
+                        offer: license.offer.map(|offer| content_runtime_api::LicenseOfferDetails {
+                            license: offer.license,
+                            price: offer.price,
+                        }),
+                    }
+                })
+            }
+
+            fn license_receipt(
+                content_id: ContentId,
+                account: AccountId,
+            ) -> Option<content_runtime_api::LicenseReceiptDetails<AccountId, BlockNumber>> {
+                pallet_content_registry::Pallet::<Runtime>::license_receipt(&content_id, &account).map(
+                    |receipt| content_runtime_api::LicenseReceiptDetails {
+                        license: receipt.license,
+                        price: receipt.price,
+                        payee: receipt.payee,
+                        purchased_at: receipt.purchased_at,
+                    },
+                )
+            }
+
+            fn proof_storage_key(content_id: ContentId) -> Vec<u8> {
+                pallet_content_registry::Pallet::<Runtime>::proof_storage_key(&content_id)
+            }
+
+            fn verification_status(
+                content_id: ContentId,
+            ) -> content_runtime_api::VerificationStatus<BlockNumber> {
+                use content_runtime_api::VerificationStatus;
+                use pallet_context_court::Decision;
+
+                let Some(proof) = pallet_content_registry::Pallet::<Runtime>::get_content(&content_id) else {
+                    return VerificationStatus::NotRegistered;
+                };
+                let did = proof.did.to_vec();
+                let exists_from = proof.exists_from;
+
+                if let Some(retracted_at) =
+                    pallet_content_registry::Pallet::<Runtime>::retracted_at(&content_id)
+                {
+                    return VerificationStatus::Retracted { did, exists_from, retracted_at };
+                }
+                if let Some(revoked_at) =
+                    pallet_identity_registry::Pallet::<Runtime>::device_revoked_at(&proof.did, &proof.device)
+                {
+                    return VerificationStatus::DeviceCompromised {
+                        did,
+                        exists_from,
+                        device: proof.device.into_inner(),
+                        revoked_at,
+                    };
+                }
+                let decision = pallet_context_court::Pallet::<Runtime>::get_decision(&content_id)
+                    .zip(pallet_context_court::Pallet::<Runtime>::decided_at(&content_id));
+                match decision {
+                    Some((Decision::Convict, decided_at)) => {
+                        return VerificationStatus::ContextConvicted { did, exists_from, decided_at };
+                    },
+                    Some((Decision::Acquittal, decided_at)) => {
+                        return VerificationStatus::ContextAcquitted { did, exists_from, decided_at };
+                    },
+                    _ => {},
+                }
+                if let Some(disputed_at) = pallet_context_court::Pallet::<Runtime>::disputed_at(&content_id) {
+                    return VerificationStatus::UnderDispute { did, exists_from, disputed_at };
+                }
+                VerificationStatus::Registered { did, exists_from }
+            }
+
+            fn labels(content_id: ContentId) -> Vec<content_runtime_api::LabelDetails<BlockNumber>> {
+                pallet_content_registry::Pallet::<Runtime>::labels(&content_id)
+                    .into_iter()
+                    .map(|(kind, label)| content_runtime_api::LabelDetails {
+                        kind,
+                        disputed_at: label.disputed_at,
+                        decided_at: label.decided_at,
+                    })
+                    .collect()
+            }
     }
-    
+
+    #[api_version(3)]
     impl context_runtime_api::PalletContextCourtApi<
         Block,
-        ContentId
+        ContentId,
+        BlockNumber,
+        Balance
     > for Runtime {
         fn has_dispute(content_id: ContentId) -> bool {
             pallet_context_court::Pallet::<Runtime>::get_dispute(content_id).is_some()
         }
-        
+
+        fn evidence(content_id: ContentId) -> Vec<context_runtime_api::EvidenceDetails<BlockNumber>> {
+            use context_runtime_api::Evidence;
+            pallet_context_court::Pallet::<Runtime>::evidence(&content_id)
+                .into_iter()
+                .map(|(did, entry)| context_runtime_api::EvidenceDetails {
+                    submitter: did.into_inner(),
+                    party: entry.party,
+                    evidence: match entry.evidence {
+                        Evidence::ContentHash(content_id) => Evidence::ContentHash(content_id),
+                        Evidence::IpfsCid(cid) => Evidence::IpfsCid(cid.into_inner()),
+                        Evidence::TextHash(hash) => Evidence::TextHash(hash),
+                    },
+                    submitted_at: entry.submitted_at,
+                })
+                .collect()
+        }
+
+        fn court_pot() -> Balance {
+            pallet_context_court::Pallet::<Runtime>::pot_balance()
+        }
     }
     
     impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
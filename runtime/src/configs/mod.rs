@@ -25,19 +25,23 @@
 
 mod xcm_config;
 
+use codec::Encode;
 use crate::sp_runtime::BoundedVec;
 use polkadot_sdk::{staging_parachain_info as parachain_info, staging_xcm as xcm, *};
 #[cfg(not(feature = "runtime-benchmarks"))]
 use polkadot_sdk::{staging_xcm_builder as xcm_builder, staging_xcm_executor as xcm_executor};
 // Substrate and Polkadot dependencies
-use cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
-use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
+use cumulus_pallet_parachain_system::{RelayChainStateProof, RelayNumberMonotonicallyIncreases};
+use cumulus_primitives_core::{
+    relay_chain::well_known_keys::CURRENT_BLOCK_RANDOMNESS, AggregateMessageOrigin, ParaId,
+};
 use frame_support::{
     derive_impl,
     dispatch::DispatchClass,
     parameter_types,
     traits::{
-        ConstBool, ConstU32, ConstU64, ConstU128, ConstU8, EitherOfDiverse, TransformOrigin, VariantCountOf,
+        ConstBool, ConstU32, ConstU64, ConstU128, ConstU8, EitherOfDiverse, Randomness,
+        TransformOrigin, VariantCountOf,
     },
     weights::{ConstantMultiplier, Weight},
     PalletId,
@@ -59,11 +63,11 @@ use xcm::latest::prelude::BodyId;
 // Local module imports
 use super::{
     weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight},
-    AccountId, Aura, Balance, Balances, Block, BlockNumber, CollatorSelection, ConsensusHook, Hash,
-    IdentityRegistry, MessageQueue, Nonce, PalletInfo, ParachainSystem, Runtime, RuntimeCall,
+    AccountId, Aura, Balance, Balances, Block, BlockNumber, CollatorSelection, ConsensusHook, ContentRegistry, Hash,
+    IdentityRegistry, MessageQueue, MultiBlockMigrations, Nonce, PalletInfo, ParachainSystem, Runtime, RuntimeCall,
     RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Session,
-    SessionKeys, System, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, EXISTENTIAL_DEPOSIT,
-    HOURS, MAXIMUM_BLOCK_WEIGHT, MICRO_UNIT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION,
+    SessionKeys, Signature, System, Timestamp, UncheckedExtrinsic, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, EXISTENTIAL_DEPOSIT,
+    DAYS, HOURS, MAXIMUM_BLOCK_WEIGHT, MICRO_UNIT, MILLI_UNIT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION,
 };
 use shared::types::BaseRight;
 use xcm_config::{RelayLocation, XcmOriginToTransactDispatchOrigin};
@@ -128,6 +132,8 @@ impl frame_system::Config for Runtime {
     /// The action to take on a Runtime Upgrade
     type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
     type MaxConsumers = frame_support::traits::ConstU32<16>;
+    /// Migrations spanning several blocks are driven by `pallet-migrations`.
+    type MultiBlockMigrator = MultiBlockMigrations;
 }
 
 /// Configure the palelt weight reclaim tx.
@@ -332,6 +338,42 @@ impl pallet_identity_registry::Config for Runtime {
 }
 
 /// Configure the pallet template in pallets/template.
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as sp_runtime::traits::Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::CreateTransactionBase<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<C> frame_system::offchain::CreateInherent<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    fn create_inherent(call: RuntimeCall) -> UncheckedExtrinsic {
+        UncheckedExtrinsic::new_bare(call)
+    }
+}
+
+parameter_types! {
+    /// Local IPFS node; operators can point their offchain worker elsewhere through
+    /// `pallet_content_registry::IPFS_GATEWAY_KEY`.
+    pub const IpfsGateway: &'static str = "http://127.0.0.1:8080";
+    /// A `Proof` takes up to about 1 KiB of storage
+    pub const ContentProofDeposit: Balance = 10 * MILLI_UNIT;
+    /// A `Commitment` takes up about 250 bytes
+    pub const ContentCommitmentDeposit: Balance = 3 * MILLI_UNIT;
+    // answers to proof status queries run unpaid on the querying chain
+    pub const ContentMaxResponseWeight: Weight = Weight::from_parts(10_000_000_000, 256 * 1024);
+    pub const ContentAvailabilityPriority: sp_runtime::transaction_validity::TransactionPriority =
+        sp_runtime::transaction_validity::TransactionPriority::MAX / 2;
+}
+
 impl pallet_content_registry::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_content_registry::weights::SubstrateWeight<Runtime>;
@@ -343,11 +385,150 @@ impl pallet_content_registry::Config for Runtime {
     type Content = super::Content;
     type ContentDescription = BoundedVec<u8, ConstU32<100>>;
     type ContentType = BoundedVec<u8, ConstU32<100>>;
-    type ContentMetadata = BoundedVec<u8, ConstU32<100>>;
-    type MaxContentInVec = ConstU32<10000>;
+    type NativeBalance = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type ProofDeposit = ContentProofDeposit;
+    type CommitmentDeposit = ContentCommitmentDeposit;
+    type QuotaPeriod = ConstU32<HOURS>;
+    type DefaultQuota = ConstU32<200>;
+    type QuotaOrigin = EnsureRoot<AccountId>;
+    type XcmQueryOrigin = EnsureXcm<xcm_config::SiblingParachains>;
+    type XcmSender = xcm_config::XcmRouter;
+    type MaxResponseWeight = ContentMaxResponseWeight;
+    type UnixTime = Timestamp;
+    type RelayBlockNumberProvider = cumulus_pallet_parachain_system::RelaychainDataProvider<Runtime>;
+    // devices that were offline at capture time get a day to register the proof
+    type MaxCaptureTimeDrift = ConstU64<{ 24 * 60 * 60 * 1000 }>;
+    type MaxIpfsCidLength = ConstU32<128>;
+    type IpfsGateway = IpfsGateway;
+    type MaxIpfsObjectSize = ConstU32<{ 32 * 1024 * 1024 }>;
+    type MaxPendingChecks = ConstU32<256>;
+    type CheckRetryInterval = ConstU32<100>;
+    type MaxReporters = ConstU32<20>;
+    type ReporterOrigin = EnsureRoot<AccountId>;
+    type AuthorityId = pallet_content_registry::crypto::AvailabilityAuthId;
+    type UnsignedPriority = ContentAvailabilityPriority;
+    type MaxCommitmentExpiriesPerBlock = ConstU32<64>;
+    type MaxAttestationsPerContent = ConstU32<32>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ContentRegistryBenchmarkHelper;
 }
-impl polkadot_sdk::pallet_insecure_randomness_collective_flip::Config for Runtime {}
 
+/// Registers a DID with the identity registry for the content registry benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ContentRegistryBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_content_registry::BenchmarkHelper<AccountId, super::Did, super::Device, super::Content>
+    for ContentRegistryBenchmarkHelper
+{
+    fn setup_did(who: &AccountId) -> (super::Did, super::Device) {
+        use frame_support::traits::fungible::Mutate;
+
+        let did: super::Did = BoundedVec::truncate_from(b"did:era:bench".to_vec());
+        let device: super::Device = BoundedVec::truncate_from(b"bench-device".to_vec());
+        let origin = || RuntimeOrigin::signed(who.clone());
+        Balances::set_balance(who, 1_000_000 * EXISTENTIAL_DEPOSIT);
+        IdentityRegistry::create_did(origin(), did.clone(), BoundedVec::truncate_from(vec![who.clone()]))
+            .expect("the benchmark account can pay the DID deposit");
+        for right in [BaseRight::Impersonate, BaseRight::Update] {
+            IdentityRegistry::add_right_for_signatory(
+                origin(),
+                did.clone(),
+                who.clone(),
+                right,
+                pallet_identity_registry::RightDuration::Permanent,
+            )
+            .expect("the DID creator may grant rights");
+        }
+        IdentityRegistry::register_device(origin(), did.clone(), device.clone())
+            .expect("the DID creator may register devices");
+        (did, device)
+    }
+
+    fn content(seed: u32) -> super::Content {
+        sp_io::hashing::blake2_256(&seed.to_le_bytes())
+    }
+}
+
+parameter_types! {
+    pub MbmServiceWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    // The court re-keys its decisions through the proofs the registry has not migrated yet,
+    // so it goes first.
+    #[cfg(not(feature = "runtime-benchmarks"))]
+    type Migrations = (
+        pallet_context_court::migrations::v1::LazyMigrationV1<Runtime>,
+        pallet_content_registry::migrations::v1::LazyMigrationV1<
+            Runtime,
+            pallet_content_registry::weights::SubstrateWeight<Runtime>,
+        >,
+    );
+    // Benchmarks need mocked migrations to guarantee that they succeed.
+    #[cfg(feature = "runtime-benchmarks")]
+    type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+    type CursorMaxLen = ConstU32<65_536>;
+    type IdentifierMaxLen = ConstU32<256>;
+    type MigrationStatusHandler = ();
+    type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+    type MaxServiceWeight = MbmServiceWeight;
+    type WeightInfo = pallet_migrations::weights::SubstrateWeight<Runtime>;
+}
+/// Randomness from the VRF output of the relay-chain block we build on, read from the relay state
+/// proof of the validation data inherent of the current block. Collators learn it only once the
+/// relay block exists and cannot grind it the way they can grind their own block hashes.
+///
+/// It is reported as known since the number of that relay block, which callers compare to tell
+/// randomness apart that was only known after an event of interest.
+///
+/// `parachain_system` clears the validation data in its `on_initialize`, so the randomness is
+/// only there once the inherent ran: in extrinsics, `on_idle` and `on_finalize`. Before that,
+/// e.g. in `on_initialize` or at genesis, it is reported as known since block 0 and callers must
+/// not rely on it.
+pub struct RelayChainRandomness;
+impl Randomness<Hash, BlockNumber> for RelayChainRandomness {
+    fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+        let relay_randomness = cumulus_pallet_parachain_system::ValidationData::<Runtime>::get()
+            .zip(cumulus_pallet_parachain_system::RelayStateProof::<Runtime>::get())
+            .and_then(|(validation_data, proof)| {
+                let proof = RelayChainStateProof::new(
+                    parachain_info::Pallet::<Runtime>::parachain_id(),
+                    validation_data.relay_parent_storage_root,
+                    proof,
+                )
+                .ok()?;
+                let randomness = proof
+                    .read_optional_entry::<Option<[u8; 32]>>(CURRENT_BLOCK_RANDOMNESS)
+                    .ok()
+                    .flatten()
+                    .flatten()?;
+                Some((randomness, validation_data.relay_parent_number))
+            });
+        match relay_randomness {
+            Some((relay_randomness, relay_parent_number)) => {
+                let seed = (subject, relay_randomness).using_encoded(sp_io::hashing::blake2_256);
+                (seed.into(), relay_parent_number)
+            },
+            None => (Hash::default(), 0),
+        }
+    }
+}
+
+
+parameter_types! {
+    pub const JurorBondShare: Perbill = Perbill::from_percent(50);
+    pub const CourtPalletId: PalletId = PalletId(*b"py/court");
+    pub const DefaultCategoryParams: pallet_context_court::CategoryParams<BlockNumber> =
+        pallet_context_court::CategoryParams {
+            jury_size: 7,
+            voting_period: 6 * HOURS,
+            quorum: Perbill::from_percent(60),
+            supermajority: Perbill::from_percent(70),
+        };
+}
 
 impl pallet_context_court::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
@@ -355,19 +536,40 @@ impl pallet_context_court::Config for Runtime {
     type Did = BoundedVec<u8, ConstU32<100>>;
     type Device = BoundedVec<u8, ConstU32<100>>;
     type DidRegistry = IdentityRegistry;
+    type ContentInspector = ContentRegistry;
+    type DisputeObserver = ContentRegistry;
+    type Randomness = RelayChainRandomness;
+    type JurorReputation = ();
     type GivenRight = BaseRight;
     type NativeBalance = Balances;
     type RuntimeHoldReason = RuntimeHoldReason;
     type MaxJurors = ConstU32<100>;
     type MaxJurorsPerDispute = ConstU32<100>;
-    type MinJurorsPerDispute = ConstU32<100>;
+    type MinJurorsPerDispute = ConstU32<7>;
+    type DefaultCategoryParams = DefaultCategoryParams;
+    type CategoryOrigin = EnsureRoot<AccountId>;
     type MaxContextLength = ConstU32<500>;
+    type EvidencePeriod = ConstU32<1200>;
+    type MaxEvidencePerParty = ConstU32<10>;
+    type MaxWitnesses = ConstU32<5>;
+    type MaxIpfsCidLength = ConstU32<128>;
     type HoldAmount = ConstU128<1000>;
+    type UnbondingPeriod = ConstU32<{ 7 * DAYS }>;
     type RewardAmount = ConstU128<120>;
     type SlashAmount = ConstU128<200>;
     type ExclusionFee = ConstU128<100>;
+    type PalletId = CourtPalletId;
+    type DisputeBondAmount = ConstU128<1000>;
+    type JurorBondShare = JurorBondShare;
+    type AppealBondAmount = ConstU128<2000>;
+    type AppealPeriod = ConstU32<1200>;
+    type MaxAppealRounds = ConstU32<2>;
     type EscalatedVotingPeriod = ConstU32<3000>;
+    type RevealPeriod = ConstU32<1200>;
     type MaxRewardsNumber = ConstU32<500>;
     type BatchRewardSize = ConstU32<10>;
-
+    type MaxDeadlinesPerBlock = ConstU32<100>;
+    type MaxCourtActionsPerBlock = ConstU32<10>;
+    // the last attempt is 511 blocks, about 51 minutes, after the first
+    type MaxSummonAttempts = ConstU32<10>;
 }
@@ -111,6 +111,14 @@ impl Contains<Location> for ParentOrParentsExecutivePlurality {
     }
 }
 
+/// Sibling parachains, which may ask the content registry for the status of a proof.
+pub struct SiblingParachains;
+impl Contains<Location> for SiblingParachains {
+    fn contains(location: &Location) -> bool {
+        matches!(location.unpack(), (1, [Parachain(_)]))
+    }
+}
+
 pub type Barrier = TrailingSetTopicAsId<
     DenyThenTry<
         DenyRecursively<DenyReserveTransferToRelayChain>,
@@ -297,6 +297,10 @@ mod runtime {
     #[runtime::pallet_index(11)]
     pub type TransactionPayment = pallet_transaction_payment;
 
+    // Runtime upgrades.
+    #[runtime::pallet_index(12)]
+    pub type MultiBlockMigrations = pallet_migrations;
+
     // Governance
     #[runtime::pallet_index(15)]
     pub type Sudo = pallet_sudo;
@@ -330,8 +334,6 @@ mod runtime {
     pub type ContentRegistry = pallet_content_registry;
     #[runtime::pallet_index(52)]
     pub type ContextCourt = pallet_context_court;
-    #[runtime::pallet_index(53)]
-    pub type Randomness = polkadot_sdk::pallet_insecure_randomness_collective_flip;
 }
 
 #[docify::export(register_validate_block)]